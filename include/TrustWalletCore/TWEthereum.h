@@ -7,6 +7,8 @@
 #pragma once
 
 #include "TWBase.h"
+#include "TWCoinType.h"
+#include "TWData.h"
 #include "TWString.h"
 
 TW_EXTERN_C_BEGIN
@@ -25,4 +27,36 @@ struct TWEthereum;
 TW_EXPORT_STATIC_METHOD
 TWString* _Nonnull TWEthereumEip2645GetPath(TWString* _Nonnull ethAddress, TWString* _Nonnull layer,  TWString* _Nonnull application,  TWString* _Nonnull index);
 
+/// Decodes a signed or unsigned transaction. Recovers the sender if the transaction is signed.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::TransactionDecodingInput`.
+/// \return serialized `Ethereum::Proto::TransactionDecodingOutput`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumDecodeTransaction(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Signs an EIP-7702 authorization to set the code of the signer's account.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::AuthorizationSigningInput`.
+/// \return serialized `Ethereum::Proto::AuthorizationSigningOutput`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumSignAuthorization(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Computes an address of a contract or an ERC-4337 smart account before it's deployed.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::DeploymentAddressInput`.
+/// \return serialized `Ethereum::Proto::DeploymentAddressOutput`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumComputeDeploymentAddress(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Returns a display-ready tree of an EIP-712 typed data.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::TypedDataDescribingInput`.
+/// \return serialized `Ethereum::Proto::TypedDataDescribingOutput`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumDescribeTypedData(enum TWCoinType coin, TWData* _Nonnull input);
+
 TW_EXTERN_C_END
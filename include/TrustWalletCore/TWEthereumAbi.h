@@ -51,6 +51,40 @@ TWData* _Nonnull TWEthereumAbiDecodeValue(enum TWCoinType coin, TWData* _Nonnull
 TW_EXPORT_STATIC_METHOD
 TWData* _Nonnull TWEthereumAbiEncodeFunction(enum TWCoinType coin, TWData* _Nonnull input);
 
+/// Decodes an event log according to a given ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.LogDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.LogDecodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiDecodeLog(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Decodes revert data of a failed call: `Error(string)`, `Panic(uint256)` or a custom error declared in a given ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.RevertDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.RevertDecodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiDecodeRevert(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Encodes parameters in the non-standard packed mode, i.e `abi.encodePacked(...)`,
+/// and returns the encoded data along with its Keccak-256 hash.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.PackedEncodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.PackedEncodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiEncodePacked(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Interprets a smart contract call against the built-in ERC-20, ERC-721, ERC-1155, ERC-4337 account ABIs
+/// and the given additional ABIs, and returns a human readable intent with risk flags.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.ContractCallInterpretingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.ContractCallInterpretingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiInterpretContractCall(enum TWCoinType coin, TWData* _Nonnull input);
+
 /// Encode function to Eth ABI binary
 ///
 /// \param fn Non-null Eth abi function
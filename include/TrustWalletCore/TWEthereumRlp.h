@@ -24,4 +24,12 @@ struct TWEthereumRlp;
 TW_EXPORT_STATIC_METHOD
 TWData* _Nonnull TWEthereumRlpEncode(enum TWCoinType coin, TWData* _Nonnull input);
 
+/// Decode an RLP encoded item or a list of items.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `EthereumRlp::Proto::DecodingInput`.
+/// \return serialized `EthereumRlp::Proto::DecodingOutput`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumRlpDecode(enum TWCoinType coin, TWData* _Nonnull input);
+
 TW_EXTERN_C_END
@@ -140,7 +140,7 @@ class CoinAddressDerivationTests {
         Persistence -> "persistence142j9u5eaduzd7faumygud6ruhdwme98q7gv2ch"
         Akash -> "akash142j9u5eaduzd7faumygud6ruhdwme98qal870f"
         Noble -> "noble142j9u5eaduzd7faumygud6ruhdwme98qc8l3wa"
-        Rootstock -> "0xA2D7065F94F838a3aB9C04D67B312056846424Df"
+        Rootstock -> "0xA2d7065f94F838A3ab9C04d67b312056846424Df"
         Sei -> "sei142j9u5eaduzd7faumygud6ruhdwme98qagm0sj"
         InternetComputer -> "b9a13d974ee9db036d5abc5b66ace23e513cb5676f3996626c7717c339a3ee87"
     }
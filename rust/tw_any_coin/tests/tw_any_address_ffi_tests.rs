@@ -20,6 +20,7 @@ use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_memory::test_utils::tw_string_helper::TWStringHelper;
 
 const ETHEREUM_COIN_TYPE: u32 = 60;
+const ROOTSTOCK_COIN_TYPE: u32 = 137;
 
 #[test]
 fn test_any_address_derive() {
@@ -36,6 +37,10 @@ fn test_any_address_derive() {
         let expected_address = match coin.blockchain {
             // By default, Bitcoin will return a P2PKH address.
             BlockchainType::Bitcoin => "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X",
+            // Rootstock uses EIP-1191 checksum.
+            BlockchainType::Ethereum if coin.coin_id == ROOTSTOCK_COIN_TYPE => {
+                "0xAc1Ec44e4f0CA7D172B7803F6836DE87fb72b309"
+            },
             BlockchainType::Ethereum => "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309",
             BlockchainType::Ronin => "ronin:Ac1ec44E4f0ca7D172B7803f6836De87Fb72b309",
             BlockchainType::InternetComputer => {
@@ -66,6 +71,10 @@ fn test_any_address_normalize_eth() {
                 "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X",
                 "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X",
             ),
+            BlockchainType::Ethereum if coin.coin_id == ROOTSTOCK_COIN_TYPE => (
+                "0xb16db98b365b1f89191996942612b14f1da4bd5f",
+                "0xb16dB98B365B1F89191996942612B14F1Da4BD5f",
+            ),
             BlockchainType::Ethereum => (
                 "0xb16db98b365b1f89191996942612b14f1da4bd5f",
                 "0xb16Db98B365B1f89191996942612B14F1Da4Bd5f",
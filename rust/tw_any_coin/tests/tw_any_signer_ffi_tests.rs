@@ -55,6 +55,54 @@ fn test_any_signer_sign_eth() {
     assert_eq!(output.encoded.to_hex(), expected);
 }
 
+#[test]
+fn test_any_signer_sign_eth_check_chain_id() {
+    use tw_proto::Ethereum::Proto;
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+
+    let sign = |chain_id: u64| {
+        let transfer = Proto::mod_Transaction::Transfer {
+            amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+            data: Cow::default(),
+        };
+        let input = Proto::SigningInput {
+            chain_id: U256::encode_be_compact(chain_id),
+            nonce: U256::encode_be_compact(9),
+            gas_price: U256::encode_be_compact(20_000_000_000),
+            gas_limit: U256::encode_be_compact(21_000),
+            to_address: "0x3535353535353535353535353535353535353535".into(),
+            transaction: Some(Proto::Transaction {
+                transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                    transfer,
+                ),
+            }),
+            private_key: private.clone().into(),
+            check_chain_id: true,
+            ..Proto::SigningInput::default()
+        };
+
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), ETHEREUM_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr")
+    };
+
+    // Ethereum chain ID is 1 according to `registry.json`.
+    let output = sign(1);
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    assert_eq!(output.encoded.to_hex(), expected);
+
+    // BNB Smart Chain ID.
+    let output = sign(56);
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
 #[test]
 fn test_any_signer_sign_unknown_coin() {
     let unsupported_coin = u32::MAX;
@@ -24,6 +24,7 @@ fn test_tw_message_signer_sign() {
         message: "Foo".into(),
         chain_id: None,
         message_type: Ethereum::Proto::MessageType::MessageType_legacy,
+        ..Ethereum::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -44,6 +45,7 @@ fn test_tw_message_signer_verify() {
         message: "Foo".into(),
         public_key: "0349d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f".decode_hex().unwrap().into(),
         signature: "21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be71101b".into(),
+        ..Ethereum::Proto::MessageVerifyingInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -57,6 +59,7 @@ fn test_tw_message_signer_verify_invalid() {
         message: "Foo".into(),
         public_key: "0349d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f".decode_hex().unwrap().into(),
         signature: "21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be71101c".into(),
+        ..Ethereum::Proto::MessageVerifyingInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -74,6 +77,7 @@ fn test_tw_message_signer_pre_image_hashes() {
         message: "Foo".into(),
         chain_id: None,
         message_type: Ethereum::Proto::MessageType::MessageType_legacy,
+        ..Ethereum::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -91,3 +95,38 @@ fn test_tw_message_signer_pre_image_hashes() {
         "0af844076e792f9685560b2e597967da7403b00a5339b5801ea251ddde375f8a"
     );
 }
+
+#[test]
+fn test_tw_message_signer_sign_check_chain_id() {
+    let sign = |chain_id: Option<u64>| {
+        let input = Ethereum::Proto::MessageSigningInput {
+            private_key: "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d"
+                .decode_hex()
+                .unwrap()
+                .into(),
+            message: "Foo".into(),
+            chain_id: chain_id.map(|chain_id| Ethereum::Proto::MaybeChainId { chain_id }),
+            message_type: Ethereum::Proto::MessageType::MessageType_eip155,
+            check_chain_id: true,
+            ..Ethereum::Proto::MessageSigningInput::default()
+        };
+
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), ETHEREUM_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr")
+    };
+
+    // The chain ID of the coin is used if not specified.
+    let output = sign(None);
+    let output: Ethereum::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.signature, "21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be711025");
+    let same_chain = sign(Some(1));
+    let same_chain: Ethereum::Proto::MessageSigningOutput = deserialize(&same_chain).unwrap();
+    assert_eq!(same_chain.signature, output.signature);
+
+    let output = sign(Some(56));
+    let output: Ethereum::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
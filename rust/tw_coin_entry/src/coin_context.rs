@@ -6,10 +6,37 @@
 
 use tw_keypair::tw::PublicKeyType;
 
+/// Mixed-case checksum of hex addresses.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AddressChecksum {
+    /// https://eips.ethereum.org/EIPS/eip-55
+    #[default]
+    Eip55,
+    /// Chain ID aware checksum.
+    /// https://eips.ethereum.org/EIPS/eip-1191
+    Eip1191 { chain_id: u64 },
+}
+
 /// Extend the trait with methods required for blockchain additions.
 pub trait CoinContext {
     fn public_key_type(&self) -> PublicKeyType;
 
+    /// Returns the chain ID if specified in `registry.json`.
+    fn chain_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the EVM address checksum if specified in `registry.json`.
+    fn address_checksum(&self) -> Option<AddressChecksum> {
+        None
+    }
+
+    /// Returns the EVM address prefix if specified in `registry.json`.
+    /// For example, `xdc` that is used instead of `0x`.
+    fn address_prefix(&self) -> Option<&str> {
+        None
+    }
+
     // Example:
     // fn ss58_prefix(&self) -> Option<String>;
 }
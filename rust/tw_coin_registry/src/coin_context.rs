@@ -5,7 +5,7 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::registry::CoinItem;
-use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_context::{AddressChecksum, CoinContext};
 use tw_keypair::tw::PublicKeyType;
 
 pub struct CoinRegistryContext {
@@ -24,4 +24,19 @@ impl CoinContext for CoinRegistryContext {
     fn public_key_type(&self) -> PublicKeyType {
         self.item.public_key_type
     }
+
+    #[inline]
+    fn chain_id(&self) -> Option<&str> {
+        self.item.chain_id.as_deref()
+    }
+
+    #[inline]
+    fn address_checksum(&self) -> Option<AddressChecksum> {
+        self.item.address_checksum
+    }
+
+    #[inline]
+    fn address_prefix(&self) -> Option<&str> {
+        self.item.address_prefix.as_deref()
+    }
 }
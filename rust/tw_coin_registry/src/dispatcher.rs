@@ -53,3 +53,11 @@ pub fn evm_dispatcher(coin: CoinType) -> RegistryResult<EvmEntryExtStaticRef> {
         BlockchainType::Unsupported => Err(RegistryError::Unsupported),
     }
 }
+
+pub fn evm_coin_dispatcher(
+    coin: CoinType,
+) -> RegistryResult<(CoinRegistryContext, EvmEntryExtStaticRef)> {
+    let evm_entry = evm_dispatcher(coin)?;
+    let coin_context = CoinRegistryContext::with_coin_item(get_coin_item(coin)?);
+    Ok((coin_context, evm_entry))
+}
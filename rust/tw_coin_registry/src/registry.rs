@@ -10,6 +10,8 @@ use crate::error::{RegistryError, RegistryResult};
 use lazy_static::lazy_static;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::AddressChecksum;
 use tw_keypair::tw::PublicKeyType;
 
 type RegistryMap = HashMap<CoinType, CoinItem>;
@@ -24,11 +26,69 @@ lazy_static! {
 
 /// Extend this structure according to `registry.json`.
 #[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(try_from = "CoinItemJson")]
 pub struct CoinItem {
     pub coin_id: CoinType,
     pub blockchain: BlockchainType,
     pub public_key_type: PublicKeyType,
+    pub chain_id: Option<String>,
+    pub address_checksum: Option<AddressChecksum>,
+    pub address_prefix: Option<String>,
+}
+
+/// The raw `registry.json` item.
+/// Fields that depend on each other are resolved in [`CoinItem::try_from`].
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CoinItemJson {
+    coin_id: CoinType,
+    blockchain: BlockchainType,
+    public_key_type: PublicKeyType,
+    #[serde(default)]
+    chain_id: Option<String>,
+    #[serde(default)]
+    address_checksum: Option<String>,
+    #[serde(default)]
+    address_prefix: Option<String>,
+}
+
+impl TryFrom<CoinItemJson> for CoinItem {
+    type Error = String;
+
+    fn try_from(json: CoinItemJson) -> Result<Self, Self::Error> {
+        let address_checksum = match json.address_checksum.as_deref() {
+            None => None,
+            Some("eip55") => Some(AddressChecksum::Eip55),
+            Some("eip1191") => {
+                let chain_id = json
+                    .chain_id
+                    .as_deref()
+                    .and_then(|chain_id| u64::from_str(chain_id).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "coin {} expected to have a decimal chainId to use EIP-1191 checksum",
+                            json.coin_id
+                        )
+                    })?;
+                Some(AddressChecksum::Eip1191 { chain_id })
+            },
+            Some(other) => {
+                return Err(format!(
+                    "coin {} has an unknown addressChecksum: {other}",
+                    json.coin_id
+                ))
+            },
+        };
+
+        Ok(CoinItem {
+            coin_id: json.coin_id,
+            blockchain: json.blockchain,
+            public_key_type: json.public_key_type,
+            chain_id: json.chain_id,
+            address_checksum,
+            address_prefix: json.address_prefix,
+        })
+    }
 }
 
 #[inline]
@@ -51,3 +111,41 @@ fn parse_registry_json() -> RegistryMap {
         serde_json::from_str(REGISTRY_JSON).expect("registry.json expected to be valid");
     items.into_iter().map(|item| (item.coin_id, item)).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every `eip1191` coin must have its chain ID resolved at load.
+    #[test]
+    fn test_registry_address_checksum() {
+        for item in registry_iter() {
+            if let Some(AddressChecksum::Eip1191 { chain_id }) = item.address_checksum {
+                assert_eq!(
+                    item.chain_id,
+                    Some(chain_id.to_string()),
+                    "coin: {}",
+                    item.coin_id
+                );
+            }
+        }
+
+        // Rootstock.
+        assert_eq!(
+            get_coin_item(137).unwrap().address_checksum,
+            Some(AddressChecksum::Eip1191 { chain_id: 30 })
+        );
+    }
+
+    #[test]
+    fn test_coin_item_eip1191_without_chain_id() {
+        let json = r#"{
+            "coinId": 60,
+            "blockchain": "Ethereum",
+            "publicKeyType": "secp256k1Extended",
+            "chainId": "not-a-number",
+            "addressChecksum": "eip1191"
+        }"#;
+        serde_json::from_str::<CoinItem>(json).unwrap_err();
+    }
+}
@@ -4,14 +4,13 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
-use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::{AddressError, AddressResult};
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::prefix::NoPrefix;
-use tw_evm::address::Address;
+use tw_evm::address::{Address, AddressFormat, ChainAddress};
 use tw_evm::evm_context::StandardEvmContext;
 use tw_evm::evm_entry::EvmEntry;
 use tw_evm::modules::compiler::Compiler;
@@ -26,7 +25,7 @@ pub struct EthereumEntry;
 
 impl CoinEntry for EthereumEntry {
     type AddressPrefix = NoPrefix;
-    type Address = Address;
+    type Address = ChainAddress;
     type SigningInput<'a> = Proto::SigningInput<'a>;
     type SigningOutput = Proto::SigningOutput<'static>;
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
@@ -39,16 +38,18 @@ impl CoinEntry for EthereumEntry {
     #[inline]
     fn parse_address(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         address: &str,
         _prefix: Option<Self::AddressPrefix>,
     ) -> AddressResult<Self::Address> {
-        Address::from_str(address)
+        let format = AddressFormat::from_coin_context(coin);
+        let address = format.parse(address)?;
+        Ok(ChainAddress::new(address, format))
     }
 
     fn derive_address(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         public_key: PublicKey,
         _derivation: Derivation,
         _prefix: Option<Self::AddressPrefix>,
@@ -56,32 +57,36 @@ impl CoinEntry for EthereumEntry {
         let public_key = public_key
             .to_secp256k1()
             .ok_or(AddressError::PublicKeyTypeMismatch)?;
-        Ok(Address::with_secp256k1_pubkey(public_key))
+        let format = AddressFormat::from_coin_context(coin);
+        Ok(ChainAddress::new(
+            Address::with_secp256k1_pubkey(public_key),
+            format,
+        ))
     }
 
     #[inline]
-    fn sign(&self, _coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
-        Signer::<StandardEvmContext>::sign_proto(input)
+    fn sign(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
+        Signer::<StandardEvmContext>::sign_proto_for_coin(coin, input)
     }
 
     #[inline]
     fn preimage_hashes(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::SigningInput<'_>,
     ) -> Self::PreSigningOutput {
-        Compiler::<StandardEvmContext>::preimage_hashes(input)
+        Compiler::<StandardEvmContext>::preimage_hashes_for_coin(coin, input)
     }
 
     #[inline]
     fn compile(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::SigningInput<'_>,
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> Self::SigningOutput {
-        Compiler::<StandardEvmContext>::compile(input, signatures, public_keys)
+        Compiler::<StandardEvmContext>::compile_for_coin(coin, input, signatures, public_keys)
     }
 
     #[inline]
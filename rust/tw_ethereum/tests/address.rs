@@ -0,0 +1,78 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry_ext::CoinEntryExt;
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::AddressError;
+use tw_coin_entry::test_utils::empty_context::EmptyCoinContext;
+use tw_encoding::hex::DecodeHex;
+use tw_ethereum::entry::EthereumEntry;
+use tw_keypair::tw::{PrivateKey, PublicKeyType};
+
+/// XDC Network displays addresses with the `xdc` prefix instead of `0x`.
+struct XdcCoinContext;
+
+impl CoinContext for XdcCoinContext {
+    fn public_key_type(&self) -> PublicKeyType {
+        PublicKeyType::Secp256k1Extended
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("xdc")
+    }
+}
+
+#[test]
+fn test_normalize_address_default_format() {
+    let normalized = EthereumEntry
+        .normalize_address(
+            &EmptyCoinContext,
+            "0xb16db98b365b1f89191996942612b14f1da4bd5f",
+            None,
+        )
+        .unwrap();
+    assert_eq!(normalized, "0xb16Db98B365B1f89191996942612B14F1Da4Bd5f");
+}
+
+#[test]
+fn test_normalize_address_with_prefix() {
+    let tests = [
+        "xdcb16db98b365b1f89191996942612b14f1da4bd5f",
+        "0xb16db98b365b1f89191996942612b14f1da4bd5f",
+    ];
+    for address in tests {
+        let normalized = EthereumEntry
+            .normalize_address(&XdcCoinContext, address, None)
+            .unwrap();
+        assert_eq!(normalized, "xdcb16Db98B365B1f89191996942612B14F1Da4Bd5f");
+    }
+
+    let err = EthereumEntry
+        .validate_address(
+            &XdcCoinContext,
+            "roninb16db98b365b1f89191996942612b14f1da4bd5f",
+            None,
+        )
+        .unwrap_err();
+    assert!(matches!(err, AddressError::MissingPrefix));
+}
+
+#[test]
+fn test_derive_address_with_prefix() {
+    let private_key = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5"
+        .decode_hex()
+        .unwrap();
+    let public_key = PrivateKey::new(private_key)
+        .unwrap()
+        .get_public_key_by_type(PublicKeyType::Secp256k1Extended)
+        .unwrap();
+
+    let address = EthereumEntry
+        .derive_address(&XdcCoinContext, public_key, Derivation::Default, None)
+        .unwrap();
+    assert_eq!(address, "xdcAc1ec44E4f0ca7D172B7803f6836De87Fb72b309");
+}
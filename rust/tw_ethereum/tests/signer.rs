@@ -5,20 +5,140 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use tw_coin_entry::coin_entry_ext::CoinEntryExt;
+use tw_coin_entry::error::SigningErrorType;
 use tw_coin_entry::test_utils::empty_context::EmptyCoinContext;
 use tw_encoding::hex::DecodeHex;
 use tw_ethereum::entry::EthereumEntry;
 
 #[test]
 fn test_sign_json() {
-    let input_json = r#"{"chainId":"AQ==","gasPrice":"1pOkAA==","gasLimit":"Ugg=","toAddress":"0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1","transaction":{"transfer":{"amount":"A0i8paFgAA=="}}})"#;
+    let input_json = r#"{"chainId":"AQ==","gasPrice":"1pOkAA==","gasLimit":"Ugg=","toAddress":"0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1","transaction":{"transfer":{"amount":"A0i8paFgAA=="}}}"#;
     let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
         .decode_hex()
         .unwrap();
 
-    EthereumEntry
+    let encoded = EthereumEntry
         .sign_json(&EmptyCoinContext, input_json, private_key)
-        .expect_err("'EthEntry::sign_json' is not supported yet");
+        .unwrap();
+    assert_eq!(encoded, "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10");
+}
+
+#[test]
+fn test_sign_json_rpc_transaction() {
+    let input_json = r#"{
+        "type": "0x2",
+        "chainId": "0x1",
+        "nonce": "0x1",
+        "from": "0x989031B3607faD4D98F881B699822BF2E966d7AD",
+        "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1",
+        "gas": "0x5208",
+        "maxFeePerGas": "0x6fc23ac00",
+        "maxPriorityFeePerGas": "0x3b9aca00",
+        "value": "0x348bca5a16000",
+        "input": "0x"
+    }"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let encoded = EthereumEntry
+        .sign_json(&EmptyCoinContext, input_json, private_key)
+        .unwrap();
+    assert_eq!(encoded, "02f8720101843b9aca008506fc23ac00825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a1600080c001a07c6b14da94fc76afed3482933cf85d143b0a08f695c288ddf6be3651853e1a23a064d1b34309bd49942f7c6b6a1937f181cb02f973b03fd4a8cef8dd69582244af");
+}
+
+#[test]
+fn test_sign_json_rpc_transaction_from_mismatch() {
+    let input_json = r#"{
+        "chainId": "0x1",
+        "from": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1",
+        "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1",
+        "gas": "0x5208",
+        "gasPrice": "0xd693a400",
+        "value": "0x348bca5a16000"
+    }"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let err = EthereumEntry
+        .sign_json(&EmptyCoinContext, input_json, private_key)
+        .unwrap_err();
+    assert_eq!(err.0, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_sign_json_erc721_approve() {
+    let input_json = r#"{"chainId":"AQ==","nonce":"Aw==","gasPrice":"1pOkAA==","gasLimit":"AYag","toAddress":"0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D","transaction":{"erc721Approve":{"spender":"0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD","tokenId":"BNI="}}}"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let encoded = EthereumEntry
+        .sign_json(&EmptyCoinContext, input_json, private_key)
+        .unwrap();
+    assert_eq!(encoded, "f8a90384d693a400830186a094bc4ca0eda7647a8ab7c2061c2e118a18a936f13d80b844095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000004d226a0b4c3de38ea6ff8152266a23d566f24251bf83bd9fcf2c24a8758bfcdceaf964aa058494fa40f531353f2f4d354eab22dbfe43010e508fdb980107834fdd746379f");
+}
+
+#[test]
+fn test_sign_json_erc1155_set_approval_for_all() {
+    let input_json = r#"{"chainId":"AQ==","nonce":"BA==","gasPrice":"1pOkAA==","gasLimit":"AYag","toAddress":"0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D","transaction":{"erc1155SetApprovalForAll":{"operator":"0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD","approved":true}}}"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let encoded = EthereumEntry
+        .sign_json(&EmptyCoinContext, input_json, private_key)
+        .unwrap();
+    assert_eq!(encoded, "f8a90484d693a400830186a094bc4ca0eda7647a8ab7c2061c2e118a18a936f13d80b844a22cb4650000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad000000000000000000000000000000000000000000000000000000000000000126a04b7e3b205bd15f9412a62d21a45ca240be91c6649ebd2cf5891818486f654922a02619cb3fe5ce3629daa07eb6a2ff316587e075bf07a080dd175517a30cac8949");
+}
+
+#[test]
+fn test_sign_json_rpc_contract_creation() {
+    // `to` is omitted, `input` is the init code.
+    let input_json = r#"{
+        "chainId": "0x1",
+        "nonce": "0x2",
+        "gas": "0x186a0",
+        "gasPrice": "0xd693a400",
+        "input": "0x600a600c600039600a6000f3602a60005260206000f3"
+    }"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let encoded = EthereumEntry
+        .sign_json(&EmptyCoinContext, input_json, private_key)
+        .unwrap();
+    assert_eq!(encoded, "f8660284d693a400830186a0808096600a600c600039600a6000f3602a60005260206000f325a01c885e5ee28b584871523fa007a9c4c6b51eaa1dd0e49998a0d76e91afab7d2ea045a31ecd3cacbad94983a3dec0953b04a70d4193e040e7777250ebb576ded35b");
+}
+
+#[test]
+fn test_sign_json_rpc_transaction_invalid() {
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+    let tests = [
+        // Missing `nonce`.
+        r#"{"chainId": "0x1", "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1", "gas": "0x5208", "gasPrice": "0xd693a400"}"#,
+        // Missing `chainId`.
+        r#"{"nonce": "0x0", "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1", "gas": "0x5208", "gasPrice": "0xd693a400"}"#,
+        // Missing `gas`.
+        r#"{"chainId": "0x1", "nonce": "0x0", "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1", "gasPrice": "0xd693a400"}"#,
+        // The type doesn't fit into a byte, although its lowest byte is `0x02`.
+        r#"{"type": "0x102", "chainId": "0x1", "nonce": "0x0", "to": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1", "gas": "0x5208", "maxFeePerGas": "0x6fc23ac00", "maxPriorityFeePerGas": "0x3b9aca00"}"#,
+        // A contract creation without the init code.
+        r#"{"chainId": "0x1", "nonce": "0x0", "gas": "0x5208", "gasPrice": "0xd693a400", "value": "0x1"}"#,
+    ];
 
-    // Expected result - "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10"
+    for input_json in tests {
+        let err = EthereumEntry
+            .sign_json(&EmptyCoinContext, input_json, private_key.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.0,
+            SigningErrorType::Error_invalid_params,
+            "{input_json}"
+        );
+    }
 }
@@ -4,19 +4,60 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::abi::custom_error::CustomError;
+use crate::abi::event::Event;
+use crate::abi::fragment::Fragment;
 use crate::abi::function::Function;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use serde::{Deserialize, Deserializer};
 use std::collections::BTreeMap;
+use std::str::FromStr;
+use tw_hash::{H256, H32};
 
 /// API building calls to contracts ABI.
-/// Consider adding missing field such as `errors`, `events` etc.
 #[derive(Clone, Debug, Default)]
 pub struct Contract {
     pub functions: BTreeMap<String, Vec<Function>>,
+    pub events: BTreeMap<String, Vec<Event>>,
+    pub errors: BTreeMap<String, Vec<CustomError>>,
 }
 
 impl Contract {
+    /// Builds a contract ABI from human-readable fragments,
+    /// e.g `function transfer(address to, uint256 amount) returns (bool)`.
+    /// See [`Fragment`] for the supported syntax.
+    pub fn from_human_readable<'a, I>(fragments: I) -> AbiResult<Contract>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut contract = Contract::default();
+        for fragment in fragments {
+            contract.add_fragment(Fragment::from_str(fragment)?);
+        }
+        Ok(contract)
+    }
+
+    /// Adds a function, an event or an error to the contract ABI.
+    pub fn add_fragment(&mut self, fragment: Fragment) {
+        match fragment {
+            Fragment::Function(fun) => self
+                .functions
+                .entry(fun.name.clone())
+                .or_default()
+                .push(fun),
+            Fragment::Event(event) => self
+                .events
+                .entry(event.name.clone())
+                .or_default()
+                .push(event),
+            Fragment::Error(error) => self
+                .errors
+                .entry(error.name.clone())
+                .or_default()
+                .push(error),
+        }
+    }
+
     /// Get the function named `name`, the first if there are overloaded versions of the same function.
     pub fn function(&self, name: &str) -> AbiResult<&Function> {
         self.functions
@@ -26,6 +67,51 @@ impl Contract {
             .next()
             .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))
     }
+
+    /// Get the `name` function overload that takes `inputs_len` arguments.
+    pub fn function_with_inputs(&self, name: &str, inputs_len: usize) -> AbiResult<&Function> {
+        self.functions
+            .get(name)
+            .into_iter()
+            .flatten()
+            .find(|f| f.inputs.len() == inputs_len)
+            .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))
+    }
+
+    /// Finds a function by the selector its call data is prefixed with.
+    /// Can be used to pick one of overloaded functions.
+    pub fn function_by_selector(&self, selector: &H32) -> Option<&Function> {
+        self.functions
+            .values()
+            .flatten()
+            .find(|fun| fun.selector() == *selector)
+    }
+
+    /// Get the event named `name`, the first if there are overloaded versions of the same event.
+    pub fn event(&self, name: &str) -> AbiResult<&Event> {
+        self.events
+            .get(name)
+            .into_iter()
+            .flatten()
+            .next()
+            .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))
+    }
+
+    /// Finds a non-anonymous event by the first topic of its log.
+    pub fn event_by_topic(&self, topic: &H256) -> Option<&Event> {
+        self.events
+            .values()
+            .flatten()
+            .find(|event| !event.anonymous && event.topic() == *topic)
+    }
+
+    /// Finds a custom error by the selector the revert data is prefixed with.
+    pub fn error_by_selector(&self, selector: &H32) -> Option<&CustomError> {
+        self.errors
+            .values()
+            .flatten()
+            .find(|error| error.selector() == *selector)
+    }
 }
 
 impl<'de> Deserialize<'de> for Contract {
@@ -33,27 +119,24 @@ impl<'de> Deserialize<'de> for Contract {
     where
         D: Deserializer<'de>,
     {
-        /// Consider adding missing field such as `errors`, `events` etc.
         #[derive(Deserialize)]
         #[serde(tag = "type", rename_all = "snake_case")]
         enum Operation {
             Function(Function),
+            Event(Event),
+            Error(CustomError),
             #[serde(other)]
             Unsupported,
         }
 
         let operations: Vec<Operation> = Vec::deserialize(deserializer)?;
 
-        let mut result = Contract {
-            functions: BTreeMap::default(),
-        };
+        let mut result = Contract::default();
         for operation in operations {
             match operation {
-                Operation::Function(fun) => result
-                    .functions
-                    .entry(fun.name.clone())
-                    .or_default()
-                    .push(fun),
+                Operation::Function(fun) => result.add_fragment(Fragment::Function(fun)),
+                Operation::Event(event) => result.add_fragment(Fragment::Event(event)),
+                Operation::Error(error) => result.add_fragment(Fragment::Error(error)),
                 Operation::Unsupported => (),
             }
         }
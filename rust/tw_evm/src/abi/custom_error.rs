@@ -0,0 +1,42 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::decode::decode_params;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::signature::short_signature;
+use crate::abi::AbiResult;
+use itertools::Itertools;
+use serde::Deserialize;
+use tw_hash::H32;
+
+/// A custom error declared as `error InsufficientBalance(uint256 available, uint256 required)`.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CustomError {
+    /// Error name.
+    pub name: String,
+    /// Error input.
+    pub inputs: Vec<Param>,
+}
+
+impl CustomError {
+    /// Returns a signature of the error, e.g `InsufficientBalance(uint256,uint256)`.
+    pub fn signature(&self) -> String {
+        let inputs = self.inputs.iter().map(|p| p.kind.to_type_long()).join(",");
+        format!("{}({inputs})", self.name)
+    }
+
+    /// Returns the selector the revert data is prefixed with.
+    pub fn selector(&self) -> H32 {
+        let param_types: Vec<_> = self.inputs.iter().map(|p| p.kind.clone()).collect();
+        short_signature(&self.name, &param_types)
+    }
+
+    /// Parses the error data (without the selector) to a list of tokens.
+    pub fn decode_input(&self, data: &[u8]) -> AbiResult<Vec<NamedToken>> {
+        decode_params(&self.inputs, data)
+    }
+}
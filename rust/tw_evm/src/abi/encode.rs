@@ -5,6 +5,7 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use tw_hash::H256;
 use tw_memory::Data;
 
@@ -17,6 +18,66 @@ pub fn encode_tokens(tokens: &[Token]) -> Data {
         .collect()
 }
 
+/// Encodes the tokens in the non-standard packed mode, i.e `abi.encodePacked(...)`.
+/// https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode
+///
+/// * Value types are encoded in-place with the minimal number of bytes, e.g `uint16` takes 2 bytes.
+/// * `bytes` and `string` are encoded in-place without length.
+/// * Array elements are padded to 32 bytes, arrays are encoded without length.
+/// * Tuple components are encoded one after another, the same as if they were passed separately.
+///
+/// Arrays of `bytes`, `string` and tuples are not supported as their packed encoding is ambiguous.
+pub fn encode_packed(tokens: &[Token]) -> AbiResult<Data> {
+    let mut result = Data::new();
+    for token in tokens {
+        encode_packed_append(&mut result, token)?;
+    }
+    Ok(result)
+}
+
+fn encode_packed_append(acc: &mut Data, token: &Token) -> AbiResult<()> {
+    match token {
+        Token::Address(address) => acc.extend_from_slice(address.as_slice()),
+        Token::FixedBytes(bytes) => acc.extend_from_slice(bytes),
+        Token::Bytes(bytes) => acc.extend_from_slice(bytes),
+        Token::String(s) => acc.extend_from_slice(s.as_bytes()),
+        Token::Int { int, bits } => {
+            let encoded = int.to_big_endian();
+            acc.extend_from_slice(&encoded[H256::LEN - bits.get() / 8..]);
+        },
+        Token::Uint { uint, bits } => {
+            let encoded = uint.to_big_endian();
+            acc.extend_from_slice(&encoded[H256::LEN - bits.get() / 8..]);
+        },
+        Token::Bool(b) => acc.push(*b as u8),
+        Token::FixedArray { arr, .. } => encode_packed_array_append(acc, arr)?,
+        Token::Array { arr, .. } => encode_packed_array_append(acc, arr)?,
+        Token::Tuple { params } => {
+            for param in params.iter() {
+                encode_packed_append(acc, &param.value)?;
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Array elements are encoded as in the standard mode, so each of them takes 32 bytes.
+fn encode_packed_array_append(acc: &mut Data, arr: &[Token]) -> AbiResult<()> {
+    for elem in arr {
+        match elem {
+            Token::Bytes(_) | Token::String(_) | Token::Tuple { .. } => {
+                return Err(AbiError(AbiErrorKind::Error_invalid_param_type))
+            },
+            Token::FixedBytes(bytes) if bytes.as_ref().len() > H256::LEN => {
+                return Err(AbiError(AbiErrorKind::Error_invalid_param_type))
+            },
+            Token::FixedArray { .. } | Token::Array { .. } => encode_packed_append(acc, elem)?,
+            _ => acc.extend(encode_tokens(std::slice::from_ref(elem))),
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 enum Mediate<'a> {
     // head
@@ -207,11 +268,12 @@ fn fixed_bytes_append(result: &mut Vec<H256>, bytes: &[u8]) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::abi::non_empty_array::{NonEmptyArray, NonEmptyBytes};
+    use crate::abi::non_empty_array::{NonEmptyArray, NonEmptyBytes, NonZeroLen};
     use crate::abi::param::Param;
     use crate::abi::param_token::NamedToken;
     use crate::abi::param_type::constructor::TypeConstructor;
     use crate::abi::param_type::ParamType;
+    use crate::abi::uint::UintBits;
     use tw_encoding::hex::DecodeHex;
     use tw_number::{I256, U256};
 
@@ -1085,4 +1147,91 @@ mod tests {
         .unwrap();
         assert_eq!(encoded, expected);
     }
+
+    #[test]
+    fn encode_packed_value_types() {
+        let tokens = [
+            Token::int(16, I256::from(-1)).unwrap(),
+            Token::uint(48, 12u64).unwrap(),
+        ];
+        let encoded = encode_packed(&tokens).unwrap();
+        assert_eq!(encoded, "ffff00000000000c".decode_hex().unwrap());
+    }
+
+    #[test]
+    fn encode_packed_mixed_types() {
+        let tokens = [
+            Token::Address("0x5FbDB2315678afecb367f032d93F642f64180aa3".into()),
+            Token::String("Hello".to_string()),
+            Token::Bool(true),
+            Token::FixedBytes(NonEmptyBytes::new(vec![0x12, 0x34]).unwrap()),
+            Token::uint(96, 1000u64).unwrap(),
+        ];
+        let encoded = encode_packed(&tokens).unwrap();
+        let expected = concat!(
+            "5fbdb2315678afecb367f032d93f642f64180aa3",
+            "48656c6c6f",
+            "01",
+            "1234",
+            "0000000000000000000003e8",
+        )
+        .decode_hex()
+        .unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_packed_arrays() {
+        let uint8 = ParamType::Uint {
+            bits: UintBits::new(8).unwrap(),
+        };
+        let int8 = ParamType::Int {
+            bits: UintBits::new(8).unwrap(),
+        };
+        let tokens = [
+            Token::Array {
+                arr: vec![Token::uint(8, 1u8).unwrap(), Token::uint(8, 2u8).unwrap()],
+                kind: uint8,
+            },
+            Token::FixedArray {
+                arr: NonEmptyArray::new(vec![Token::int(8, I256::from(-1)).unwrap()]).unwrap(),
+                kind: int8,
+            },
+            Token::Array {
+                arr: vec![Token::FixedBytes(
+                    NonEmptyBytes::new(vec![0xab, 0xcd]).unwrap(),
+                )],
+                kind: ParamType::FixedBytes {
+                    len: NonZeroLen::new(2).unwrap(),
+                },
+            },
+            Token::Array {
+                arr: vec![Token::Address(
+                    "0x5FbDB2315678afecb367f032d93F642f64180aa3".into(),
+                )],
+                kind: ParamType::Address,
+            },
+        ];
+        let encoded = encode_packed(&tokens).unwrap();
+        let expected = concat!(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "abcd000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3",
+        )
+        .decode_hex()
+        .unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_packed_array_of_strings_unsupported() {
+        let token = Token::Array {
+            arr: vec![Token::String("Hello".to_string())],
+            kind: ParamType::String,
+        };
+        let err = encode_packed(&[token]).unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_invalid_param_type);
+    }
 }
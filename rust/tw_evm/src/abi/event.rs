@@ -0,0 +1,120 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::decode::{decode_params, decode_value};
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::signature::long_signature;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use itertools::Itertools;
+use serde::Deserialize;
+use tw_hash::H256;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventParam {
+    #[serde(flatten)]
+    pub param: Param,
+    /// Whether the param is stored in the log topics rather than in the log data.
+    #[serde(default)]
+    pub indexed: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Event {
+    /// Event name.
+    pub name: String,
+    /// Event input.
+    pub inputs: Vec<EventParam>,
+    /// Whether the event signature is omitted from the log topics.
+    #[serde(default)]
+    pub anonymous: bool,
+}
+
+impl Event {
+    /// Returns a signature of the event, e.g `Transfer(address,address,uint256)`.
+    pub fn signature(&self) -> String {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.param.kind.to_type_long())
+            .join(",");
+        format!("{}({inputs})", self.name)
+    }
+
+    /// Returns the first topic of a non-anonymous event log.
+    pub fn topic(&self) -> H256 {
+        let param_types: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| input.param.kind.clone())
+            .collect();
+        long_signature(&self.name, &param_types)
+    }
+
+    /// Parses the event log to a list of tokens in the order of the event inputs.
+    ///
+    /// Indexed params of a reference type (`string`, `bytes`, arrays and tuples)
+    /// cannot be recovered as only their Keccak-256 hash is stored in the topics.
+    /// Such params are decoded as `bytes32`.
+    pub fn decode_log(&self, topics: &[H256], data: &[u8]) -> AbiResult<Vec<NamedToken>> {
+        let topics = if self.anonymous {
+            topics
+        } else {
+            let (first, rest) = topics
+                .split_first()
+                .ok_or(AbiError(AbiErrorKind::Error_decoding_data))?;
+            if *first != self.topic() {
+                return Err(AbiError(AbiErrorKind::Error_abi_mismatch));
+            }
+            rest
+        };
+
+        let indexed_count = self.inputs.iter().filter(|input| input.indexed).count();
+        if topics.len() != indexed_count {
+            return Err(AbiError(AbiErrorKind::Error_decoding_data));
+        }
+
+        let non_indexed: Vec<_> = self
+            .inputs
+            .iter()
+            .filter(|input| !input.indexed)
+            .map(|input| input.param.clone())
+            .collect();
+        let mut non_indexed_tokens = decode_params(&non_indexed, data)?.into_iter();
+        let mut topics = topics.iter();
+
+        let mut tokens = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs.iter() {
+            let token = if input.indexed {
+                let topic = topics
+                    .next()
+                    .ok_or(AbiError(AbiErrorKind::Error_decoding_data))?;
+                let value = decode_topic(&input.param.kind, topic)?;
+                NamedToken::with_param_and_token(&input.param, value)
+            } else {
+                non_indexed_tokens
+                    .next()
+                    .ok_or(AbiError(AbiErrorKind::Error_decoding_data))?
+            };
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+}
+
+fn decode_topic(kind: &ParamType, topic: &H256) -> AbiResult<Token> {
+    match kind {
+        ParamType::Bytes
+        | ParamType::String
+        | ParamType::Array { .. }
+        | ParamType::FixedArray { .. }
+        | ParamType::Tuple { .. } => Ok(Token::FixedBytes(NonEmptyBytes::new(topic.to_vec())?)),
+        _ => decode_value(kind, topic.as_slice()),
+    }
+}
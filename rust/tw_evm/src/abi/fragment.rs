@@ -0,0 +1,329 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::custom_error::CustomError;
+use crate::abi::event::{Event, EventParam};
+use crate::abi::function::Function;
+use crate::abi::param::Param;
+use crate::abi::param_type::reader::{is_identifier, parse_param_name, split_top_level, Reader};
+use crate::abi::param_type::ParamType;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use std::str::FromStr;
+
+/// Function modifiers that don't affect the ABI encoding.
+const FUNCTION_MODIFIERS: [&str; 6] = [
+    "external",
+    "public",
+    "view",
+    "pure",
+    "payable",
+    "nonpayable",
+];
+
+/// A human-readable ABI fragment.
+///
+/// Examples:
+/// - `function swap((address,uint256)[] calldata routes, bytes data) returns (uint256)`
+/// - `event Transfer(address indexed from, address indexed to, uint256 value)`
+/// - `error InsufficientBalance(uint256 available, uint256 required)`
+/// - `transfer(address,uint256)`, the `function` keyword can be omitted.
+#[derive(Clone, Debug)]
+pub enum Fragment {
+    Function(Function),
+    Event(Event),
+    Error(CustomError),
+}
+
+impl FromStr for Fragment {
+    type Err = AbiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (keyword, signature) = match s.split_once(char::is_whitespace) {
+            Some((keyword @ ("function" | "event" | "error"), signature)) => (keyword, signature),
+            _ => ("function", s),
+        };
+
+        let (name, params_str, tail) = split_signature(signature)?;
+
+        match keyword {
+            "function" => parse_function(name, params_str, tail).map(Fragment::Function),
+            "event" => parse_event(name, params_str, tail).map(Fragment::Event),
+            _ => parse_error(name, params_str, tail).map(Fragment::Error),
+        }
+    }
+}
+
+fn parse_function(name: &str, params_str: &str, tail: &str) -> AbiResult<Function> {
+    let (modifiers, outputs) = match tail.split_once("returns") {
+        Some((modifiers, outputs_str)) => {
+            let outputs_str = outputs_str
+                .trim()
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .ok_or(AbiError(AbiErrorKind::Error_invalid_abi))?;
+            (modifiers, parse_params(outputs_str)?)
+        },
+        None => (tail, Vec::default()),
+    };
+
+    if !modifiers
+        .split_whitespace()
+        .all(|modifier| FUNCTION_MODIFIERS.contains(&modifier))
+    {
+        return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+    }
+
+    Ok(Function {
+        name: name.to_string(),
+        inputs: parse_params(params_str)?,
+        outputs,
+    })
+}
+
+fn parse_event(name: &str, params_str: &str, tail: &str) -> AbiResult<Event> {
+    let anonymous = match tail.trim() {
+        "" => false,
+        "anonymous" => true,
+        _ => return Err(AbiError(AbiErrorKind::Error_invalid_abi)),
+    };
+
+    let inputs = split_top_level(params_str)?
+        .into_iter()
+        .map(|param_str| {
+            let (kind, mut words) = Reader::parse_param::<ParamType>(param_str)?;
+            let indexed = words.first() == Some(&"indexed");
+            if indexed {
+                words.remove(0);
+            }
+            let name = parse_param_name(words)?;
+            Ok(EventParam {
+                param: Param {
+                    name,
+                    kind,
+                    internal_type: None,
+                },
+                indexed,
+            })
+        })
+        .collect::<AbiResult<Vec<_>>>()?;
+
+    Ok(Event {
+        name: name.to_string(),
+        inputs,
+        anonymous,
+    })
+}
+
+fn parse_error(name: &str, params_str: &str, tail: &str) -> AbiResult<CustomError> {
+    if !tail.trim().is_empty() {
+        return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+    }
+    Ok(CustomError {
+        name: name.to_string(),
+        inputs: parse_params(params_str)?,
+    })
+}
+
+fn parse_params(params_str: &str) -> AbiResult<Vec<Param>> {
+    split_top_level(params_str)?
+        .into_iter()
+        .map(|param_str| {
+            let (kind, words) = Reader::parse_param::<ParamType>(param_str)?;
+            let name = parse_param_name(words)?;
+            Ok(Param {
+                name,
+                kind,
+                internal_type: None,
+            })
+        })
+        .collect()
+}
+
+/// Splits `name(params) tail` into the name, the params and the tail.
+fn split_signature(signature: &str) -> AbiResult<(&str, &str, &str)> {
+    let (name, rest) = signature
+        .split_once('(')
+        .ok_or(AbiError(AbiErrorKind::Error_invalid_abi))?;
+    let name = name.trim();
+    if !is_identifier(name) {
+        return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+    }
+
+    // Find the parenthesis closing the params.
+    let mut depth = 0_usize;
+    for (idx, ch) in rest.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Ok((name, &rest[..idx], &rest[idx + 1..])),
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+    Err(AbiError(AbiErrorKind::Error_invalid_abi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::contract::Contract;
+    use crate::abi::non_empty_array::NonZeroLen;
+    use crate::abi::token::Token;
+    use crate::address::Address;
+    use tw_encoding::hex::ToHex;
+    use tw_number::U256;
+
+    fn param(name: Option<&str>, kind: ParamType) -> Param {
+        Param {
+            name: name.map(str::to_string),
+            kind,
+            internal_type: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_function() {
+        let fragment = Fragment::from_str(
+            "function swap((address pool, uint256 amount)[] calldata routes, bytes data) external payable returns(uint256)",
+        )
+        .unwrap();
+        let Fragment::Function(function) = fragment else {
+            panic!("Expected a function, found: {fragment:?}");
+        };
+
+        let route = ParamType::Tuple {
+            params: vec![
+                param(Some("pool"), ParamType::Address),
+                param(Some("amount"), ParamType::u256()),
+            ],
+        };
+        assert_eq!(function.name, "swap");
+        assert_eq!(
+            function.inputs,
+            vec![
+                param(
+                    Some("routes"),
+                    ParamType::Array {
+                        kind: Box::new(route)
+                    }
+                ),
+                param(Some("data"), ParamType::Bytes),
+            ]
+        );
+        assert_eq!(function.outputs, vec![param(None, ParamType::u256())]);
+        assert_eq!(
+            function.signature(),
+            "swap((address,uint256)[],bytes):(uint256)"
+        );
+    }
+
+    #[test]
+    fn test_parse_function_without_keyword() {
+        let fragment = Fragment::from_str("transfer(address,uint256)").unwrap();
+        let Fragment::Function(function) = fragment else {
+            panic!("Expected a function, found: {fragment:?}");
+        };
+        assert_eq!(function.signature(), "transfer(address,uint256)");
+    }
+
+    #[test]
+    fn test_parse_event() {
+        let fragment = Fragment::from_str(
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        )
+        .unwrap();
+        let Fragment::Event(event) = fragment else {
+            panic!("Expected an event, found: {fragment:?}");
+        };
+
+        assert!(!event.anonymous);
+        assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+        let indexed: Vec<_> = event.inputs.iter().map(|input| input.indexed).collect();
+        assert_eq!(indexed, [true, true, false]);
+        assert_eq!(event.inputs[2].param.name.as_deref(), Some("value"));
+    }
+
+    #[test]
+    fn test_parse_anonymous_event() {
+        let fragment =
+            Fragment::from_str("event Deposit(bytes32[2] indexed, uint128) anonymous").unwrap();
+        let Fragment::Event(event) = fragment else {
+            panic!("Expected an event, found: {fragment:?}");
+        };
+
+        assert!(event.anonymous);
+        assert!(event.inputs[0].indexed);
+        assert_eq!(event.inputs[0].param.name, None);
+        assert_eq!(
+            event.inputs[0].param.kind,
+            ParamType::FixedArray {
+                kind: Box::new(ParamType::FixedBytes {
+                    len: NonZeroLen::new(32).unwrap()
+                }),
+                len: NonZeroLen::new(2).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let fragment =
+            Fragment::from_str("error InsufficientBalance(uint256 available, uint256 required)")
+                .unwrap();
+        let Fragment::Error(error) = fragment else {
+            panic!("Expected an error, found: {fragment:?}");
+        };
+        assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+    }
+
+    #[test]
+    fn test_contract_from_human_readable() {
+        let contract = Contract::from_human_readable([
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+        ])
+        .unwrap();
+
+        let encoded = contract
+            .function("transfer")
+            .unwrap()
+            .encode_input(&[
+                Token::Address(Address::from("0x5FbDB2315678afecb367f032d93F642f64180aa3")),
+                Token::u256(U256::from(1000u64)),
+            ])
+            .unwrap();
+        assert_eq!(encoded.to_hex(), "a9059cbb0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa300000000000000000000000000000000000000000000000000000000000003e8");
+
+        assert_eq!(
+            contract.event("Transfer").unwrap().topic().to_hex(),
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+        assert_eq!(
+            contract.errors.get("InsufficientBalance").unwrap()[0]
+                .selector()
+                .to_hex(),
+            "cf479181"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_fragment() {
+        let invalid = [
+            "function",
+            "function (uint256)",
+            "function transfer(address,uint256",
+            "function transfer(address,uint256) override",
+            "function balanceOf(address) returns uint256",
+            "function transfer(address indexed to)",
+            "event Transfer(address from) indexed",
+            "error Unauthorized() view",
+        ];
+        for fragment in invalid {
+            Fragment::from_str(fragment).unwrap_err();
+        }
+    }
+}
@@ -13,6 +13,7 @@ use crate::abi::token::Token;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use itertools::Itertools;
 use serde::Deserialize;
+use tw_hash::H32;
 use tw_memory::Data;
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -44,11 +45,23 @@ impl Function {
         }
     }
 
+    /// Returns the 4-byte selector the function call data is prefixed with.
+    pub fn selector(&self) -> H32 {
+        let input_param_types: Vec<_> =
+            self.inputs.iter().map(|param| param.kind.clone()).collect();
+        short_signature(&self.name, &input_param_types)
+    }
+
     /// Parses the ABI function input to a list of tokens.
     pub fn decode_input(&self, data: &[u8]) -> AbiResult<Vec<NamedToken>> {
         decode_params(&self.inputs, data)
     }
 
+    /// Parses the ABI function output, i.e the data returned by `eth_call`, to a list of tokens.
+    pub fn decode_output(&self, data: &[u8]) -> AbiResult<Vec<NamedToken>> {
+        decode_params(&self.outputs, data)
+    }
+
     /// Encodes function input to Eth ABI binary.
     pub fn encode_input(&self, tokens: &[Token]) -> AbiResult<Data> {
         // Check if the given tokens match `Self::inputs` ABI.
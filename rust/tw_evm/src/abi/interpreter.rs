@@ -0,0 +1,792 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::param_token::NamedToken;
+use crate::abi::prebuild::erc1155::Erc1155;
+use crate::abi::prebuild::erc20::Erc20;
+use crate::abi::prebuild::erc4337::Erc4337SimpleAccount;
+use crate::abi::prebuild::erc721::Erc721;
+use crate::abi::prebuild::uniswap_v3::UniswapV3SwapRouter;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use crate::address::Address;
+use itertools::Itertools;
+use tw_encoding::hex::ToHex;
+use tw_hash::H32;
+use tw_number::U256;
+
+/// Maximum nesting of smart account calls interpreted recursively.
+/// Deeper calls are described as [`ContractInterface::Unknown`].
+const RECURSION_LIMIT: usize = 10;
+
+/// Interface of the contract a call is interpreted against.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContractInterface {
+    /// A contract registered via [`CallInterpreter::register`].
+    Custom,
+    Erc20,
+    Erc721,
+    Erc1155,
+    /// eth-infinitism `SimpleAccount` and its descendants (Biconomy, Barz).
+    Erc4337Account,
+    /// Uniswap V3 `SwapRouter`.
+    UniswapV3SwapRouter,
+    /// A call of a smart account without a payload, i.e a transfer of the native coin.
+    NativeTransfer,
+    /// A call of a smart account that was not interpreted because its selector is not registered,
+    /// its data doesn't match the ABI or it's nested deeper than the recursion limit.
+    Unknown,
+}
+
+/// A property of a call the user should be warned about before signing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RiskFlag {
+    /// ERC-20 allowance of `type(uint256).max`.
+    UnlimitedApproval,
+    /// ERC-721 or ERC-1155 operator allowed to transfer all tokens of the owner.
+    ApprovalForAll,
+}
+
+/// A human-readable interpretation of a contract call.
+#[derive(Clone, Debug)]
+pub struct CallIntent {
+    /// Name of the registry entry the call matched, e.g "ERC-20".
+    pub contract_name: String,
+    pub interface: ContractInterface,
+    /// Function signature without outputs, e.g "approve(address,uint256)".
+    pub function_signature: String,
+    pub params: Vec<NamedToken>,
+    /// Example: "Approve 0x3fC9…7FAD to spend unlimited tokens".
+    pub description: String,
+    /// Risks of the call including the risks of `inner_calls`.
+    pub risks: Vec<RiskFlag>,
+    /// Calls performed on behalf of a smart account, including the ones that were not interpreted.
+    pub inner_calls: Vec<CallIntent>,
+    /// The called contract. Known for `inner_calls` only.
+    pub to: Option<Address>,
+    /// Amount of wei sent along with the call. Known for `inner_calls` only.
+    pub value: Option<U256>,
+    /// The function selector. `None` if the call has no payload.
+    pub selector: Option<H32>,
+}
+
+impl CallIntent {
+    /// An opaque intent of a call that was not interpreted.
+    fn unknown(selector: Option<H32>) -> CallIntent {
+        let description = match selector {
+            Some(selector) => format!("Unknown call {}", selector.to_hex_prefixed()),
+            None => "Unknown call".to_string(),
+        };
+        CallIntent {
+            contract_name: "Unknown".to_string(),
+            interface: ContractInterface::Unknown,
+            function_signature: String::default(),
+            params: Vec::default(),
+            description,
+            risks: Vec::default(),
+            inner_calls: Vec::default(),
+            to: None,
+            value: None,
+            selector,
+        }
+    }
+
+    /// An intent of a smart account call without a payload.
+    fn native_transfer(to: Address, value: U256) -> CallIntent {
+        CallIntent {
+            contract_name: "Native".to_string(),
+            interface: ContractInterface::NativeTransfer,
+            function_signature: String::default(),
+            params: Vec::default(),
+            description: format!("Transfer {value} wei to {to}"),
+            risks: Vec::default(),
+            inner_calls: Vec::default(),
+            to: Some(to),
+            value: Some(value),
+            selector: None,
+        }
+    }
+}
+
+/// A call performed on behalf of a smart account.
+struct InnerCall<'a> {
+    to: Address,
+    value: U256,
+    data: &'a [u8],
+}
+
+struct RegistryEntry {
+    name: String,
+    interface: ContractInterface,
+    contract: Contract,
+}
+
+/// Matches call data against a registry of contract ABIs and describes the call.
+///
+/// If several ABIs declare the same selector (e.g ERC-20 and ERC-721 `approve(address,uint256)`),
+/// custom ABIs take precedence, then the ABI of the hinted interface, then the registration order.
+/// Risk flags are computed from the standard selectors regardless of the matched ABI,
+/// so a custom ABI declaring `approve` or `setApprovalForAll` doesn't hide them.
+#[derive(Default)]
+pub struct CallInterpreter {
+    entries: Vec<RegistryEntry>,
+}
+
+impl CallInterpreter {
+    /// Creates an interpreter of the built-in ERC-20, ERC-721, ERC-1155, ERC-4337 account
+    /// and Uniswap V3 router ABIs.
+    pub fn with_prebuild() -> CallInterpreter {
+        let mut interpreter = CallInterpreter::default();
+        interpreter.add_entry("ERC-20", ContractInterface::Erc20, Erc20::contract());
+        interpreter.add_entry("ERC-721", ContractInterface::Erc721, Erc721::contract());
+        interpreter.add_entry("ERC-1155", ContractInterface::Erc1155, Erc1155::contract());
+        interpreter.add_entry(
+            "ERC-4337 Account",
+            ContractInterface::Erc4337Account,
+            Erc4337SimpleAccount::contract(),
+        );
+        interpreter.add_entry(
+            "Uniswap V3 Router",
+            ContractInterface::UniswapV3SwapRouter,
+            UniswapV3SwapRouter::contract(),
+        );
+        interpreter
+    }
+
+    /// Registers an additional contract ABI, e.g `register("Uniswap V2 Router", router_abi)`.
+    pub fn register<S: Into<String>>(&mut self, name: S, contract: Contract) {
+        self.entries.push(RegistryEntry {
+            name: name.into(),
+            interface: ContractInterface::Custom,
+            contract,
+        });
+    }
+
+    /// Interprets the call data prefixed with a function selector.
+    /// `hint` is used to choose between standards sharing the same selectors.
+    pub fn interpret(
+        &self,
+        encoded: &[u8],
+        hint: Option<ContractInterface>,
+    ) -> AbiResult<CallIntent> {
+        self.interpret_impl(encoded, hint, 0)
+    }
+
+    fn interpret_impl(
+        &self,
+        encoded: &[u8],
+        hint: Option<ContractInterface>,
+        depth: usize,
+    ) -> AbiResult<CallIntent> {
+        if encoded.len() < H32::len() {
+            return Err(AbiError(AbiErrorKind::Error_decoding_data));
+        }
+        let (selector, encoded_params) = encoded.split_at(H32::len());
+        let selector = H32::try_from(selector).expect("The length expected to be checked above");
+        if depth >= RECURSION_LIMIT {
+            return Ok(CallIntent::unknown(Some(selector)));
+        }
+
+        let (entry, function) = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let function = entry.contract.function_by_selector(&selector)?;
+                Some((entry, function))
+            })
+            .min_by_key(|(entry, _)| match entry.interface {
+                ContractInterface::Custom => 0,
+                interface if Some(interface) == hint => 1,
+                _ => 2,
+            })
+            .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))?;
+
+        let params = function.decode_input(encoded_params)?;
+
+        let mut function = function.clone();
+        function.outputs.clear();
+
+        let mut intent = CallIntent {
+            contract_name: entry.name.clone(),
+            interface: entry.interface,
+            function_signature: function.signature(),
+            params,
+            description: String::default(),
+            risks: standard_risks(&selector, encoded_params, entry.interface)?,
+            inner_calls: Vec::default(),
+            to: None,
+            value: None,
+            selector: Some(selector),
+        };
+        self.describe(&mut intent, &function.name, &entry.name, depth)?;
+        Ok(intent)
+    }
+
+    fn add_entry(&mut self, name: &str, interface: ContractInterface, contract: &Contract) {
+        self.entries.push(RegistryEntry {
+            name: name.to_string(),
+            interface,
+            contract: contract.clone(),
+        });
+    }
+
+    fn describe(
+        &self,
+        intent: &mut CallIntent,
+        function_name: &str,
+        contract_name: &str,
+        depth: usize,
+    ) -> AbiResult<()> {
+        let params = &intent.params;
+        let description = match (intent.interface, function_name) {
+            (ContractInterface::Erc20, "transfer") => format!(
+                "Transfer {} tokens to {}",
+                uint_at(params, 1)?,
+                address_at(params, 0)?
+            ),
+            (ContractInterface::Erc20, "transferFrom") => format!(
+                "Transfer {} tokens from {} to {}",
+                uint_at(params, 2)?,
+                address_at(params, 0)?,
+                address_at(params, 1)?
+            ),
+            (ContractInterface::Erc20, "approve") => {
+                let spender = address_at(params, 0)?;
+                let amount = uint_at(params, 1)?;
+                if amount == U256::MAX {
+                    format!("Approve {spender} to spend unlimited tokens")
+                } else if amount.is_zero() {
+                    format!("Revoke the allowance of {spender}")
+                } else {
+                    format!("Approve {spender} to spend {amount} tokens")
+                }
+            },
+            (ContractInterface::Erc721, "approve") => format!(
+                "Approve {} to transfer NFT #{}",
+                address_at(params, 0)?,
+                uint_at(params, 1)?
+            ),
+            (ContractInterface::Erc721, "transferFrom" | "safeTransferFrom") => format!(
+                "Transfer NFT #{} from {} to {}",
+                uint_at(params, 2)?,
+                address_at(params, 0)?,
+                address_at(params, 1)?
+            ),
+            (ContractInterface::Erc1155, "safeTransferFrom") => format!(
+                "Transfer {} of NFT #{} from {} to {}",
+                uint_at(params, 3)?,
+                uint_at(params, 2)?,
+                address_at(params, 0)?,
+                address_at(params, 1)?
+            ),
+            (ContractInterface::Erc1155, "safeBatchTransferFrom") => {
+                let ids = array_at(params, 2)?;
+                let amounts = array_at(params, 3)?;
+                // The contract reverts the call if the lengths mismatch.
+                if ids.len() != amounts.len() {
+                    return Err(AbiError(AbiErrorKind::Error_decoding_data));
+                }
+                let transfers = ids
+                    .iter()
+                    .zip(amounts.iter())
+                    .map(|(id, amount)| Ok(format!("{} of #{}", as_uint(amount)?, as_uint(id)?)))
+                    .collect::<AbiResult<Vec<_>>>()?;
+                format!(
+                    "Transfer NFTs ({}) from {} to {}",
+                    transfers.join(", "),
+                    address_at(params, 0)?,
+                    address_at(params, 1)?
+                )
+            },
+            (ContractInterface::Erc721 | ContractInterface::Erc1155, "setApprovalForAll") => {
+                let operator = address_at(params, 0)?;
+                if bool_at(params, 1)? {
+                    format!("Approve {operator} to transfer all your NFTs")
+                } else {
+                    format!("Revoke the approval of {operator} to transfer all your NFTs")
+                }
+            },
+            (ContractInterface::Erc4337Account, "execute") => {
+                let to = address_at(params, 0)?;
+                let value = uint_at(params, 1)?;
+                let data = bytes_at(params, 2)?;
+                intent.inner_calls =
+                    self.interpret_inner_calls([InnerCall { to, value, data }], depth + 1);
+                format!("Execute a call to {to} with {value} wei")
+            },
+            (ContractInterface::Erc4337Account, "executeBatch") => {
+                let dests = array_at(params, 0)?;
+                let values = array_at(params, 1)?;
+                let datas = array_at(params, 2)?;
+                // `values` may be empty if no value is sent, the contract reverts the call otherwise.
+                let values_match = values.is_empty() || values.len() == datas.len();
+                if dests.len() != datas.len() || !values_match {
+                    return Err(AbiError(AbiErrorKind::Error_decoding_data));
+                }
+                let calls = dests
+                    .iter()
+                    .zip(datas)
+                    .enumerate()
+                    .map(|(i, (to, data))| {
+                        let value = match values.get(i) {
+                            Some(value) => as_uint(value)?,
+                            None => U256::zero(),
+                        };
+                        Ok(InnerCall {
+                            to: as_address(to)?,
+                            value,
+                            data: as_bytes(data)?,
+                        })
+                    })
+                    .collect::<AbiResult<Vec<_>>>()?;
+                intent.inner_calls = self.interpret_inner_calls(calls, depth + 1);
+                format!("Execute a batch of {} calls", dests.len())
+            },
+            (ContractInterface::UniswapV3SwapRouter, _) => "Swap on Uniswap V3".to_string(),
+            (_, function_name) => format!("Call `{function_name}` on {contract_name}"),
+        };
+
+        let inner_risks = intent
+            .inner_calls
+            .iter()
+            .flat_map(|inner| inner.risks.iter().copied());
+        intent.risks = intent
+            .risks
+            .iter()
+            .copied()
+            .chain(inner_risks)
+            .unique()
+            .collect();
+        intent.description = description;
+        Ok(())
+    }
+
+    /// Interprets the calls of a smart account.
+    /// Every call is kept, so the ones that cannot be decoded are described as unknown.
+    fn interpret_inner_calls<'a, I>(&self, calls: I, depth: usize) -> Vec<CallIntent>
+    where
+        I: IntoIterator<Item = InnerCall<'a>>,
+    {
+        calls
+            .into_iter()
+            .map(|InnerCall { to, value, data }| {
+                if data.is_empty() {
+                    return CallIntent::native_transfer(to, value);
+                }
+
+                let mut intent = self.interpret_impl(data, None, depth).unwrap_or_else(|_| {
+                    let selector = data.get(..H32::len()).and_then(|s| H32::try_from(s).ok());
+                    CallIntent::unknown(selector)
+                });
+                if intent.interface == ContractInterface::Unknown {
+                    intent.description = format!("{} to {to} with {value} wei", intent.description);
+                }
+                intent.to = Some(to);
+                intent.value = Some(value);
+                intent
+            })
+            .collect()
+    }
+}
+
+/// Returns the risks of the ERC-20 `approve` and ERC-721/ERC-1155 `setApprovalForAll` calls.
+/// ERC-721 `approve` shares the selector with ERC-20 but allows transferring a single token only.
+fn standard_risks(
+    selector: &H32,
+    encoded_params: &[u8],
+    interface: ContractInterface,
+) -> AbiResult<Vec<RiskFlag>> {
+    let approve = Erc20::contract().function("approve")?;
+    let set_approval_for_all = Erc721::contract().function("setApprovalForAll")?;
+
+    let mut risks = Vec::default();
+    if *selector == approve.selector() && interface != ContractInterface::Erc721 {
+        let params = approve.decode_input(encoded_params)?;
+        if uint_at(&params, 1)? == U256::MAX {
+            risks.push(RiskFlag::UnlimitedApproval);
+        }
+    } else if *selector == set_approval_for_all.selector() {
+        let params = set_approval_for_all.decode_input(encoded_params)?;
+        if bool_at(&params, 1)? {
+            risks.push(RiskFlag::ApprovalForAll);
+        }
+    }
+    Ok(risks)
+}
+
+fn token_at(params: &[NamedToken], idx: usize) -> AbiResult<&Token> {
+    params
+        .get(idx)
+        .map(|param| &param.value)
+        .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))
+}
+
+fn address_at(params: &[NamedToken], idx: usize) -> AbiResult<Address> {
+    as_address(token_at(params, idx)?)
+}
+
+fn uint_at(params: &[NamedToken], idx: usize) -> AbiResult<U256> {
+    as_uint(token_at(params, idx)?)
+}
+
+fn bool_at(params: &[NamedToken], idx: usize) -> AbiResult<bool> {
+    match token_at(params, idx)? {
+        Token::Bool(value) => Ok(*value),
+        _ => Err(AbiError(AbiErrorKind::Error_abi_mismatch)),
+    }
+}
+
+fn bytes_at(params: &[NamedToken], idx: usize) -> AbiResult<&[u8]> {
+    as_bytes(token_at(params, idx)?)
+}
+
+fn array_at(params: &[NamedToken], idx: usize) -> AbiResult<&[Token]> {
+    match token_at(params, idx)? {
+        Token::Array { arr, .. } => Ok(arr),
+        _ => Err(AbiError(AbiErrorKind::Error_abi_mismatch)),
+    }
+}
+
+fn as_address(token: &Token) -> AbiResult<Address> {
+    match token {
+        Token::Address(addr) => Ok(*addr),
+        _ => Err(AbiError(AbiErrorKind::Error_abi_mismatch)),
+    }
+}
+
+fn as_uint(token: &Token) -> AbiResult<U256> {
+    match token {
+        Token::Uint { uint, .. } => Ok(*uint),
+        _ => Err(AbiError(AbiErrorKind::Error_abi_mismatch)),
+    }
+}
+
+fn as_bytes(token: &Token) -> AbiResult<&[u8]> {
+    match token {
+        Token::Bytes(bytes) => Ok(bytes),
+        _ => Err(AbiError(AbiErrorKind::Error_abi_mismatch)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::param_type::ParamType;
+    use crate::abi::prebuild::erc4337::ExecuteArgs;
+    use crate::abi::prebuild::{input_kind, tuple_token};
+
+    const OWNER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const SPENDER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    #[test]
+    fn test_interpret_erc20_approve() {
+        let interpreter = CallInterpreter::with_prebuild();
+
+        let encoded = Erc20::approve(Address::from(SPENDER), U256::MAX).unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::Erc20);
+        assert_eq!(intent.function_signature, "approve(address,uint256)");
+        assert_eq!(
+            intent.description,
+            "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to spend unlimited tokens"
+        );
+        assert_eq!(intent.risks, [RiskFlag::UnlimitedApproval]);
+
+        let encoded = Erc20::approve(Address::from(SPENDER), U256::from(1_000_000u64)).unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(
+            intent.description,
+            "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to spend 1000000 tokens"
+        );
+        assert!(intent.risks.is_empty());
+    }
+
+    #[test]
+    fn test_interpret_erc721_hint() {
+        let interpreter = CallInterpreter::with_prebuild();
+        // ERC-20 and ERC-721 `approve(address,uint256)` share the same selector.
+        let encoded = Erc20::approve(Address::from(SPENDER), U256::from(12u64)).unwrap();
+
+        let intent = interpreter
+            .interpret(&encoded, Some(ContractInterface::Erc721))
+            .unwrap();
+        assert_eq!(intent.interface, ContractInterface::Erc721);
+        assert_eq!(
+            intent.description,
+            "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to transfer NFT #12"
+        );
+    }
+
+    #[test]
+    fn test_interpret_set_approval_for_all() {
+        let interpreter = CallInterpreter::with_prebuild();
+        let func = Erc721::contract().function("setApprovalForAll").unwrap();
+
+        let encoded = func
+            .encode_input(&[Token::Address(Address::from(SPENDER)), Token::Bool(true)])
+            .unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(
+            intent.description,
+            "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to transfer all your NFTs"
+        );
+        assert_eq!(intent.risks, [RiskFlag::ApprovalForAll]);
+
+        let encoded = func
+            .encode_input(&[Token::Address(Address::from(SPENDER)), Token::Bool(false)])
+            .unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert!(intent.risks.is_empty());
+    }
+
+    #[test]
+    fn test_interpret_erc1155_transfer() {
+        let interpreter = CallInterpreter::with_prebuild();
+        let encoded = Erc1155::encode_safe_transfer_from(
+            Address::from(OWNER),
+            Address::from(SPENDER),
+            U256::from(12u64),
+            U256::from(5u64),
+            Vec::default(),
+        )
+        .unwrap();
+
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::Erc1155);
+        assert_eq!(intent.description, "Transfer 5 of NFT #12 from 0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F to 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD");
+    }
+
+    #[test]
+    fn test_interpret_erc4337_execute_batch() {
+        let interpreter = CallInterpreter::with_prebuild();
+        let encoded = Erc4337SimpleAccount::encode_execute_batch([
+            ExecuteArgs {
+                to: Address::from(USDC),
+                value: U256::zero(),
+                data: Erc20::approve(Address::from(SPENDER), U256::MAX).unwrap(),
+            },
+            ExecuteArgs {
+                to: Address::from(SPENDER),
+                value: U256::from(1_000u64),
+                data: Vec::default(),
+            },
+        ])
+        .unwrap();
+
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.description, "Execute a batch of 2 calls");
+        assert_eq!(intent.inner_calls.len(), 2);
+        assert_eq!(intent.inner_calls[0].interface, ContractInterface::Erc20);
+        assert_eq!(intent.inner_calls[0].to, Some(Address::from(USDC)));
+
+        let transfer = &intent.inner_calls[1];
+        assert_eq!(transfer.interface, ContractInterface::NativeTransfer);
+        assert_eq!(
+            transfer.description,
+            "Transfer 1000 wei to 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD"
+        );
+        assert_eq!(transfer.value, Some(U256::from(1_000u64)));
+        // The risks of the inner calls are propagated.
+        assert_eq!(intent.risks, [RiskFlag::UnlimitedApproval]);
+    }
+
+    #[test]
+    fn test_interpret_erc4337_execute_batch_unknown_call() {
+        let interpreter = CallInterpreter::with_prebuild();
+        let encoded = Erc4337SimpleAccount::encode_execute_batch([
+            ExecuteArgs {
+                to: Address::from(USDC),
+                value: U256::zero(),
+                data: Erc20::approve(Address::from(SPENDER), U256::MAX).unwrap(),
+            },
+            ExecuteArgs {
+                to: Address::from(SPENDER),
+                value: U256::from(5u64),
+                data: vec![0xde, 0xad, 0xbe, 0xef, 0x01],
+            },
+        ])
+        .unwrap();
+
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        // The call that cannot be decoded is not hidden.
+        assert_eq!(intent.inner_calls.len(), 2);
+        let unknown = &intent.inner_calls[1];
+        assert_eq!(unknown.interface, ContractInterface::Unknown);
+        assert_eq!(unknown.to, Some(Address::from(SPENDER)));
+        assert_eq!(unknown.value, Some(U256::from(5u64)));
+        assert_eq!(unknown.selector, Some(H32::from([0xde, 0xad, 0xbe, 0xef])));
+        assert_eq!(
+            unknown.description,
+            "Unknown call 0xdeadbeef to 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD with 5 wei"
+        );
+    }
+
+    #[test]
+    fn test_interpret_erc1155_batch_transfer_length_mismatch() {
+        let func = Erc1155::contract()
+            .function("safeBatchTransferFrom")
+            .unwrap();
+        let encoded = func
+            .encode_input(&[
+                Token::Address(Address::from(OWNER)),
+                Token::Address(Address::from(SPENDER)),
+                Token::array(
+                    ParamType::u256(),
+                    vec![Token::u256(U256::from(1u64)), Token::u256(U256::from(2u64))],
+                ),
+                Token::array(ParamType::u256(), vec![Token::u256(U256::from(10u64))]),
+                Token::Bytes(Vec::default()),
+            ])
+            .unwrap();
+
+        let interpreter = CallInterpreter::with_prebuild();
+        let err = interpreter.interpret(&encoded, None).unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_decoding_data);
+    }
+
+    #[test]
+    fn test_interpret_custom_abi() {
+        let router = Contract::from_human_readable([
+            "function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline) returns (uint256[] amounts)",
+        ])
+        .unwrap();
+        let encoded = router
+            .function("swapExactTokensForTokens")
+            .unwrap()
+            .encode_input(&[
+                Token::u256(U256::from(1_000u64)),
+                Token::u256(U256::from(900u64)),
+                Token::array(
+                    ParamType::Address,
+                    vec![
+                        Token::Address(Address::from(USDC)),
+                        Token::Address(Address::from(SPENDER)),
+                    ],
+                ),
+                Token::Address(Address::from(OWNER)),
+                Token::u256(U256::from(1_718_236_800u64)),
+            ])
+            .unwrap();
+
+        let mut interpreter = CallInterpreter::with_prebuild();
+        interpreter.register("Uniswap V2 Router", router);
+
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::Custom);
+        assert_eq!(intent.contract_name, "Uniswap V2 Router");
+        assert_eq!(
+            intent.function_signature,
+            "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)"
+        );
+        assert_eq!(
+            intent.description,
+            "Call `swapExactTokensForTokens` on Uniswap V2 Router"
+        );
+        assert_eq!(intent.params.len(), 5);
+    }
+
+    #[test]
+    fn test_interpret_custom_abi_risks() {
+        let token = Contract::from_human_readable([
+            "function approve(address spender, uint256 value) returns (bool)",
+            "function setApprovalForAll(address operator, bool approved)",
+        ])
+        .unwrap();
+        let mut interpreter = CallInterpreter::with_prebuild();
+        interpreter.register("Custom Token", token);
+
+        // The custom ABI takes precedence but doesn't hide the risks of the standard selectors.
+        let encoded = Erc20::approve(Address::from(SPENDER), U256::MAX).unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::Custom);
+        assert_eq!(intent.description, "Call `approve` on Custom Token");
+        assert_eq!(intent.risks, [RiskFlag::UnlimitedApproval]);
+
+        let encoded = Erc721::contract()
+            .function("setApprovalForAll")
+            .unwrap()
+            .encode_input(&[Token::Address(Address::from(SPENDER)), Token::Bool(true)])
+            .unwrap();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::Custom);
+        assert_eq!(intent.risks, [RiskFlag::ApprovalForAll]);
+    }
+
+    #[test]
+    fn test_interpret_uniswap_v3_swap() {
+        let func = UniswapV3SwapRouter::contract()
+            .function("exactInputSingle")
+            .unwrap();
+        let swap_params = tuple_token(
+            input_kind(func, 0).unwrap(),
+            vec![
+                Token::Address(Address::from(USDC)),
+                Token::Address(Address::from(SPENDER)),
+                Token::uint(24, 3_000u64).unwrap(),
+                Token::Address(Address::from(OWNER)),
+                Token::u256(U256::from(1_718_236_800u64)),
+                Token::u256(U256::from(1_000u64)),
+                Token::u256(U256::from(900u64)),
+                Token::uint(160, 0u64).unwrap(),
+            ],
+        )
+        .unwrap();
+        let encoded = func.encode_input(&[swap_params]).unwrap();
+        assert_eq!(encoded[..4], [0x41, 0x4b, 0xf3, 0x89]);
+
+        let interpreter = CallInterpreter::with_prebuild();
+        let intent = interpreter.interpret(&encoded, None).unwrap();
+        assert_eq!(intent.interface, ContractInterface::UniswapV3SwapRouter);
+        assert_eq!(intent.contract_name, "Uniswap V3 Router");
+        assert_eq!(
+            intent.function_signature,
+            "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))"
+        );
+        assert_eq!(intent.description, "Swap on Uniswap V3");
+        assert!(intent.risks.is_empty());
+    }
+
+    #[test]
+    fn test_interpret_recursion_limit() {
+        let mut encoded = Erc20::approve(Address::from(SPENDER), U256::MAX).unwrap();
+        for _ in 0..RECURSION_LIMIT {
+            encoded = Erc4337SimpleAccount::encode_execute(ExecuteArgs {
+                to: Address::from(USDC),
+                value: U256::zero(),
+                data: encoded,
+            })
+            .unwrap();
+        }
+
+        let interpreter = CallInterpreter::with_prebuild();
+        let mut intent = interpreter.interpret(&encoded, None).unwrap();
+        for _ in 0..RECURSION_LIMIT {
+            assert_eq!(intent.interface, ContractInterface::Erc4337Account);
+            assert_eq!(intent.inner_calls.len(), 1);
+            intent = intent.inner_calls.remove(0);
+        }
+        // The `approve` call is nested deeper than the limit.
+        assert_eq!(intent.interface, ContractInterface::Unknown);
+        assert_eq!(
+            intent.description,
+            "Unknown call 0x095ea7b3 to 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48 with 0 wei"
+        );
+    }
+
+    #[test]
+    fn test_interpret_unknown_selector() {
+        let interpreter = CallInterpreter::with_prebuild();
+        let err = interpreter
+            .interpret(&[0xde, 0xad, 0xbe, 0xef], None)
+            .unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_abi_mismatch);
+
+        let err = interpreter.interpret(&[0xde, 0xad], None).unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_decoding_data);
+    }
+}
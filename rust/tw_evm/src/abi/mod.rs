@@ -7,14 +7,19 @@
 use tw_coin_entry::error::{SigningError, SigningErrorType};
 
 pub mod contract;
+pub mod custom_error;
 pub mod decode;
 pub mod encode;
+pub mod event;
+pub mod fragment;
 pub mod function;
+pub mod interpreter;
 pub mod non_empty_array;
 pub mod param;
 pub mod param_token;
 pub mod param_type;
 pub mod prebuild;
+pub mod revert;
 pub mod signature;
 pub mod token;
 pub mod uint;
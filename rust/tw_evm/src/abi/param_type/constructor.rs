@@ -5,6 +5,7 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::abi::non_empty_array::NonZeroLen;
+use crate::abi::param::Param;
 use crate::abi::param_type::ParamType;
 use crate::abi::uint::UintBits;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
@@ -50,6 +51,9 @@ pub trait TypeConstructor: Sized {
 
     fn empty_tuple() -> AbiResult<Self>;
 
+    /// Tuple components with optional names, e.g `(uint256 amount, address)`.
+    fn tuple(components: Vec<(Option<String>, Self)>) -> AbiResult<Self>;
+
     fn custom(s: &str) -> AbiResult<Self>;
 }
 
@@ -101,6 +105,21 @@ impl TypeConstructor for ParamType {
         })
     }
 
+    fn tuple(components: Vec<(Option<String>, Self)>) -> AbiResult<Self> {
+        if components.is_empty() {
+            return Err(AbiError(AbiErrorKind::Error_invalid_param_type));
+        }
+        let params = components
+            .into_iter()
+            .map(|(name, kind)| Param {
+                name,
+                kind,
+                internal_type: None,
+            })
+            .collect();
+        Ok(ParamType::Tuple { params })
+    }
+
     fn custom(_s: &str) -> AbiResult<Self> {
         Err(AbiError(AbiErrorKind::Error_invalid_param_type))
     }
@@ -10,10 +10,13 @@ use crate::abi::uint::UintBits;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use std::str::FromStr;
 
+/// Data location keywords that can follow a param type in a human-readable signature.
+const DATA_LOCATIONS: [&str; 3] = ["memory", "calldata", "storage"];
+
 pub struct Reader;
 
 impl Reader {
-    /// Doesn't accept tuple types with specified parameters, e.g `(uint32, address)`.
+    /// Accepts tuple types with specified parameters, e.g `(uint32, address to)` or `tuple(uint32,address)[]`.
     pub fn parse_type<T: TypeConstructor>(s: &str) -> AbiResult<T> {
         // Array
         if let Some(remaining) = s.strip_suffix(']') {
@@ -28,6 +31,15 @@ impl Reader {
             return Ok(T::array(element_type));
         }
 
+        // Tuple
+        if let Some(components_str) = strip_tuple(s) {
+            let components = split_top_level(components_str)?
+                .into_iter()
+                .map(Reader::parse_tuple_component::<T>)
+                .collect::<AbiResult<Vec<_>>>()?;
+            return T::tuple(components);
+        }
+
         let all_alphanumeric = s.chars().all(|ch| ch.is_ascii_alphanumeric());
         if s.is_empty() || !all_alphanumeric {
             return Err(AbiError(AbiErrorKind::Error_invalid_param_type));
@@ -68,6 +80,105 @@ impl Reader {
             custom => T::custom(custom),
         }
     }
+
+    /// Parses a param declaration of a human-readable signature, e.g `address indexed from`
+    /// or `(uint256,bytes)[] calldata calls`.
+    /// Returns the param type and the words following it.
+    pub fn parse_param<T: TypeConstructor>(s: &str) -> AbiResult<(T, Vec<&str>)> {
+        let s = s.trim();
+        let type_end = find_type_end(s)?;
+        let (type_str, rest) = s.split_at(type_end);
+
+        let kind = Reader::parse_type(type_str)?;
+        Ok((kind, rest.split_whitespace().collect()))
+    }
+
+    fn parse_tuple_component<T: TypeConstructor>(s: &str) -> AbiResult<(Option<String>, T)> {
+        let (kind, words) = Reader::parse_param(s)?;
+        let name = parse_param_name(words)?;
+        Ok((name, kind))
+    }
+}
+
+/// Parses an optional param name skipping data location keywords.
+pub fn parse_param_name(words: Vec<&str>) -> AbiResult<Option<String>> {
+    let mut names = words
+        .into_iter()
+        .filter(|word| !DATA_LOCATIONS.contains(word));
+    let name = names.next();
+    if names.next().is_some() {
+        return Err(AbiError(AbiErrorKind::Error_invalid_param_type));
+    }
+
+    match name {
+        Some(name) if !is_identifier(name) => Err(AbiError(AbiErrorKind::Error_invalid_param_type)),
+        name => Ok(name.map(str::to_string)),
+    }
+}
+
+/// Splits the string by commas that are not enclosed in parentheses.
+/// Returns an empty list if the string is empty.
+pub fn split_top_level(s: &str) -> AbiResult<Vec<&str>> {
+    if s.trim().is_empty() {
+        return Ok(Vec::default());
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut part_start = 0;
+    for (idx, ch) in s.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(AbiError(AbiErrorKind::Error_invalid_param_type))?
+            },
+            ',' if depth == 0 => {
+                parts.push(s[part_start..idx].trim());
+                part_start = idx + 1;
+            },
+            _ => (),
+        }
+    }
+    if depth != 0 {
+        return Err(AbiError(AbiErrorKind::Error_invalid_param_type));
+    }
+    parts.push(s[part_start..].trim());
+    Ok(parts)
+}
+
+/// Returns whether the string is a valid Solidity identifier.
+pub fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    let first_valid = chars.next().map_or(false, |ch| {
+        ch.is_ascii_alphabetic() || ch == '_' || ch == '$'
+    });
+    first_valid && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+}
+
+/// Returns tuple components if the type is `(...)` or `tuple(...)`.
+fn strip_tuple(s: &str) -> Option<&str> {
+    let s = s.strip_prefix("tuple").unwrap_or(s);
+    s.strip_prefix('(')?.strip_suffix(')')
+}
+
+/// Returns the position of the first whitespace that is not enclosed in parentheses.
+fn find_type_end(s: &str) -> AbiResult<usize> {
+    let mut depth = 0_usize;
+    for (idx, ch) in s.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(AbiError(AbiErrorKind::Error_invalid_param_type))?
+            },
+            ch if ch.is_whitespace() && depth == 0 => return Ok(idx),
+            _ => (),
+        }
+    }
+    Ok(s.len())
 }
 
 fn parse_len(len_str: &str) -> AbiResult<Option<NonZeroLen>> {
@@ -101,3 +212,103 @@ fn parse_usize(usize_str: &str) -> AbiResult<Option<usize>> {
         .map(Some)
         .map_err(|_| AbiError(AbiErrorKind::Error_invalid_param_type))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::param::Param;
+    use crate::abi::param_type::ParamType;
+
+    fn named(name: &str, kind: ParamType) -> Param {
+        Param {
+            name: Some(name.to_string()),
+            kind,
+            internal_type: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_tuple() {
+        let expected = ParamType::Tuple {
+            params: vec![
+                Param::with_type(ParamType::u256()),
+                Param::with_type(ParamType::Address),
+            ],
+        };
+        assert_eq!(
+            Reader::parse_type::<ParamType>("(uint256,address)").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Reader::parse_type::<ParamType>("(uint, address)").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Reader::parse_type::<ParamType>("tuple(uint256,address)").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_nested_tuple_array() {
+        let call = ParamType::Tuple {
+            params: vec![
+                named("to", ParamType::Address),
+                named("value", ParamType::u256()),
+                named("data", ParamType::Bytes),
+            ],
+        };
+        let expected = ParamType::Array {
+            kind: Box::new(ParamType::Tuple {
+                params: vec![
+                    Param::with_type(ParamType::Array {
+                        kind: Box::new(call),
+                    }),
+                    named("flag", ParamType::Bool),
+                ],
+            }),
+        };
+
+        let actual = Reader::parse_type::<ParamType>(
+            "((address to, uint256 value, bytes memory data)[], bool flag)[]",
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_tuple_error() {
+        let invalid = [
+            "()",
+            "(uint256",
+            "uint256)",
+            "(uint256,)",
+            "(uint256 a b)",
+            "(uint256 1a)",
+            "((uint256)",
+        ];
+        for ty in invalid {
+            Reader::parse_type::<ParamType>(ty).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_parse_param() {
+        let (kind, words) = Reader::parse_param::<ParamType>(" address indexed from ").unwrap();
+        assert_eq!(kind, ParamType::Address);
+        assert_eq!(words, ["indexed", "from"]);
+
+        let (kind, words) =
+            Reader::parse_param::<ParamType>("(uint256 a, bytes b)[2] calldata").unwrap();
+        assert_eq!(
+            kind,
+            ParamType::FixedArray {
+                kind: Box::new(ParamType::Tuple {
+                    params: vec![named("a", ParamType::u256()), named("b", ParamType::Bytes)],
+                }),
+                len: NonZeroLen::new(2).unwrap(),
+            }
+        );
+        assert_eq!(words, ["calldata"]);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::prebuild::erc4337::{execute_batch_array_tokens, ExecuteArgs};
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+
+/// https://github.com/trustwallet/barz/blob/main/contracts/facets/AccountFacet.sol
+const BARZ_ACCOUNT_FACET_ABI: &str = include_str!("resource/barz.account_facet.abi.json");
+
+lazy_static! {
+    static ref BARZ_ACCOUNT_FACET: Contract = serde_json::from_str(BARZ_ACCOUNT_FACET_ABI).unwrap();
+}
+
+/// Barz account, calls are executed by its `AccountFacet`.
+pub struct BarzAccount;
+
+impl BarzAccount {
+    pub fn encode_execute(args: ExecuteArgs) -> AbiResult<Data> {
+        let func = BARZ_ACCOUNT_FACET.function("execute")?;
+        func.encode_input(&[
+            Token::Address(args.to),
+            Token::u256(args.value),
+            Token::Bytes(args.data),
+        ])
+    }
+
+    pub fn encode_execute_batch<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let func = BARZ_ACCOUNT_FACET.function("executeBatch")?;
+        func.encode_input(&execute_batch_array_tokens(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::test_utils::test_calls;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_encode_execute() {
+        let encoded = BarzAccount::encode_execute(test_calls().remove(1)).unwrap();
+        assert_eq!(encoded.to_hex(), "b61d27f60000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_execute_batch() {
+        let encoded = BarzAccount::encode_execute_batch(test_calls()).unwrap();
+        assert_eq!(encoded.to_hex(), "47e1da2a000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000120000000000000000000000000000000000000000000000000000000000000000200000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e90000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa30000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+}
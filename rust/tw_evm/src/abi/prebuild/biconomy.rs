@@ -0,0 +1,55 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::prebuild::erc4337::{execute_batch_array_tokens, ExecuteArgs};
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+
+/// https://github.com/bcnmy/scw-contracts/blob/main/contracts/smart-account/SmartAccount.sol
+/// Function names are suffixed to get selectors with leading zero bytes, which are cheaper in calldata.
+const BICONOMY_ABI: &str = include_str!("resource/biconomy.abi.json");
+
+lazy_static! {
+    static ref BICONOMY: Contract = serde_json::from_str(BICONOMY_ABI).unwrap();
+}
+
+/// Biconomy Smart Account v2.
+pub struct BiconomySmartAccount;
+
+impl BiconomySmartAccount {
+    pub fn encode_execute(args: ExecuteArgs) -> AbiResult<Data> {
+        let func = BICONOMY.function("execute_ncC")?;
+        func.encode_input(&[
+            Token::Address(args.to),
+            Token::u256(args.value),
+            Token::Bytes(args.data),
+        ])
+    }
+
+    pub fn encode_execute_batch<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let func = BICONOMY.function("executeBatch_y6U")?;
+        func.encode_input(&execute_batch_array_tokens(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::test_utils::test_calls;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_encode_execute_batch() {
+        let encoded = BiconomySmartAccount::encode_execute_batch(test_calls()).unwrap();
+        assert_eq!(encoded.to_hex(), "00004680000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000120000000000000000000000000000000000000000000000000000000000000000200000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e90000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa30000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+}
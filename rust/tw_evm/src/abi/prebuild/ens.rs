@@ -0,0 +1,224 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::function::Function;
+use crate::abi::prebuild::fixed_bytes_token;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use crate::address::Address;
+use lazy_static::lazy_static;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// Subset of the ENS registry and public resolver ABIs.
+/// Solidity: https://github.com/ensdomains/ens-contracts/tree/staging/contracts/resolvers/profiles
+const ENS_ABI: &str = include_str!("resource/ens.abi.json");
+
+/// ENS registry, deployed at the same address on Ethereum mainnet and testnets.
+/// https://docs.ens.domains/learn/deployments
+pub const ENS_REGISTRY_ADDRESS: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+lazy_static! {
+    static ref ENS: Contract = serde_json::from_str(ENS_ABI).unwrap();
+}
+
+/// ENS registry calls. The `node` argument is expected to be computed via [`crate::ens::namehash`].
+pub struct EnsRegistry;
+
+impl EnsRegistry {
+    /// Encodes `resolver(bytes32 node)`.
+    pub fn encode_resolver(node: H256) -> AbiResult<Data> {
+        let func = ENS.function("resolver")?;
+        func.encode_input(&[fixed_bytes_token(node)?])
+    }
+
+    /// Decodes the resolver address returned by `resolver(bytes32 node)`.
+    /// Zero address means the name has no resolver set.
+    pub fn decode_resolver(data: &[u8]) -> AbiResult<Address> {
+        let func = ENS.function("resolver")?;
+        match single_output(func, data)? {
+            Token::Address(addr) => Ok(addr),
+            _ => Err(AbiError(AbiErrorKind::Error_decoding_data)),
+        }
+    }
+}
+
+/// ENS resolver calls. The `node` argument is expected to be computed via [`crate::ens::namehash`].
+pub struct EnsResolver;
+
+impl EnsResolver {
+    /// Encodes `addr(bytes32 node)`.
+    /// https://docs.ens.domains/ensip/1
+    pub fn encode_addr(node: H256) -> AbiResult<Data> {
+        let func = addr_function(1)?;
+        func.encode_input(&[fixed_bytes_token(node)?])
+    }
+
+    /// Decodes the Ethereum address returned by `addr(bytes32 node)`.
+    pub fn decode_addr(data: &[u8]) -> AbiResult<Address> {
+        let func = addr_function(1)?;
+        match single_output(func, data)? {
+            Token::Address(addr) => Ok(addr),
+            _ => Err(AbiError(AbiErrorKind::Error_decoding_data)),
+        }
+    }
+
+    /// Encodes `addr(bytes32 node, uint256 coinType)`, where `coin_type` is a SLIP-44 coin type.
+    /// https://docs.ens.domains/ensip/9
+    pub fn encode_addr_with_coin_type(node: H256, coin_type: U256) -> AbiResult<Data> {
+        let func = addr_function(2)?;
+        func.encode_input(&[fixed_bytes_token(node)?, Token::u256(coin_type)])
+    }
+
+    /// Decodes the binary address returned by `addr(bytes32 node, uint256 coinType)`.
+    pub fn decode_addr_with_coin_type(data: &[u8]) -> AbiResult<Data> {
+        let func = addr_function(2)?;
+        decode_bytes(func, data)
+    }
+
+    /// Encodes `text(bytes32 node, string key)`.
+    /// https://docs.ens.domains/ensip/5
+    pub fn encode_text(node: H256, key: &str) -> AbiResult<Data> {
+        let func = ENS.function("text")?;
+        func.encode_input(&[fixed_bytes_token(node)?, Token::String(key.to_string())])
+    }
+
+    /// Decodes the text record returned by `text(bytes32 node, string key)`.
+    pub fn decode_text(data: &[u8]) -> AbiResult<String> {
+        let func = ENS.function("text")?;
+        match single_output(func, data)? {
+            Token::String(text) => Ok(text),
+            _ => Err(AbiError(AbiErrorKind::Error_decoding_data)),
+        }
+    }
+
+    /// Encodes `resolve(bytes name, bytes data)` of an ENSIP-10 wildcard resolver,
+    /// where `dns_name` is computed via [`crate::ens::dns_encode`],
+    /// and `call_data` is one of the resolver calls above.
+    /// https://docs.ens.domains/ensip/10
+    pub fn encode_resolve(dns_name: Data, call_data: Data) -> AbiResult<Data> {
+        let func = ENS.function("resolve")?;
+        func.encode_input(&[Token::Bytes(dns_name), Token::Bytes(call_data)])
+    }
+
+    /// Decodes the result of `resolve(bytes name, bytes data)`.
+    /// The result should be decoded further by the decoder corresponding to the inner call.
+    pub fn decode_resolve(data: &[u8]) -> AbiResult<Data> {
+        let func = ENS.function("resolve")?;
+        decode_bytes(func, data)
+    }
+}
+
+/// Returns the `addr` overload with the given number of inputs.
+fn addr_function(inputs_len: usize) -> AbiResult<&'static Function> {
+    ENS.function_with_inputs("addr", inputs_len)
+}
+
+fn single_output(func: &Function, data: &[u8]) -> AbiResult<Token> {
+    func.decode_output(data)?
+        .into_iter()
+        .next()
+        .map(|output| output.value)
+        .ok_or(AbiError(AbiErrorKind::Error_decoding_data))
+}
+
+fn decode_bytes(func: &Function, data: &[u8]) -> AbiResult<Data> {
+    match single_output(func, data)? {
+        Token::Bytes(bytes) => Ok(bytes),
+        _ => Err(AbiError(AbiErrorKind::Error_decoding_data)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ens::{dns_encode, namehash};
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    const VITALIK: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+    const ETH_COIN_TYPE: u64 = 60;
+
+    fn vitalik_node() -> H256 {
+        namehash("vitalik.eth").unwrap()
+    }
+
+    #[test]
+    fn test_encode_resolver() {
+        let encoded = EnsRegistry::encode_resolver(vitalik_node()).unwrap();
+        assert_eq!(
+            encoded.to_hex(),
+            "0178b8bfee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"
+        );
+    }
+
+    #[test]
+    fn test_encode_addr() {
+        let encoded = EnsResolver::encode_addr(vitalik_node()).unwrap();
+        assert_eq!(
+            encoded.to_hex(),
+            "3b3b57deee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"
+        );
+
+        let encoded =
+            EnsResolver::encode_addr_with_coin_type(vitalik_node(), U256::from(ETH_COIN_TYPE))
+                .unwrap();
+        assert_eq!(encoded.to_hex(), "f1cb7e06ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835000000000000000000000000000000000000000000000000000000000000003c");
+    }
+
+    #[test]
+    fn test_encode_text() {
+        let encoded = EnsResolver::encode_text(vitalik_node(), "com.twitter").unwrap();
+        assert_eq!(encoded.to_hex(), "59d1d43cee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a534758350000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000b636f6d2e74776974746572000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_resolve() {
+        let call_data = EnsResolver::encode_addr(vitalik_node()).unwrap();
+        let encoded =
+            EnsResolver::encode_resolve(dns_encode("vitalik.eth").unwrap(), call_data).unwrap();
+        assert_eq!(encoded.to_hex(), "9061b92300000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000d07766974616c696b03657468000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000243b3b57deee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a5347583500000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_decode_addr() {
+        let response = "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+            .decode_hex()
+            .unwrap();
+        assert_eq!(
+            EnsResolver::decode_addr(&response).unwrap(),
+            Address::from(VITALIK)
+        );
+        assert_eq!(
+            EnsRegistry::decode_resolver(&response).unwrap(),
+            Address::from(VITALIK)
+        );
+
+        let response = "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000014d8da6bf26964af9d7eed9e03e53415d37aa96045000000000000000000000000".decode_hex().unwrap();
+        let addr = EnsResolver::decode_addr_with_coin_type(&response).unwrap();
+        assert_eq!(addr.to_hex(), "d8da6bf26964af9d7eed9e03e53415d37aa96045");
+
+        // `resolve` returns the `addr(bytes32,uint256)` response wrapped into `bytes`.
+        let wrapped = EnsResolver::decode_resolve(&response).unwrap();
+        assert_eq!(wrapped, addr);
+    }
+
+    #[test]
+    fn test_decode_text() {
+        let response = "0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000e566974616c696b4275746572696e000000000000000000000000000000000000".decode_hex().unwrap();
+        assert_eq!(
+            EnsResolver::decode_text(&response).unwrap(),
+            "VitalikButerin"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_response() {
+        assert!(EnsResolver::decode_addr(&[0x01; 4]).is_err());
+        assert!(EnsResolver::decode_text(&[]).is_err());
+    }
+}
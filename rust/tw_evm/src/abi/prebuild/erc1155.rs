@@ -5,6 +5,7 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::abi::contract::Contract;
+use crate::abi::param_type::ParamType;
 use crate::abi::token::Token;
 use crate::abi::AbiResult;
 use crate::address::Address;
@@ -23,6 +24,11 @@ lazy_static! {
 pub struct Erc1155;
 
 impl Erc1155 {
+    /// Returns the contract ABI.
+    pub(crate) fn contract() -> &'static Contract {
+        &ERC1155
+    }
+
     pub fn encode_safe_transfer_from(
         from: Address,
         to: Address,
@@ -39,4 +45,32 @@ impl Erc1155 {
             Token::Bytes(data),
         ])
     }
+
+    pub fn encode_safe_batch_transfer_from(
+        from: Address,
+        to: Address,
+        token_ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Data,
+    ) -> AbiResult<Data> {
+        let func = ERC1155.function("safeBatchTransferFrom")?;
+        func.encode_input(&[
+            Token::Address(from),
+            Token::Address(to),
+            Token::array(
+                ParamType::u256(),
+                token_ids.into_iter().map(Token::u256).collect(),
+            ),
+            Token::array(
+                ParamType::u256(),
+                values.into_iter().map(Token::u256).collect(),
+            ),
+            Token::Bytes(data),
+        ])
+    }
+
+    pub fn encode_set_approval_for_all(operator: Address, approved: bool) -> AbiResult<Data> {
+        let func = ERC1155.function("setApprovalForAll")?;
+        func.encode_input(&[Token::Address(operator), Token::Bool(approved)])
+    }
 }
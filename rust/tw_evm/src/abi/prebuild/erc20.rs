@@ -23,6 +23,11 @@ lazy_static! {
 pub struct Erc20;
 
 impl Erc20 {
+    /// Returns the contract ABI.
+    pub(crate) fn contract() -> &'static Contract {
+        &ERC20
+    }
+
     pub fn transfer(recipient: Address, amount: U256) -> AbiResult<Data> {
         let func = ERC20.function("transfer")?;
         func.encode_input(&[Token::Address(recipient), Token::u256(amount)])
@@ -0,0 +1,238 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::prebuild::fixed_bytes_token;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::signature::MessageSignature;
+use crate::message::{EthMessage, MessageSigningResult};
+use lazy_static::lazy_static;
+use serde_json::{json, Value as Json};
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://eips.ethereum.org/EIPS/eip-2612
+const ERC2612_ABI: &str = include_str!("resource/erc2612.abi.json");
+/// https://github.com/makerdao/dss/blob/master/src/dai.sol
+const DAI_ABI: &str = include_str!("resource/dai.abi.json");
+
+lazy_static! {
+    static ref ERC2612: Contract = serde_json::from_str(ERC2612_ABI).unwrap();
+    static ref DAI: Contract = serde_json::from_str(DAI_ABI).unwrap();
+}
+
+/// EIP-712 domain of a token contract, e.g `{ name: "USD Coin", version: "2", chainId: 1, verifyingContract: USDC }`.
+#[derive(Clone, Debug)]
+pub struct PermitDomain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: U256,
+    pub verifying_contract: Address,
+}
+
+impl PermitDomain {
+    fn types() -> Json {
+        json!([
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" },
+        ])
+    }
+
+    fn to_json(&self) -> Json {
+        json!({
+            "name": self.name,
+            "version": self.version,
+            "chainId": self.chain_id.to_string(),
+            "verifyingContract": self.verifying_contract.to_string(),
+        })
+    }
+}
+
+/// ERC-2612 `Permit` allowing `spender` to spend `value` tokens of `owner` until `deadline`.
+#[derive(Clone, Debug)]
+pub struct Erc2612Permit {
+    pub owner: Address,
+    pub spender: Address,
+    pub value: U256,
+    /// The current `nonces(owner)` of the token contract.
+    pub nonce: U256,
+    /// Unix timestamp in seconds.
+    pub deadline: U256,
+}
+
+impl Erc2612Permit {
+    /// Returns the EIP-712 typed data to be signed by the `owner`.
+    pub fn typed_data(&self, domain: &PermitDomain) -> Json {
+        json!({
+            "types": {
+                "EIP712Domain": PermitDomain::types(),
+                "Permit": [
+                    { "name": "owner", "type": "address" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "value", "type": "uint256" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "deadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "Permit",
+            "domain": domain.to_json(),
+            "message": {
+                "owner": self.owner.to_string(),
+                "spender": self.spender.to_string(),
+                "value": self.value.to_string(),
+                "nonce": self.nonce.to_string(),
+                "deadline": self.deadline.to_string(),
+            },
+        })
+    }
+
+    /// Returns the EIP-712 hash of the typed data.
+    pub fn hash(&self, domain: &PermitDomain) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(domain))?.hash()
+    }
+
+    /// Encodes `permit(owner, spender, value, deadline, v, r, s)` signed by the `owner`.
+    pub fn encode_permit(&self, signature: &MessageSignature) -> AbiResult<Data> {
+        let func = ERC2612.function("permit")?;
+        let signature = signature.to_legacy();
+        func.encode_input(&[
+            Token::Address(self.owner),
+            Token::Address(self.spender),
+            Token::u256(self.value),
+            Token::u256(self.deadline),
+            Token::uint(8, signature.v())?,
+            fixed_bytes_token(signature.r())?,
+            fixed_bytes_token(signature.s())?,
+        ])
+    }
+}
+
+/// DAI-style `Permit` that allows or disallows `spender` to spend an unlimited amount of `holder` tokens.
+#[derive(Clone, Debug)]
+pub struct DaiPermit {
+    pub holder: Address,
+    pub spender: Address,
+    /// The current `nonces(holder)` of the token contract.
+    pub nonce: U256,
+    /// Unix timestamp in seconds, or 0 if the permit never expires.
+    pub expiry: U256,
+    pub allowed: bool,
+}
+
+impl DaiPermit {
+    /// Returns the EIP-712 typed data to be signed by the `holder`.
+    pub fn typed_data(&self, domain: &PermitDomain) -> Json {
+        json!({
+            "types": {
+                "EIP712Domain": PermitDomain::types(),
+                "Permit": [
+                    { "name": "holder", "type": "address" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "expiry", "type": "uint256" },
+                    { "name": "allowed", "type": "bool" },
+                ],
+            },
+            "primaryType": "Permit",
+            "domain": domain.to_json(),
+            "message": {
+                "holder": self.holder.to_string(),
+                "spender": self.spender.to_string(),
+                "nonce": self.nonce.to_string(),
+                "expiry": self.expiry.to_string(),
+                "allowed": self.allowed,
+            },
+        })
+    }
+
+    /// Returns the EIP-712 hash of the typed data.
+    pub fn hash(&self, domain: &PermitDomain) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(domain))?.hash()
+    }
+
+    /// Encodes `permit(holder, spender, nonce, expiry, allowed, v, r, s)` signed by the `holder`.
+    pub fn encode_permit(&self, signature: &MessageSignature) -> AbiResult<Data> {
+        let func = DAI.function("permit")?;
+        let signature = signature.to_legacy();
+        func.encode_input(&[
+            Token::Address(self.holder),
+            Token::Address(self.spender),
+            Token::u256(self.nonce),
+            Token::u256(self.expiry),
+            Token::Bool(self.allowed),
+            Token::uint(8, signature.v())?,
+            fixed_bytes_token(signature.r())?,
+            fixed_bytes_token(signature.s())?,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex::ToHex;
+
+    const OWNER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const SPENDER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+
+    #[test]
+    fn test_erc2612_permit() {
+        let domain = PermitDomain {
+            name: "USD Coin".to_string(),
+            version: "2".to_string(),
+            chain_id: U256::from(1u64),
+            verifying_contract: Address::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+        };
+        let permit = Erc2612Permit {
+            owner: Address::from(OWNER),
+            spender: Address::from(SPENDER),
+            value: U256::from(1_000_000u64),
+            nonce: U256::zero(),
+            deadline: U256::from(1_718_236_800u64),
+        };
+        assert_eq!(
+            permit.hash(&domain).unwrap().to_hex(),
+            "c6101b486f0424691c3eb7a469e97c9422bc28f9d7d0845ba0850d886e05feea"
+        );
+
+        // The signature `v` is expected to be converted to 27 or 28.
+        let signature = MessageSignature::from_str("9979249ca64940db4144c8c4572135be9e5ad8bab2859577426c7186e2919c2951397523308037666813feb589f72907c7ffeeac8eb9b6f73f0fa0e188be576200").unwrap();
+        let encoded = permit.encode_permit(&signature).unwrap();
+        assert_eq!(encoded.to_hex(), "d505accf0000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f0000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000f424000000000000000000000000000000000000000000000000000000000666a3680000000000000000000000000000000000000000000000000000000000000001b9979249ca64940db4144c8c4572135be9e5ad8bab2859577426c7186e2919c2951397523308037666813feb589f72907c7ffeeac8eb9b6f73f0fa0e188be5762");
+    }
+
+    #[test]
+    fn test_dai_permit() {
+        let domain = PermitDomain {
+            name: "Dai Stablecoin".to_string(),
+            version: "1".to_string(),
+            chain_id: U256::from(1u64),
+            verifying_contract: Address::from("0x6B175474E89094C44Da98b954EedeAC495271d0F"),
+        };
+        let permit = DaiPermit {
+            holder: Address::from(OWNER),
+            spender: Address::from(SPENDER),
+            nonce: U256::from(3u64),
+            expiry: U256::zero(),
+            allowed: true,
+        };
+        assert_eq!(
+            permit.hash(&domain).unwrap().to_hex(),
+            "d9b068434babe5a2880c14a391cd1ca1d700c0314283e98830dce5fec1df1857"
+        );
+
+        let signature = MessageSignature::from_str("bf0c1551340300fe85678274357e2ecbad798b15bab40cbfd4ac8a3a6d957f744cfb380c9b923225b38f0b3efa47a4fda0e55b4b203facf784a774964ff1fddf1c").unwrap();
+        let encoded = permit.encode_permit(&signature).unwrap();
+        assert_eq!(encoded.to_hex(), "8fcbaf0c0000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f0000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000001cbf0c1551340300fe85678274357e2ecbad798b15bab40cbfd4ac8a3a6d957f744cfb380c9b923225b38f0b3efa47a4fda0e55b4b203facf784a774964ff1fddf");
+    }
+}
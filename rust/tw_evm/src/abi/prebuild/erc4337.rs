@@ -28,9 +28,28 @@ pub struct ExecuteArgs {
     pub data: Data,
 }
 
+/// Operation type of the accounts that can either `call` or `delegatecall` the target,
+/// such as Safe and Kernel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    Call = 0,
+    DelegateCall = 1,
+}
+
+impl Operation {
+    pub fn to_token(self) -> Token {
+        Token::uint(8, self as u8).expect("8 bits is a valid uint size")
+    }
+}
+
 pub struct Erc4337SimpleAccount;
 
 impl Erc4337SimpleAccount {
+    /// Returns the contract ABI.
+    pub(crate) fn contract() -> &'static Contract {
+        &ERC4337_SIMPLE_ACCOUNT
+    }
+
     pub fn encode_execute(args: ExecuteArgs) -> AbiResult<Data> {
         let func = ERC4337_SIMPLE_ACCOUNT.function("execute")?;
         func.encode_input(&[
@@ -45,27 +64,41 @@ impl Erc4337SimpleAccount {
         I: IntoIterator<Item = ExecuteArgs>,
     {
         let func = ERC4337_SIMPLE_ACCOUNT.function("executeBatch")?;
+        func.encode_input(&execute_batch_array_tokens(args))
+    }
 
-        let args = args.into_iter();
-        let capacity = {
-            let (lower, upper) = args.size_hint();
-            upper.unwrap_or(lower)
-        };
+    /// Encodes `initialize(owner)` that is called by `SimpleAccountFactory` right after the proxy is deployed.
+    pub fn encode_initialize(owner: Address) -> AbiResult<Data> {
+        let func = ERC4337_SIMPLE_ACCOUNT.function("initialize")?;
+        func.encode_input(&[Token::Address(owner)])
+    }
+}
 
-        let mut addresses = Vec::with_capacity(capacity);
-        let mut values = Vec::with_capacity(capacity);
-        let mut datas = Vec::with_capacity(capacity);
+/// Splits the calls into `(address[] dest, uint256[] value, bytes[] func)` arrays,
+/// the batch layout used by SimpleAccount and its descendants (Biconomy, Barz).
+pub(crate) fn execute_batch_array_tokens<I>(args: I) -> [Token; 3]
+where
+    I: IntoIterator<Item = ExecuteArgs>,
+{
+    let args = args.into_iter();
+    let capacity = {
+        let (lower, upper) = args.size_hint();
+        upper.unwrap_or(lower)
+    };
 
-        for arg in args {
-            addresses.push(Token::Address(arg.to));
-            values.push(Token::u256(arg.value));
-            datas.push(Token::Bytes(arg.data));
-        }
+    let mut addresses = Vec::with_capacity(capacity);
+    let mut values = Vec::with_capacity(capacity);
+    let mut datas = Vec::with_capacity(capacity);
 
-        func.encode_input(&[
-            Token::array(ParamType::Address, addresses),
-            Token::array(ParamType::u256(), values),
-            Token::array(ParamType::Bytes, datas),
-        ])
+    for arg in args {
+        addresses.push(Token::Address(arg.to));
+        values.push(Token::u256(arg.value));
+        datas.push(Token::Bytes(arg.data));
     }
+
+    [
+        Token::array(ParamType::Address, addresses),
+        Token::array(ParamType::u256(), values),
+        Token::array(ParamType::Bytes, datas),
+    ]
 }
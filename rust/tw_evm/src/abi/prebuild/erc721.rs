@@ -23,6 +23,11 @@ lazy_static! {
 pub struct Erc721;
 
 impl Erc721 {
+    /// Returns the contract ABI.
+    pub(crate) fn contract() -> &'static Contract {
+        &ERC721
+    }
+
     pub fn encode_transfer_from(from: Address, to: Address, token_id: U256) -> AbiResult<Data> {
         let func = ERC721.function("transferFrom")?;
         func.encode_input(&[
@@ -31,4 +36,30 @@ impl Erc721 {
             Token::u256(token_id),
         ])
     }
+
+    /// Encodes `safeTransferFrom(address from, address to, uint256 tokenId, bytes data)`.
+    pub fn encode_safe_transfer_from(
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Data,
+    ) -> AbiResult<Data> {
+        let func = ERC721.function_with_inputs("safeTransferFrom", 4)?;
+        func.encode_input(&[
+            Token::Address(from),
+            Token::Address(to),
+            Token::u256(token_id),
+            Token::Bytes(data),
+        ])
+    }
+
+    pub fn encode_approve(spender: Address, token_id: U256) -> AbiResult<Data> {
+        let func = ERC721.function("approve")?;
+        func.encode_input(&[Token::Address(spender), Token::u256(token_id)])
+    }
+
+    pub fn encode_set_approval_for_all(operator: Address, approved: bool) -> AbiResult<Data> {
+        let func = ERC721.function("setApprovalForAll")?;
+        func.encode_input(&[Token::Address(operator), Token::Bool(approved)])
+    }
 }
@@ -0,0 +1,153 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::encode::encode_tokens;
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::erc4337::ExecuteArgs;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use lazy_static::lazy_static;
+use tw_hash::H256;
+use tw_memory::Data;
+
+/// https://eips.ethereum.org/EIPS/eip-7579
+const ERC7579_ABI: &str = include_str!("resource/erc7579.abi.json");
+
+lazy_static! {
+    static ref ERC7579: Contract = serde_json::from_str(ERC7579_ABI).unwrap();
+}
+
+/// The first byte of the `ModeCode`.
+/// https://github.com/erc7579/erc7579-implementation/blob/main/src/lib/ModeLib.sol
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CallType {
+    Single = 0x00,
+    Batch = 0x01,
+    DelegateCall = 0xff,
+}
+
+impl CallType {
+    /// Returns the `ModeCode` with the default exec type (revert on failure),
+    /// no mode selector and no mode payload.
+    pub fn mode_code(self) -> H256 {
+        let mut mode = H256::default();
+        mode[0] = self as u8;
+        mode
+    }
+}
+
+/// Modular smart account implementing ERC-7579, such as Kernel v3, Nexus or Safe7579.
+pub struct Erc7579Account;
+
+impl Erc7579Account {
+    /// Encodes `execute(mode, abi.encodePacked(target, value, callData))`.
+    pub fn encode_execute(args: ExecuteArgs) -> AbiResult<Data> {
+        let mut execution = args.to.as_slice().to_vec();
+        execution.extend_from_slice(args.value.to_big_endian().as_slice());
+        execution.extend_from_slice(&args.data);
+
+        Self::encode_execute_with_mode(CallType::Single, execution)
+    }
+
+    /// Encodes `execute(mode, abi.encodePacked(target, callData))`.
+    /// Fails if `args.value` is not zero, as a delegate call cannot transfer value.
+    pub fn encode_execute_delegate_call(args: ExecuteArgs) -> AbiResult<Data> {
+        if !args.value.is_zero() {
+            return Err(AbiError(AbiErrorKind::Error_invalid_uint_value));
+        }
+
+        let mut execution = args.to.as_slice().to_vec();
+        execution.extend_from_slice(&args.data);
+
+        Self::encode_execute_with_mode(CallType::DelegateCall, execution)
+    }
+
+    /// Encodes `execute(mode, abi.encode(Execution[]))`,
+    /// where `Execution` is `(address target, uint256 value, bytes callData)`.
+    pub fn encode_execute_batch<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let executions: Vec<_> = args
+            .into_iter()
+            .map(|arg| Token::Tuple {
+                params: vec![
+                    unnamed_token(Token::Address(arg.to)),
+                    unnamed_token(Token::u256(arg.value)),
+                    unnamed_token(Token::Bytes(arg.data)),
+                ],
+            })
+            .collect();
+        let execution_kind = ParamType::Tuple {
+            params: vec![
+                unnamed_param(ParamType::Address),
+                unnamed_param(ParamType::u256()),
+                unnamed_param(ParamType::Bytes),
+            ],
+        };
+
+        let execution = encode_tokens(&[Token::array(execution_kind, executions)]);
+        Self::encode_execute_with_mode(CallType::Batch, execution)
+    }
+
+    fn encode_execute_with_mode(call_type: CallType, execution: Data) -> AbiResult<Data> {
+        let func = ERC7579.function("execute")?;
+        let mode =
+            NonEmptyBytes::new(call_type.mode_code().to_vec()).expect("H256 must not be empty");
+        func.encode_input(&[Token::FixedBytes(mode), Token::Bytes(execution)])
+    }
+}
+
+fn unnamed_param(kind: ParamType) -> Param {
+    Param {
+        name: None,
+        kind,
+        internal_type: None,
+    }
+}
+
+fn unnamed_token(value: Token) -> NamedToken {
+    NamedToken {
+        name: None,
+        value,
+        internal_type: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::test_utils::test_calls;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_encode_execute_single() {
+        let encoded = Erc7579Account::encode_execute(test_calls().remove(0)).unwrap();
+        assert_eq!(encoded.to_hex(), "e9ae5c5300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000003461061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_execute_batch() {
+        let encoded = Erc7579Account::encode_execute_batch(test_calls()).unwrap();
+        assert_eq!(encoded.to_hex(), "e9ae5c530100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000001e000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000c000000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_execute_delegate_call() {
+        let encoded = Erc7579Account::encode_execute_delegate_call(test_calls().remove(1)).unwrap();
+        assert_eq!(encoded.to_hex(), "e9ae5c53ff00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000585fbdb2315678afecb367f032d93f642f64180aa3a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e80000000000000000");
+    }
+
+    #[test]
+    fn test_encode_execute_delegate_call_with_value() {
+        let err = Erc7579Account::encode_execute_delegate_call(test_calls().remove(0)).unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_invalid_uint_value);
+    }
+}
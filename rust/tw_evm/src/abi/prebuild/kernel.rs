@@ -0,0 +1,94 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::erc4337::{ExecuteArgs, Operation};
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use lazy_static::lazy_static;
+use tw_memory::Data;
+
+/// https://github.com/zerodevapp/kernel/blob/v2.1/src/abstract/KernelStorage.sol
+/// Kernel v3 implements ERC-7579, see [`crate::abi::prebuild::erc7579`].
+const KERNEL_ABI: &str = include_str!("resource/kernel.abi.json");
+
+lazy_static! {
+    static ref KERNEL: Contract = serde_json::from_str(KERNEL_ABI).unwrap();
+}
+
+/// ZeroDev Kernel v2 account.
+pub struct Kernel;
+
+impl Kernel {
+    pub fn encode_execute(args: ExecuteArgs, operation: Operation) -> AbiResult<Data> {
+        let func = KERNEL.function("execute")?;
+        func.encode_input(&[
+            Token::Address(args.to),
+            Token::u256(args.value),
+            Token::Bytes(args.data),
+            operation.to_token(),
+        ])
+    }
+
+    pub fn encode_execute_batch<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let func = KERNEL.function("executeBatch")?;
+
+        // `executeBatch(Call[] calls)`, where `Call` is `(address to, uint256 value, bytes data)`.
+        // Tuple tokens must be named as in the ABI.
+        let Some(ParamType::Array { kind: call_kind }) =
+            func.inputs.first().map(|param| &param.kind)
+        else {
+            return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+        };
+        let ParamType::Tuple {
+            params: call_params,
+        } = call_kind.as_ref()
+        else {
+            return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+        };
+        let [to_param, value_param, data_param] = call_params.as_slice() else {
+            return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+        };
+
+        let calls = args
+            .into_iter()
+            .map(|arg| Token::Tuple {
+                params: vec![
+                    NamedToken::with_param_and_token(to_param, Token::Address(arg.to)),
+                    NamedToken::with_param_and_token(value_param, Token::u256(arg.value)),
+                    NamedToken::with_param_and_token(data_param, Token::Bytes(arg.data)),
+                ],
+            })
+            .collect();
+
+        func.encode_input(&[Token::array(call_kind.as_ref().clone(), calls)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::test_utils::test_calls;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_encode_execute_delegate_call() {
+        let encoded =
+            Kernel::encode_execute(test_calls().remove(1), Operation::DelegateCall).unwrap();
+        assert_eq!(encoded.to_hex(), "519454470000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa30000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_execute_batch() {
+        let encoded = Kernel::encode_execute_batch(test_calls()).unwrap();
+        assert_eq!(encoded.to_hex(), "34fcd5be00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000c000000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000");
+    }
+}
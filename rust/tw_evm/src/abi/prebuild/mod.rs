@@ -4,7 +4,68 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+pub mod barz;
+pub mod biconomy;
+pub mod ens;
 pub mod erc1155;
 pub mod erc20;
+pub mod erc2612;
 pub mod erc4337;
 pub mod erc721;
+pub mod erc7579;
+pub mod kernel;
+pub mod multicall3;
+pub mod permit2;
+pub mod safe;
+#[cfg(test)]
+mod test_utils;
+pub mod uniswap_v3;
+
+use crate::abi::function::Function;
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use tw_hash::H256;
+
+/// Returns the type of the function input at `idx`.
+pub(crate) fn input_kind(func: &Function, idx: usize) -> AbiResult<&ParamType> {
+    func.inputs
+        .get(idx)
+        .map(|param| &param.kind)
+        .ok_or(AbiError(AbiErrorKind::Error_invalid_abi))
+}
+
+/// Returns the type of the tuple component at `idx`.
+pub(crate) fn tuple_component_kind(kind: &ParamType, idx: usize) -> AbiResult<&ParamType> {
+    match kind {
+        ParamType::Tuple { params } => params
+            .get(idx)
+            .map(|param| &param.kind)
+            .ok_or(AbiError(AbiErrorKind::Error_invalid_abi)),
+        _ => Err(AbiError(AbiErrorKind::Error_invalid_abi)),
+    }
+}
+
+/// Builds a tuple of the given `kind`.
+/// Tuple tokens must be named as in the ABI, otherwise `Function::encode_input` fails.
+pub(crate) fn tuple_token(kind: &ParamType, values: Vec<Token>) -> AbiResult<Token> {
+    let ParamType::Tuple { params } = kind else {
+        return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+    };
+    if params.len() != values.len() {
+        return Err(AbiError(AbiErrorKind::Error_abi_mismatch));
+    }
+    let params = params
+        .iter()
+        .zip(values)
+        .map(|(param, value)| NamedToken::with_param_and_token(param, value))
+        .collect();
+    Ok(Token::Tuple { params })
+}
+
+/// Returns a `bytes32` token.
+pub(crate) fn fixed_bytes_token(bytes: H256) -> AbiResult<Token> {
+    Ok(Token::FixedBytes(NonEmptyBytes::new(bytes.into_vec())?))
+}
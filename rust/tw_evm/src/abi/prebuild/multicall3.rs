@@ -0,0 +1,155 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::{input_kind, tuple_token};
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use crate::address::Address;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://github.com/mds1/multicall/blob/main/src/Multicall3.sol
+const MULTICALL3_ABI: &str = include_str!("resource/multicall3.abi.json");
+
+/// `Multicall3` contract, deployed at the same address on most of the EVM chains.
+/// https://www.multicall3.com/deployments
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+lazy_static! {
+    static ref MULTICALL3: Contract = serde_json::from_str(MULTICALL3_ABI).unwrap();
+}
+
+/// A call without value.
+pub struct Call3 {
+    pub target: Address,
+    /// Whether the batch should proceed if this call reverts.
+    pub allow_failure: bool,
+    pub call_data: Data,
+}
+
+/// A call transferring `value` of the native coin.
+pub struct Call3Value {
+    pub target: Address,
+    /// Whether the batch should proceed if this call reverts.
+    pub allow_failure: bool,
+    pub value: U256,
+    pub call_data: Data,
+}
+
+/// Note that the calls are made by `Multicall3` itself, i.e `msg.sender` is the `Multicall3` contract.
+pub struct Multicall3;
+
+impl Multicall3 {
+    /// Encodes `aggregate3(Call3[] calls)`.
+    pub fn encode_aggregate3<I>(calls: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = Call3>,
+    {
+        let func = MULTICALL3.function("aggregate3")?;
+        let call_kind = array_element_kind(input_kind(func, 0)?)?;
+
+        let calls = calls
+            .into_iter()
+            .map(|call| {
+                tuple_token(
+                    call_kind,
+                    vec![
+                        Token::Address(call.target),
+                        Token::Bool(call.allow_failure),
+                        Token::Bytes(call.call_data),
+                    ],
+                )
+            })
+            .collect::<AbiResult<Vec<_>>>()?;
+        func.encode_input(&[Token::array(call_kind.clone(), calls)])
+    }
+
+    /// Encodes `aggregate3Value(Call3Value[] calls)`.
+    /// The transaction value must be equal to the sum of the call values.
+    pub fn encode_aggregate3_value<I>(calls: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = Call3Value>,
+    {
+        let func = MULTICALL3.function("aggregate3Value")?;
+        let call_kind = array_element_kind(input_kind(func, 0)?)?;
+
+        let calls = calls
+            .into_iter()
+            .map(|call| {
+                tuple_token(
+                    call_kind,
+                    vec![
+                        Token::Address(call.target),
+                        Token::Bool(call.allow_failure),
+                        Token::u256(call.value),
+                        Token::Bytes(call.call_data),
+                    ],
+                )
+            })
+            .collect::<AbiResult<Vec<_>>>()?;
+        func.encode_input(&[Token::array(call_kind.clone(), calls)])
+    }
+}
+
+fn array_element_kind(kind: &ParamType) -> AbiResult<&ParamType> {
+    match kind {
+        ParamType::Array { kind } => Ok(kind),
+        _ => Err(AbiError(AbiErrorKind::Error_invalid_abi)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::erc20::Erc20;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const ROUTER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+
+    #[test]
+    fn test_encode_aggregate3() {
+        let approve = Erc20::approve(Address::from(ROUTER), U256::from(1_000u64)).unwrap();
+        let encoded = Multicall3::encode_aggregate3([
+            Call3 {
+                target: Address::from(USDC),
+                allow_failure: false,
+                call_data: approve,
+            },
+            Call3 {
+                target: Address::from(ROUTER),
+                allow_failure: false,
+                call_data: "deadbeef".decode_hex().unwrap(),
+            },
+        ])
+        .unwrap();
+        assert_eq!(encoded.to_hex(), "82ad56cb0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000120000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000044095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e8000000000000000000000000000000000000000000000000000000000000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000004deadbeef00000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_encode_aggregate3_value() {
+        let approve = Erc20::approve(Address::from(ROUTER), U256::from(1_000u64)).unwrap();
+        let encoded = Multicall3::encode_aggregate3_value([
+            Call3Value {
+                target: Address::from(USDC),
+                allow_failure: false,
+                value: U256::zero(),
+                call_data: approve,
+            },
+            Call3Value {
+                target: Address::from(ROUTER),
+                allow_failure: false,
+                value: U256::from(5_000u64),
+                call_data: "deadbeef".decode_hex().unwrap(),
+            },
+        ])
+        .unwrap();
+        assert_eq!(encoded.to_hex(), "174dea710000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000140000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000044095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e8000000000000000000000000000000000000000000000000000000000000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000138800000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000004deadbeef00000000000000000000000000000000000000000000000000000000");
+    }
+}
@@ -0,0 +1,481 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::function::Function;
+use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::{input_kind, tuple_component_kind, tuple_token};
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use crate::address::Address;
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::signature::MessageSignature;
+use crate::message::{EthMessage, MessageSigningError, MessageSigningResult};
+use lazy_static::lazy_static;
+use serde_json::{json, Value as Json};
+use tw_hash::{H256, H32};
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://github.com/Uniswap/permit2/blob/main/src/Permit2.sol
+const PERMIT2_ABI: &str = include_str!("resource/permit2.abi.json");
+
+/// `Permit2` contract, deployed at the same address on every supported chain.
+pub const PERMIT2_ADDRESS: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+
+/// `permit(address,((address,uint160,uint48,uint48),address,uint256),bytes)`.
+const PERMIT_SINGLE_SELECTOR: [u8; 4] = [0x2b, 0x67, 0xb5, 0x70];
+/// `permit(address,((address,uint160,uint48,uint48)[],address,uint256),bytes)`.
+const PERMIT_BATCH_SELECTOR: [u8; 4] = [0x2a, 0x2d, 0x80, 0xd1];
+
+lazy_static! {
+    static ref PERMIT2: Contract = serde_json::from_str(PERMIT2_ABI).unwrap();
+}
+
+/// Allowance of `spender` to transfer up to `amount` of `token` until `expiration`.
+#[derive(Clone, Debug)]
+pub struct PermitDetails {
+    pub token: Address,
+    /// `uint160` amount.
+    pub amount: U256,
+    /// `uint48` unix timestamp in seconds, or 0 to expire at the end of the block.
+    pub expiration: U256,
+    /// `uint48` nonce of the `(owner, token, spender)` allowance.
+    pub nonce: U256,
+}
+
+impl PermitDetails {
+    fn types() -> Json {
+        json!([
+            { "name": "token", "type": "address" },
+            { "name": "amount", "type": "uint160" },
+            { "name": "expiration", "type": "uint48" },
+            { "name": "nonce", "type": "uint48" },
+        ])
+    }
+
+    /// Checks that the values fit into their `uint160` and `uint48` types,
+    /// as the typed data doesn't limit the values on its own.
+    fn validate(&self) -> MessageSigningResult<()> {
+        let fits = fits_uint(160, self.amount)
+            && fits_uint(48, self.expiration)
+            && fits_uint(48, self.nonce);
+        if !fits {
+            return Err(MessageSigningError::InvalidParameterValue);
+        }
+        Ok(())
+    }
+
+    fn to_json(&self) -> Json {
+        json!({
+            "token": self.token.to_string(),
+            "amount": self.amount.to_string(),
+            "expiration": self.expiration.to_string(),
+            "nonce": self.nonce.to_string(),
+        })
+    }
+
+    fn to_token(&self, kind: &ParamType) -> AbiResult<Token> {
+        tuple_token(
+            kind,
+            vec![
+                Token::Address(self.token),
+                uint_token(160, self.amount)?,
+                uint_token(48, self.expiration)?,
+                uint_token(48, self.nonce)?,
+            ],
+        )
+    }
+}
+
+/// `AllowanceTransfer` permit of a single token.
+#[derive(Clone, Debug)]
+pub struct PermitSingle {
+    pub details: PermitDetails,
+    pub spender: Address,
+    /// Unix timestamp in seconds.
+    pub sig_deadline: U256,
+}
+
+impl PermitSingle {
+    /// Returns the EIP-712 typed data to be signed by the token owner.
+    pub fn typed_data(&self, chain_id: U256) -> MessageSigningResult<Json> {
+        self.details.validate()?;
+        Ok(json!({
+            "types": {
+                "EIP712Domain": domain_types(),
+                "PermitDetails": PermitDetails::types(),
+                "PermitSingle": [
+                    { "name": "details", "type": "PermitDetails" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "sigDeadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "PermitSingle",
+            "domain": domain(chain_id),
+            "message": {
+                "details": self.details.to_json(),
+                "spender": self.spender.to_string(),
+                "sigDeadline": self.sig_deadline.to_string(),
+            },
+        }))
+    }
+
+    /// Returns the EIP-712 hash of the typed data.
+    pub fn hash(&self, chain_id: U256) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(chain_id)?)?.hash()
+    }
+
+    /// Encodes `permit(owner, permitSingle, signature)` signed by the `owner`.
+    pub fn encode_permit(&self, owner: Address, signature: &MessageSignature) -> AbiResult<Data> {
+        let func = permit2_function(PERMIT_SINGLE_SELECTOR)?;
+        let permit_kind = input_kind(func, 1)?;
+        let details_kind = tuple_component_kind(permit_kind, 0)?;
+
+        let permit = tuple_token(
+            permit_kind,
+            vec![
+                self.details.to_token(details_kind)?,
+                Token::Address(self.spender),
+                Token::u256(self.sig_deadline),
+            ],
+        )?;
+        func.encode_input(&[
+            Token::Address(owner),
+            permit,
+            Token::Bytes(signature.to_legacy().to_bytes().into_vec()),
+        ])
+    }
+}
+
+/// `AllowanceTransfer` permit of several tokens at once.
+#[derive(Clone, Debug)]
+pub struct PermitBatch {
+    pub details: Vec<PermitDetails>,
+    pub spender: Address,
+    /// Unix timestamp in seconds.
+    pub sig_deadline: U256,
+}
+
+impl PermitBatch {
+    /// Returns the EIP-712 typed data to be signed by the tokens owner.
+    pub fn typed_data(&self, chain_id: U256) -> MessageSigningResult<Json> {
+        let details = self
+            .details
+            .iter()
+            .map(|details| details.validate().map(|_| details.to_json()))
+            .collect::<MessageSigningResult<Vec<_>>>()?;
+        Ok(json!({
+            "types": {
+                "EIP712Domain": domain_types(),
+                "PermitDetails": PermitDetails::types(),
+                "PermitBatch": [
+                    { "name": "details", "type": "PermitDetails[]" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "sigDeadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "PermitBatch",
+            "domain": domain(chain_id),
+            "message": {
+                "details": details,
+                "spender": self.spender.to_string(),
+                "sigDeadline": self.sig_deadline.to_string(),
+            },
+        }))
+    }
+
+    /// Returns the EIP-712 hash of the typed data.
+    pub fn hash(&self, chain_id: U256) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(chain_id)?)?.hash()
+    }
+
+    /// Encodes `permit(owner, permitBatch, signature)` signed by the `owner`.
+    pub fn encode_permit(&self, owner: Address, signature: &MessageSignature) -> AbiResult<Data> {
+        let func = permit2_function(PERMIT_BATCH_SELECTOR)?;
+        let permit_kind = input_kind(func, 1)?;
+        let ParamType::Array { kind: details_kind } = tuple_component_kind(permit_kind, 0)? else {
+            return Err(AbiError(AbiErrorKind::Error_invalid_abi));
+        };
+
+        let details = self
+            .details
+            .iter()
+            .map(|details| details.to_token(details_kind))
+            .collect::<AbiResult<Vec<_>>>()?;
+        let permit = tuple_token(
+            permit_kind,
+            vec![
+                Token::array(details_kind.as_ref().clone(), details),
+                Token::Address(self.spender),
+                Token::u256(self.sig_deadline),
+            ],
+        )?;
+        func.encode_input(&[
+            Token::Address(owner),
+            permit,
+            Token::Bytes(signature.to_legacy().to_bytes().into_vec()),
+        ])
+    }
+}
+
+/// `SignatureTransfer` one-time permit allowing `spender` to transfer up to `amount` of `token`.
+#[derive(Clone, Debug)]
+pub struct PermitTransferFrom {
+    pub token: Address,
+    pub amount: U256,
+    /// The account that will call `permitTransferFrom`.
+    /// It is not a part of the call data, but is signed as `msg.sender`.
+    pub spender: Address,
+    /// Unordered nonce, any unused value can be taken.
+    pub nonce: U256,
+    /// Unix timestamp in seconds.
+    pub deadline: U256,
+}
+
+impl PermitTransferFrom {
+    /// Returns the EIP-712 typed data to be signed by the token owner.
+    pub fn typed_data(&self, chain_id: U256) -> Json {
+        json!({
+            "types": {
+                "EIP712Domain": domain_types(),
+                "TokenPermissions": [
+                    { "name": "token", "type": "address" },
+                    { "name": "amount", "type": "uint256" },
+                ],
+                "PermitTransferFrom": [
+                    { "name": "permitted", "type": "TokenPermissions" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "deadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "PermitTransferFrom",
+            "domain": domain(chain_id),
+            "message": {
+                "permitted": {
+                    "token": self.token.to_string(),
+                    "amount": self.amount.to_string(),
+                },
+                "spender": self.spender.to_string(),
+                "nonce": self.nonce.to_string(),
+                "deadline": self.deadline.to_string(),
+            },
+        })
+    }
+
+    /// Returns the EIP-712 hash of the typed data.
+    pub fn hash(&self, chain_id: U256) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(chain_id))?.hash()
+    }
+
+    /// Encodes `permitTransferFrom(permit, transferDetails, owner, signature)` to be sent by the `spender`
+    /// to transfer `requested_amount` of tokens to the `to` recipient.
+    pub fn encode_permit_transfer_from(
+        &self,
+        to: Address,
+        requested_amount: U256,
+        owner: Address,
+        signature: &MessageSignature,
+    ) -> AbiResult<Data> {
+        let func = PERMIT2.function("permitTransferFrom")?;
+        let permit_kind = input_kind(func, 0)?;
+        let permitted_kind = tuple_component_kind(permit_kind, 0)?;
+        let transfer_details_kind = input_kind(func, 1)?;
+
+        let permitted = tuple_token(
+            permitted_kind,
+            vec![Token::Address(self.token), Token::u256(self.amount)],
+        )?;
+        let permit = tuple_token(
+            permit_kind,
+            vec![
+                permitted,
+                Token::u256(self.nonce),
+                Token::u256(self.deadline),
+            ],
+        )?;
+        let transfer_details = tuple_token(
+            transfer_details_kind,
+            vec![Token::Address(to), Token::u256(requested_amount)],
+        )?;
+        func.encode_input(&[
+            permit,
+            transfer_details,
+            Token::Address(owner),
+            Token::Bytes(signature.to_legacy().to_bytes().into_vec()),
+        ])
+    }
+}
+
+/// `Permit2` domain doesn't have a version.
+fn domain_types() -> Json {
+    json!([
+        { "name": "name", "type": "string" },
+        { "name": "chainId", "type": "uint256" },
+        { "name": "verifyingContract", "type": "address" },
+    ])
+}
+
+fn domain(chain_id: U256) -> Json {
+    json!({
+        "name": "Permit2",
+        "chainId": chain_id.to_string(),
+        "verifyingContract": PERMIT2_ADDRESS,
+    })
+}
+
+/// `permit` is overloaded, so the function is looked up by its selector.
+fn permit2_function(selector: [u8; 4]) -> AbiResult<&'static Function> {
+    PERMIT2
+        .function_by_selector(&H32::from(selector))
+        .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))
+}
+
+fn fits_uint(bits: usize, value: U256) -> bool {
+    value.bits() <= bits
+}
+
+fn uint_token(bits: usize, value: U256) -> AbiResult<Token> {
+    if !fits_uint(bits, value) {
+        return Err(AbiError(AbiErrorKind::Error_invalid_uint_value));
+    }
+    Token::uint(bits, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex::ToHex;
+
+    const OWNER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const SPENDER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const DAI: &str = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+
+    fn usdc_details() -> PermitDetails {
+        PermitDetails {
+            token: Address::from(USDC),
+            amount: U256::from_str("1461501637330902918203684832716283019655932542975").unwrap(),
+            expiration: U256::from(1_718_236_800u64),
+            nonce: U256::zero(),
+        }
+    }
+
+    #[test]
+    fn test_permit_single() {
+        let permit = PermitSingle {
+            details: usdc_details(),
+            spender: Address::from(SPENDER),
+            sig_deadline: U256::from(1_718_236_800u64),
+        };
+        assert_eq!(
+            permit.hash(U256::from(1u64)).unwrap().to_hex(),
+            "2d25ef3f5a1f86f957a5d7b32e113ea650ca8241e6f4d28de827c1be80677b76"
+        );
+
+        let signature = MessageSignature::from_str("2f7ca0f7f5f4798d30327a1d8dc31bd4757bc8fb311abf7d0617652cb44f91300f6bb8d8da3f90046c075001ad42bd42bbbd44ade4b4ee8dc232fda9e757b1ad00").unwrap();
+        let encoded = permit
+            .encode_permit(Address::from(OWNER), &signature)
+            .unwrap();
+        assert_eq!(encoded.to_hex(), "2b67b5700000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48000000000000000000000000ffffffffffffffffffffffffffffffffffffffff00000000000000000000000000000000000000000000000000000000666a368000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000666a3680000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000412f7ca0f7f5f4798d30327a1d8dc31bd4757bc8fb311abf7d0617652cb44f91300f6bb8d8da3f90046c075001ad42bd42bbbd44ade4b4ee8dc232fda9e757b1ad1b00000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_permit_batch() {
+        let permit = PermitBatch {
+            details: vec![
+                usdc_details(),
+                PermitDetails {
+                    token: Address::from(DAI),
+                    amount: U256::from(1_000_000_000_000_000_000u64),
+                    expiration: U256::from(1_718_236_800u64),
+                    nonce: U256::from(5u64),
+                },
+            ],
+            spender: Address::from(SPENDER),
+            sig_deadline: U256::from(1_718_236_800u64),
+        };
+        assert_eq!(
+            permit.hash(U256::from(1u64)).unwrap().to_hex(),
+            "dcd17171d9f52c1c95436dfe744aba6bb8b564f4d494fe909099ccdc3944e014"
+        );
+
+        let signature = MessageSignature::from_str("2d4731ab211d539876f58497f6dd6059d66b5ee0285f02c9c270bfff7e63e41b75cf884b0e85fdf68f71d5ec3f85ff33c89ceac6f4015d9e64dcbd678939631801").unwrap();
+        let encoded = permit
+            .encode_permit(Address::from(OWNER), &signature)
+            .unwrap();
+        assert_eq!(encoded.to_hex(), "2a2d80d10000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000001e000000000000000000000000000000000000000000000000000000000000000600000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000666a36800000000000000000000000000000000000000000000000000000000000000002000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48000000000000000000000000ffffffffffffffffffffffffffffffffffffffff00000000000000000000000000000000000000000000000000000000666a368000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006b175474e89094c44da98b954eedeac495271d0f0000000000000000000000000000000000000000000000000de0b6b3a764000000000000000000000000000000000000000000000000000000000000666a3680000000000000000000000000000000000000000000000000000000000000000500000000000000000000000000000000000000000000000000000000000000412d4731ab211d539876f58497f6dd6059d66b5ee0285f02c9c270bfff7e63e41b75cf884b0e85fdf68f71d5ec3f85ff33c89ceac6f4015d9e64dcbd67893963181c00000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_permit_transfer_from() {
+        let permit = PermitTransferFrom {
+            token: Address::from(DAI),
+            amount: U256::from(1_000_000_000_000_000_000u64),
+            spender: Address::from(SPENDER),
+            nonce: U256::from(7u64),
+            deadline: U256::from(1_718_236_800u64),
+        };
+        assert_eq!(
+            permit.hash(U256::from(1u64)).unwrap().to_hex(),
+            "ce44624b685eb3b167c1f503591b258028d13959f5bc41502c2602c4e444460d"
+        );
+
+        let signature = MessageSignature::from_str("fb2b04f010f05d4ed1a72ecc4105e32f9006afe4f927e56dd3cf5ff446b9f0ea40f0d1a60a55c48c0cb4e49fd06cda2452d71ece65836d3a9e8227e2543193dc00").unwrap();
+        let encoded = permit
+            .encode_permit_transfer_from(
+                Address::from("0x61061FCaE11fD5461535E134EFf67A98cFff44E9"),
+                U256::from(500_000_000_000_000_000u64),
+                Address::from(OWNER),
+                &signature,
+            )
+            .unwrap();
+        assert_eq!(encoded.to_hex(), "30f28b7a0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f0000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000666a368000000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000006f05b59d3b200000000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f00000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000041fb2b04f010f05d4ed1a72ecc4105e32f9006afe4f927e56dd3cf5ff446b9f0ea40f0d1a60a55c48c0cb4e49fd06cda2452d71ece65836d3a9e8227e2543193dc1b00000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_permit_amount_overflow() {
+        let mut details = usdc_details();
+        details.amount = U256::MAX;
+        let permit = PermitSingle {
+            details,
+            spender: Address::from(SPENDER),
+            sig_deadline: U256::zero(),
+        };
+        let signature = MessageSignature::from_str("2f7ca0f7f5f4798d30327a1d8dc31bd4757bc8fb311abf7d0617652cb44f91300f6bb8d8da3f90046c075001ad42bd42bbbd44ade4b4ee8dc232fda9e757b1ad00").unwrap();
+        let err = permit
+            .encode_permit(Address::from(OWNER), &signature)
+            .unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_invalid_uint_value);
+    }
+
+    #[test]
+    fn test_permit_hash_out_of_range() {
+        // `amount` doesn't fit into `uint160`.
+        let mut details = usdc_details();
+        details.amount =
+            U256::from_str("1461501637330902918203684832716283019655932542976").unwrap();
+        let permit = PermitSingle {
+            details,
+            spender: Address::from(SPENDER),
+            sig_deadline: U256::zero(),
+        };
+        permit.hash(U256::from(1u64)).unwrap_err();
+        permit.typed_data(U256::from(1u64)).unwrap_err();
+
+        // `nonce` of the second permit doesn't fit into `uint48`.
+        let mut details = usdc_details();
+        details.nonce = U256::from(1u64 << 48);
+        let permit = PermitBatch {
+            details: vec![usdc_details(), details],
+            spender: Address::from(SPENDER),
+            sig_deadline: U256::zero(),
+        };
+        permit.hash(U256::from(1u64)).unwrap_err();
+    }
+}
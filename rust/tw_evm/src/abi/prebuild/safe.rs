@@ -0,0 +1,338 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::prebuild::erc4337::{ExecuteArgs, Operation};
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use crate::address::Address;
+use crate::message::eip191::{ETHEREUM_MESSAGE_PREFIX, ETHEREUM_PREFIX};
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::signature::{MessageSignature, SignatureType};
+use crate::message::{EthMessage, MessageSigningResult};
+use lazy_static::lazy_static;
+use serde_json::{json, Value as Json};
+use tw_encoding::hex;
+use tw_hash::sha3::keccak256;
+use tw_hash::{H256, H520};
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_keypair::KeyPairResult;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://github.com/safe-global/safe-modules/blob/main/modules/4337/contracts/Safe4337Module.sol
+const SAFE_4337_MODULE_ABI: &str = include_str!("resource/safe_4337_module.abi.json");
+/// https://github.com/safe-global/safe-smart-account/blob/v1.4.1/contracts/Safe.sol
+const SAFE_ABI: &str = include_str!("resource/safe.abi.json");
+/// https://github.com/safe-global/safe-smart-account/blob/v1.4.1/contracts/libraries/MultiSendCallOnly.sol
+const SAFE_MULTI_SEND_ABI: &str = include_str!("resource/safe_multi_send.abi.json");
+
+/// `MultiSendCallOnly` v1.4.1 library, deployed at the same address on every supported chain.
+pub const SAFE_MULTI_SEND_CALL_ONLY_ADDRESS: &str = "0x9641d764fc13c8B624c04430C7356C1C7C8102e2";
+
+/// `v` offset of the signatures over the `eth_sign` hash of `safeTxHash`.
+/// https://docs.safe.global/advanced/smart-account-signatures#eth_sign-signature
+const ETH_SIGN_V_OFFSET: u8 = 4;
+
+lazy_static! {
+    static ref SAFE: Contract = serde_json::from_str(SAFE_ABI).unwrap();
+    static ref SAFE_4337_MODULE: Contract = serde_json::from_str(SAFE_4337_MODULE_ABI).unwrap();
+    static ref SAFE_MULTI_SEND: Contract = serde_json::from_str(SAFE_MULTI_SEND_ABI).unwrap();
+}
+
+/// Safe account with the `Safe4337Module` enabled as a fallback handler.
+pub struct Safe4337Module;
+
+impl Safe4337Module {
+    pub fn encode_execute_user_op(args: ExecuteArgs, operation: Operation) -> AbiResult<Data> {
+        let func = SAFE_4337_MODULE.function("executeUserOp")?;
+        func.encode_input(&[
+            Token::Address(args.to),
+            Token::u256(args.value),
+            Token::Bytes(args.data),
+            operation.to_token(),
+        ])
+    }
+
+    /// Safe executes several calls by delegating to the `MultiSendCallOnly` library.
+    pub fn encode_execute_user_op_batch<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let multi_send_address = Address::from(SAFE_MULTI_SEND_CALL_ONLY_ADDRESS);
+        let multi_send_payload = SafeMultiSend::encode_multi_send(args)?;
+
+        Self::encode_execute_user_op(
+            ExecuteArgs {
+                to: multi_send_address,
+                value: U256::zero(),
+                data: multi_send_payload,
+            },
+            Operation::DelegateCall,
+        )
+    }
+}
+
+pub struct SafeMultiSend;
+
+impl SafeMultiSend {
+    /// Each call is packed as `operation (1 byte) || to (20 bytes) || value (32 bytes) || data length (32 bytes) || data`.
+    pub fn encode_multi_send<I>(args: I) -> AbiResult<Data>
+    where
+        I: IntoIterator<Item = ExecuteArgs>,
+    {
+        let func = SAFE_MULTI_SEND.function("multiSend")?;
+
+        let mut transactions = Data::new();
+        for arg in args {
+            transactions.push(Operation::Call as u8);
+            transactions.extend_from_slice(arg.to.as_slice());
+            transactions.extend_from_slice(arg.value.to_big_endian().as_slice());
+            transactions.extend_from_slice(U256::from(arg.data.len()).to_big_endian().as_slice());
+            transactions.extend_from_slice(&arg.data);
+        }
+
+        func.encode_input(&[Token::Bytes(transactions)])
+    }
+}
+
+/// Safe (v1.3.0+) multisig transaction, signed by the owners and executed via `execTransaction`.
+#[derive(Clone, Debug)]
+pub struct SafeTransaction {
+    pub to: Address,
+    pub value: U256,
+    pub data: Data,
+    pub operation: Operation,
+    pub safe_tx_gas: U256,
+    pub base_gas: U256,
+    pub gas_price: U256,
+    /// Token used for the gas refund, or zero address for the native coin.
+    pub gas_token: Address,
+    /// Receiver of the gas refund, or zero address for `tx.origin`.
+    pub refund_receiver: Address,
+    /// The current `nonce()` of the Safe.
+    pub nonce: U256,
+}
+
+impl SafeTransaction {
+    /// Returns the `SafeTx` EIP-712 typed data of the given Safe.
+    pub fn typed_data(&self, safe: Address, chain_id: U256) -> Json {
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" },
+                ],
+                "SafeTx": [
+                    { "name": "to", "type": "address" },
+                    { "name": "value", "type": "uint256" },
+                    { "name": "data", "type": "bytes" },
+                    { "name": "operation", "type": "uint8" },
+                    { "name": "safeTxGas", "type": "uint256" },
+                    { "name": "baseGas", "type": "uint256" },
+                    { "name": "gasPrice", "type": "uint256" },
+                    { "name": "gasToken", "type": "address" },
+                    { "name": "refundReceiver", "type": "address" },
+                    { "name": "nonce", "type": "uint256" },
+                ],
+            },
+            "primaryType": "SafeTx",
+            "domain": {
+                "chainId": chain_id.to_string(),
+                "verifyingContract": safe.to_string(),
+            },
+            "message": {
+                "to": self.to.to_string(),
+                "value": self.value.to_string(),
+                "data": hex::encode(&self.data, true),
+                "operation": (self.operation as u8).to_string(),
+                "safeTxGas": self.safe_tx_gas.to_string(),
+                "baseGas": self.base_gas.to_string(),
+                "gasPrice": self.gas_price.to_string(),
+                "gasToken": self.gas_token.to_string(),
+                "refundReceiver": self.refund_receiver.to_string(),
+                "nonce": self.nonce.to_string(),
+            },
+        })
+    }
+
+    /// Returns `safeTxHash`, the same as `Safe.getTransactionHash(...)`.
+    pub fn hash(&self, safe: Address, chain_id: U256) -> MessageSigningResult<H256> {
+        Eip712Message::from_json(self.typed_data(safe, chain_id))?.hash()
+    }
+
+    /// Encodes `execTransaction(...)` with the owner signatures packed as the Safe expects.
+    pub fn encode_exec_transaction(&self, signatures: Vec<SafeSignature>) -> AbiResult<Data> {
+        let func = SAFE.function("execTransaction")?;
+        func.encode_input(&[
+            Token::Address(self.to),
+            Token::u256(self.value),
+            Token::Bytes(self.data.clone()),
+            self.operation.to_token(),
+            Token::u256(self.safe_tx_gas),
+            Token::u256(self.base_gas),
+            Token::u256(self.gas_price),
+            Token::Address(self.gas_token),
+            Token::Address(self.refund_receiver),
+            Token::Bytes(pack_signatures(signatures)?),
+        ])
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SafeSignatureType {
+    /// The owner signs `safeTxHash` as EIP-712 typed data, `v` is 27 or 28.
+    Eip712,
+    /// The owner signs `safeTxHash` via `eth_sign` (EIP-191), `v` is 31 or 32.
+    EthSign,
+}
+
+/// ECDSA signature of a Safe owner.
+#[derive(Clone, Debug)]
+pub struct SafeSignature {
+    pub owner: Address,
+    signature: H520,
+}
+
+impl SafeSignature {
+    /// Signs `safeTxHash` with the owner's private key.
+    pub fn sign(
+        safe_tx_hash: H256,
+        private_key: &secp256k1::PrivateKey,
+        sign_type: SafeSignatureType,
+    ) -> KeyPairResult<SafeSignature> {
+        let hash_to_sign = match sign_type {
+            SafeSignatureType::Eip712 => safe_tx_hash,
+            SafeSignatureType::EthSign => eth_sign_hash(safe_tx_hash),
+        };
+        let signature = private_key.sign(hash_to_sign)?;
+        let signature = MessageSignature::prepared(signature, SignatureType::Legacy)?;
+
+        let owner = Address::with_secp256k1_pubkey(&private_key.public());
+        Ok(SafeSignature::new(owner, &signature, sign_type))
+    }
+
+    /// Wraps a signature produced elsewhere, e.g by a hardware wallet.
+    /// The signature `v` is converted to the one expected by the Safe for the given `sign_type`.
+    pub fn new(
+        owner: Address,
+        signature: &MessageSignature,
+        sign_type: SafeSignatureType,
+    ) -> SafeSignature {
+        let mut signature = signature.to_legacy().to_bytes().into_vec();
+        if sign_type == SafeSignatureType::EthSign {
+            signature[H520::LEN - 1] += ETH_SIGN_V_OFFSET;
+        }
+        SafeSignature {
+            owner,
+            signature: H520::try_from(signature.as_slice()).expect("Expected 65 byte signature"),
+        }
+    }
+
+    /// Returns `r || s || v`.
+    pub fn to_bytes(&self) -> H520 {
+        self.signature
+    }
+}
+
+/// Concatenates the signatures sorted by the owner addresses in ascending order,
+/// as the Safe checks that each owner is greater than the previous one.
+pub fn pack_signatures(mut signatures: Vec<SafeSignature>) -> AbiResult<Data> {
+    signatures.sort_by(|a, b| a.owner.as_slice().cmp(b.owner.as_slice()));
+    if signatures
+        .windows(2)
+        .any(|pair| pair[0].owner == pair[1].owner)
+    {
+        // The same owner cannot sign twice.
+        return Err(AbiError(AbiErrorKind::Error_invalid_address_value));
+    }
+
+    Ok(signatures
+        .iter()
+        .flat_map(|signature| signature.signature.into_vec())
+        .collect())
+}
+
+/// Returns `keccak256("\x19Ethereum Signed Message:\n32" || hash)`.
+fn eth_sign_hash(hash: H256) -> H256 {
+    let mut data = Vec::with_capacity(ETHEREUM_MESSAGE_PREFIX.len() + 3 + H256::LEN);
+    data.push(ETHEREUM_PREFIX);
+    data.extend_from_slice(ETHEREUM_MESSAGE_PREFIX.as_bytes());
+    data.extend_from_slice(H256::LEN.to_string().as_bytes());
+    data.extend_from_slice(hash.as_slice());
+    H256::try_from(keccak256(&data).as_slice()).expect("Expected 32 byte hash")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::test_utils::test_calls;
+    use tw_encoding::hex::{self, ToHex};
+
+    #[test]
+    fn test_encode_execute_user_op_batch() {
+        let encoded = Safe4337Module::encode_execute_user_op_batch(test_calls()).unwrap();
+        assert_eq!(encoded.to_hex(), "7bb374280000000000000000000000009641d764fc13c8b624c04430c7356c1c7c8102e200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000001448d80ff0a000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000ee0061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc100000000000000000000000000000000000000000000000000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_safe_transaction() {
+        let safe = Address::from("0x4e1DCf7AD4e460CfD30791CCC4F9c8a4f820ec67");
+        let chain_id = U256::from(1u64);
+        // ERC20 `transfer(0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD, 1000)`.
+        let tx = SafeTransaction {
+            to: Address::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+            value: U256::zero(),
+            data: hex::decode("a9059cbb0000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e8").unwrap(),
+            operation: Operation::Call,
+            safe_tx_gas: U256::zero(),
+            base_gas: U256::zero(),
+            gas_price: U256::zero(),
+            gas_token: Address::default(),
+            refund_receiver: Address::default(),
+            nonce: U256::from(7u64),
+        };
+
+        let safe_tx_hash = tx.hash(safe, chain_id).unwrap();
+        assert_eq!(
+            safe_tx_hash.to_hex(),
+            "09cd51907abb95ee03bad58d77fc5de3ba3d2d8c8467ea3eec86b34e1061109b"
+        );
+
+        // Owner 0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7
+        let private_key = secp256k1::PrivateKey::try_from(
+            "4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904",
+        )
+        .unwrap();
+        let eip712_signature =
+            SafeSignature::sign(safe_tx_hash, &private_key, SafeSignatureType::Eip712).unwrap();
+        assert_eq!(
+            eip712_signature.owner.to_string(),
+            "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7"
+        );
+        assert_eq!(eip712_signature.to_bytes().to_hex(), "0cd627a438724ec5386f10b52684470dcb70d9073ab257462c723afb1c1fe59e53ea2b7ca0ca2af8971e1db38564ee927c2bde6bc66c5d6c79fd33014d219ef31b");
+
+        // Owner 0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309
+        let private_key = secp256k1::PrivateKey::try_from(
+            "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+        )
+        .unwrap();
+        let eth_sign_signature =
+            SafeSignature::sign(safe_tx_hash, &private_key, SafeSignatureType::EthSign).unwrap();
+        assert_eq!(eth_sign_signature.to_bytes().to_hex(), "6a1c3a30065e63f15d7aa591c55d9a20937f12eb03039a148aa261a14e5008611b530cfae926aa78e5ccf07ca7b4fc1d1aceb0b6907c5d7f40be33c3f636054420");
+
+        // The signatures are expected to be sorted by the owner addresses.
+        let encoded = tx
+            .encode_exec_transaction(vec![eip712_signature.clone(), eth_sign_signature.clone()])
+            .unwrap();
+        assert_eq!(encoded.to_hex(), "6a761202000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001c00000000000000000000000000000000000000000000000000000000000000044a9059cbb0000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e80000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000826a1c3a30065e63f15d7aa591c55d9a20937f12eb03039a148aa261a14e5008611b530cfae926aa78e5ccf07ca7b4fc1d1aceb0b6907c5d7f40be33c3f6360544200cd627a438724ec5386f10b52684470dcb70d9073ab257462c723afb1c1fe59e53ea2b7ca0ca2af8971e1db38564ee927c2bde6bc66c5d6c79fd33014d219ef31b000000000000000000000000000000000000000000000000000000000000");
+
+        // The same owner cannot sign twice.
+        tx.encode_exec_transaction(vec![eip712_signature.clone(), eip712_signature])
+            .unwrap_err();
+    }
+}
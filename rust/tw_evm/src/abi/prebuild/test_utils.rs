@@ -0,0 +1,28 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::prebuild::erc4337::ExecuteArgs;
+use crate::address::Address;
+use tw_encoding::hex;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// Calls shared by the smart account encoder tests: a native transfer and an ERC20 transfer.
+pub(crate) fn test_calls() -> Vec<ExecuteArgs> {
+    vec![
+        ExecuteArgs {
+            to: Address::from("0x61061fCAE11fD5461535e134EfF67A98CFFF44E9"),
+            value: U256::from(0x2386_f26f_c100_00u64),
+            data: Data::default(),
+        },
+        // ERC20 `transfer(0x61061fCAE11fD5461535e134EfF67A98CFFF44E9, 1000)`.
+        ExecuteArgs {
+            to: Address::from("0x5FbDB2315678afecb367f032d93F642f64180aa3"),
+            value: U256::zero(),
+            data: hex::decode("a9059cbb00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e900000000000000000000000000000000000000000000000000000000000003e8").unwrap(),
+        },
+    ]
+}
@@ -0,0 +1,26 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use lazy_static::lazy_static;
+
+/// Swap functions of the Uniswap V3 `SwapRouter`.
+/// Solidity: https://github.com/Uniswap/v3-periphery/blob/main/contracts/interfaces/ISwapRouter.sol
+const UNISWAP_V3_SWAP_ROUTER_ABI: &str = include_str!("resource/uniswap_v3.swap_router.abi.json");
+
+lazy_static! {
+    static ref UNISWAP_V3_SWAP_ROUTER: Contract =
+        serde_json::from_str(UNISWAP_V3_SWAP_ROUTER_ABI).unwrap();
+}
+
+pub struct UniswapV3SwapRouter;
+
+impl UniswapV3SwapRouter {
+    /// Returns the contract ABI.
+    pub(crate) fn contract() -> &'static Contract {
+        &UNISWAP_V3_SWAP_ROUTER
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::contract::Contract;
+use crate::abi::custom_error::CustomError;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use lazy_static::lazy_static;
+use tw_hash::H32;
+use tw_number::U256;
+
+lazy_static! {
+    /// `Error(string)` emitted by `revert("reason")` and `require(condition, "reason")`.
+    static ref ERROR: CustomError = CustomError {
+        name: "Error".to_string(),
+        inputs: vec![unnamed_param(ParamType::String)],
+    };
+    /// `Panic(uint256)` emitted by failed assertions, arithmetic overflows, out-of-bounds access etc.
+    static ref PANIC: CustomError = CustomError {
+        name: "Panic".to_string(),
+        inputs: vec![unnamed_param(ParamType::u256())],
+    };
+}
+
+/// The reason of a reverted call decoded from the revert data.
+#[derive(Clone, Debug)]
+pub enum RevertReason {
+    /// `revert()` or `require(condition)` without a reason.
+    Empty,
+    /// `Error(string)` with a reason message.
+    Error(String),
+    /// `Panic(uint256)` with a panic code.
+    Panic(U256),
+    /// A custom error declared in the contract ABI.
+    Custom {
+        error: CustomError,
+        params: Vec<NamedToken>,
+    },
+}
+
+impl RevertReason {
+    /// Decodes the revert data.
+    /// `contract` is used to look up custom errors by the selector, if provided.
+    pub fn decode(data: &[u8], contract: Option<&Contract>) -> AbiResult<RevertReason> {
+        if data.is_empty() {
+            return Ok(RevertReason::Empty);
+        }
+        if data.len() < H32::len() {
+            return Err(AbiError(AbiErrorKind::Error_decoding_data));
+        }
+
+        let (selector, encoded) = data.split_at(H32::len());
+        let selector = H32::try_from(selector).expect("The length expected to be checked above");
+
+        if selector == ERROR.selector() {
+            let message = match first_token(ERROR.decode_input(encoded)?)? {
+                Token::String(message) => message,
+                _ => return Err(AbiError(AbiErrorKind::Error_decoding_data)),
+            };
+            return Ok(RevertReason::Error(message));
+        }
+        if selector == PANIC.selector() {
+            let code = match first_token(PANIC.decode_input(encoded)?)? {
+                Token::Uint { uint, .. } => uint,
+                _ => return Err(AbiError(AbiErrorKind::Error_decoding_data)),
+            };
+            return Ok(RevertReason::Panic(code));
+        }
+
+        let error = contract
+            .and_then(|contract| contract.error_by_selector(&selector))
+            .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))?;
+        let params = error.decode_input(encoded)?;
+        Ok(RevertReason::Custom {
+            error: error.clone(),
+            params,
+        })
+    }
+
+    /// Returns the error signature, e.g `Error(string)`, or an empty string if there is no reason.
+    pub fn signature(&self) -> String {
+        match self {
+            RevertReason::Empty => String::default(),
+            RevertReason::Error(_) => ERROR.signature(),
+            RevertReason::Panic(_) => PANIC.signature(),
+            RevertReason::Custom { error, .. } => error.signature(),
+        }
+    }
+
+    /// Returns the decoded error params.
+    pub fn params(&self) -> Vec<NamedToken> {
+        match self {
+            RevertReason::Empty => Vec::default(),
+            RevertReason::Error(message) => vec![NamedToken::with_param_and_token(
+                &ERROR.inputs[0],
+                Token::String(message.clone()),
+            )],
+            RevertReason::Panic(code) => vec![NamedToken::with_param_and_token(
+                &PANIC.inputs[0],
+                Token::u256(*code),
+            )],
+            RevertReason::Custom { params, .. } => params.clone(),
+        }
+    }
+
+    /// Returns a human readable description of the reason, if known.
+    pub fn description(&self) -> Option<String> {
+        match self {
+            RevertReason::Error(message) => Some(message.clone()),
+            RevertReason::Panic(code) => panic_description(*code).map(str::to_string),
+            RevertReason::Empty | RevertReason::Custom { .. } => None,
+        }
+    }
+}
+
+/// https://docs.soliditylang.org/en/latest/control-structures.html#panic-via-assert-and-error-via-require
+fn panic_description(code: U256) -> Option<&'static str> {
+    let code = u8::try_from(code).ok()?;
+    let description = match code {
+        0x00 => "generic compiler inserted panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array encoding",
+        0x31 => "pop on an empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to a zero-initialized function",
+        _ => return None,
+    };
+    Some(description)
+}
+
+fn first_token(tokens: Vec<NamedToken>) -> AbiResult<Token> {
+    tokens
+        .into_iter()
+        .next()
+        .map(|token| token.value)
+        .ok_or(AbiError(AbiErrorKind::Error_decoding_data))
+}
+
+fn unnamed_param(kind: ParamType) -> Param {
+    Param {
+        name: None,
+        kind,
+        internal_type: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    #[test]
+    fn test_decode_revert_error() {
+        let data = "08c379a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001a4e6f7420656e6f7567682045746865722070726f76696465642e000000000000"
+            .decode_hex()
+            .unwrap();
+        let reason = RevertReason::decode(&data, None).unwrap();
+        assert_eq!(reason.signature(), "Error(string)");
+        assert_eq!(
+            reason.description(),
+            Some("Not enough Ether provided.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_panic() {
+        let data = "4e487b710000000000000000000000000000000000000000000000000000000000000011"
+            .decode_hex()
+            .unwrap();
+        let reason = RevertReason::decode(&data, None).unwrap();
+        assert_eq!(reason.signature(), "Panic(uint256)");
+        assert_eq!(
+            reason.description(),
+            Some("arithmetic overflow or underflow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_empty() {
+        let reason = RevertReason::decode(&[], None).unwrap();
+        assert!(matches!(reason, RevertReason::Empty));
+        assert_eq!(reason.signature(), "");
+    }
+
+    #[test]
+    fn test_decode_revert_unknown_custom_error() {
+        let data = "cf4791810000000000000000000000000000000000000000000000000000000000000001"
+            .decode_hex()
+            .unwrap();
+        let err = RevertReason::decode(&data, None).unwrap_err();
+        assert_eq!(err.0, AbiErrorKind::Error_abi_mismatch);
+    }
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 use std::ops::{RangeFrom, RangeInclusive};
 use std::str::FromStr;
+use tw_coin_entry::coin_context::{AddressChecksum, CoinContext};
 use tw_coin_entry::coin_entry::CoinAddress;
 use tw_coin_entry::error::{AddressError, AddressResult};
 use tw_encoding::hex;
@@ -61,13 +62,18 @@ impl Address {
 
     /// Displays the address in mixed-case checksum form
     /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-55.md
-    fn into_checksum_address(self) -> String {
+    /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1191.md
+    pub fn to_checksum_address(&self, checksum: AddressChecksum) -> String {
         const UPPER_RANGE_1: RangeInclusive<char> = '8'..='9';
         const UPPER_RANGE_2: RangeInclusive<char> = 'a'..='f';
 
         let prefixed = false;
         let addr_hex = hex::encode(self.bytes, prefixed);
-        let addr_hash = hex::encode(keccak256(addr_hex.as_bytes()), prefixed);
+        let hash_preimage = match checksum {
+            AddressChecksum::Eip55 => addr_hex.clone(),
+            AddressChecksum::Eip1191 { chain_id } => format!("{chain_id}0x{addr_hex}"),
+        };
+        let addr_hash = hex::encode(keccak256(hash_preimage.as_bytes()), prefixed);
 
         let payload_chars = addr_hex.chars().zip(addr_hash.chars()).map(|(a, h)| {
             if a.is_ascii_digit() {
@@ -125,7 +131,7 @@ impl From<&'static str> for Address {
 impl Display for Address {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.into_checksum_address())
+        write!(f, "{}", self.to_checksum_address(AddressChecksum::Eip55))
     }
 }
 
@@ -148,6 +154,75 @@ impl Serialize for Address {
     }
 }
 
+/// Address checksum and prefix of a specific EVM chain, as specified in `registry.json`.
+#[derive(Clone, Debug, Default)]
+pub struct AddressFormat {
+    pub checksum: AddressChecksum,
+    /// The prefix that replaces `0x`, if any.
+    pub prefix: Option<String>,
+}
+
+impl AddressFormat {
+    pub fn from_coin_context(coin: &dyn CoinContext) -> AddressFormat {
+        AddressFormat {
+            checksum: coin.address_checksum().unwrap_or_default(),
+            prefix: coin.address_prefix().map(str::to_string),
+        }
+    }
+
+    /// Parses an address starting with either the chain specific prefix or `0x`.
+    pub fn parse(&self, s: &str) -> AddressResult<Address> {
+        let no_prefix = self
+            .prefix
+            .as_ref()
+            .and_then(|prefix| s.strip_prefix(prefix.as_str()));
+        match no_prefix {
+            Some(no_prefix) => Address::from_str(&format!("0x{no_prefix}")),
+            None => Address::from_str(s),
+        }
+    }
+
+    pub fn format(&self, address: &Address) -> String {
+        let checksummed = address.to_checksum_address(self.checksum);
+        match self.prefix {
+            Some(ref prefix) => checksummed.replacen("0x", prefix, 1),
+            None => checksummed,
+        }
+    }
+}
+
+/// An address displayed in the format of a specific EVM chain.
+#[derive(Clone, Debug)]
+pub struct ChainAddress {
+    address: Address,
+    format: AddressFormat,
+}
+
+impl ChainAddress {
+    pub fn new(address: Address, format: AddressFormat) -> ChainAddress {
+        ChainAddress { address, format }
+    }
+
+    #[inline]
+    pub fn address(&self) -> Address {
+        self.address
+    }
+}
+
+impl CoinAddress for ChainAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.address.data()
+    }
+}
+
+impl Display for ChainAddress {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format.format(&self.address))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +295,54 @@ mod tests {
             "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309"
         );
     }
+
+    /// Examples are taken from https://eips.ethereum.org/EIPS/eip-1191#test-cases
+    #[test]
+    fn test_eip1191_checksum() {
+        let tests = [
+            (
+                30,
+                "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+                "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+            ),
+            (
+                30,
+                "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359",
+                "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+            ),
+            (
+                31,
+                "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359",
+                "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359",
+            ),
+        ];
+        for (chain_id, test, expected) in tests {
+            let addr = Address::from_str(test).unwrap();
+            let actual = addr.to_checksum_address(AddressChecksum::Eip1191 { chain_id });
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_address_format() {
+        let format = AddressFormat {
+            checksum: AddressChecksum::Eip55,
+            prefix: Some("xdc".to_string()),
+        };
+        let expected = "xdcAc1ec44E4f0ca7D172B7803f6836De87Fb72b309";
+
+        for test in [
+            "xdcac1ec44e4f0ca7d172b7803f6836de87fb72b309",
+            "0xac1ec44e4f0ca7d172b7803f6836de87fb72b309",
+        ] {
+            let addr = format.parse(test).unwrap();
+            assert_eq!(
+                ChainAddress::new(addr, format.clone()).to_string(),
+                expected
+            );
+        }
+        format
+            .parse("ac1ec44e4f0ca7d172b7803f6836de87fb72b309")
+            .unwrap_err();
+    }
 }
@@ -0,0 +1,255 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::encode::encode_tokens;
+use crate::abi::prebuild::erc4337::Erc4337SimpleAccount;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use tw_hash::sha3::keccak256;
+use tw_hash::{H160, H256};
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://eips.ethereum.org/EIPS/eip-1014
+const CREATE2_PREFIX: u8 = 0xff;
+
+/// Computes the address of a contract deployed by `sender` via the `CREATE` opcode
+/// or a contract creation transaction: `keccak256(rlp([sender, nonce]))[12..]`.
+pub fn create_address(sender: Address, nonce: U256) -> Address {
+    let mut list = RlpList::new();
+    list.append(sender).append(nonce);
+    address_from_hash(&keccak256(&list.finish()))
+}
+
+/// Computes the address of a contract deployed by `deployer` via the `CREATE2` opcode:
+/// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]`.
+pub fn create2_address(deployer: Address, salt: H256, init_code_hash: H256) -> Address {
+    let mut preimage = Vec::with_capacity(1 + Address::LEN + H256::LEN * 2);
+    preimage.push(CREATE2_PREFIX);
+    preimage.extend_from_slice(deployer.as_slice());
+    preimage.extend_from_slice(salt.as_slice());
+    preimage.extend_from_slice(init_code_hash.as_slice());
+    address_from_hash(&keccak256(&preimage))
+}
+
+/// Returns `keccak256(init_code)`.
+pub fn init_code_hash(init_code: &[u8]) -> H256 {
+    H256::try_from(keccak256(init_code).as_slice()).expect("keccak256 returns 32 bytes")
+}
+
+/// An account deployed by eth-infinitism `SimpleAccountFactory.createAccount(owner, salt)`:
+/// an `ERC1967Proxy` pointing to the account implementation and initialized with `initialize(owner)`.
+/// https://github.com/eth-infinitism/account-abstraction/blob/develop/contracts/samples/SimpleAccountFactory.sol
+#[derive(Clone, Debug)]
+pub struct SimpleAccountDeployment {
+    pub factory: Address,
+    /// `SimpleAccountFactory.accountImplementation()`.
+    pub account_implementation: Address,
+    /// Creation code of `ERC1967Proxy`.
+    pub proxy_bytecode: Data,
+    pub owner: Address,
+    pub salt: U256,
+}
+
+impl SimpleAccountDeployment {
+    pub fn init_code_hash(&self) -> AbiResult<H256> {
+        let initialize_call = Erc4337SimpleAccount::encode_initialize(self.owner)?;
+        let constructor_args = encode_tokens(&[
+            Token::Address(self.account_implementation),
+            Token::Bytes(initialize_call),
+        ]);
+
+        let mut init_code = self.proxy_bytecode.clone();
+        init_code.extend_from_slice(&constructor_args);
+        Ok(init_code_hash(&init_code))
+    }
+
+    /// Returns the same address as `SimpleAccountFactory.getAddress(owner, salt)`.
+    pub fn address(&self) -> AbiResult<Address> {
+        let init_code_hash = self.init_code_hash()?;
+        Ok(create2_address(
+            self.factory,
+            self.salt.to_big_endian(),
+            init_code_hash,
+        ))
+    }
+}
+
+/// A Barz diamond account deployed by `BarzFactory.createAccount(verificationFacet, owner, salt)`.
+/// https://github.com/trustwallet/barz/blob/main/contracts/BarzFactory.sol
+#[derive(Clone, Debug)]
+pub struct BarzDeployment {
+    pub factory: Address,
+    pub account_facet: Address,
+    pub verification_facet: Address,
+    pub entry_point: Address,
+    pub facet_registry: Address,
+    pub default_fallback: Address,
+    /// Creation code of the `Barz` contract.
+    pub bytecode: Data,
+    /// Public key or address of the owner, depending on the verification facet.
+    pub owner_public_key: Data,
+    pub salt: U256,
+}
+
+impl BarzDeployment {
+    pub fn init_code_hash(&self) -> H256 {
+        let constructor_args = encode_tokens(&[
+            Token::Address(self.account_facet),
+            Token::Address(self.verification_facet),
+            Token::Address(self.entry_point),
+            Token::Address(self.facet_registry),
+            Token::Address(self.default_fallback),
+            Token::Bytes(self.owner_public_key.clone()),
+        ]);
+
+        let mut init_code = self.bytecode.clone();
+        init_code.extend_from_slice(&constructor_args);
+        init_code_hash(&init_code)
+    }
+
+    /// Returns the same address as `BarzFactory.getAddress(verificationFacet, owner, salt)`.
+    pub fn address(&self) -> Address {
+        create2_address(
+            self.factory,
+            self.salt.to_big_endian(),
+            self.init_code_hash(),
+        )
+    }
+}
+
+/// A Safe proxy deployed by `SafeProxyFactory.createProxyWithNonce(singleton, initializer, saltNonce)`.
+/// https://github.com/safe-global/safe-smart-account/blob/main/contracts/proxies/SafeProxyFactory.sol
+#[derive(Clone, Debug)]
+pub struct SafeProxyDeployment {
+    pub factory: Address,
+    /// `SafeProxyFactory.proxyCreationCode()`.
+    pub proxy_bytecode: Data,
+    /// The Safe (or SafeL2) implementation contract.
+    pub singleton: Address,
+    /// Call data of the `setup(...)` call executed right after the proxy is deployed.
+    pub initializer: Data,
+    pub salt_nonce: U256,
+}
+
+impl SafeProxyDeployment {
+    /// Returns `keccak256(keccak256(initializer) ++ saltNonce)`.
+    pub fn salt(&self) -> H256 {
+        let mut salt_preimage = keccak256(&self.initializer);
+        salt_preimage.extend_from_slice(self.salt_nonce.to_big_endian().as_slice());
+        init_code_hash(&salt_preimage)
+    }
+
+    pub fn init_code_hash(&self) -> H256 {
+        let mut init_code = self.proxy_bytecode.clone();
+        init_code.extend_from_slice(&encode_tokens(&[Token::Address(self.singleton)]));
+        init_code_hash(&init_code)
+    }
+
+    pub fn address(&self) -> Address {
+        create2_address(self.factory, self.salt(), self.init_code_hash())
+    }
+}
+
+fn address_from_hash(hash: &[u8]) -> Address {
+    let bytes = H160::try_from(&hash[H256::LEN - H160::LEN..]).expect("Expected 20 byte array");
+    Address::from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    /// `GnosisSafeProxyFactory.proxyCreationCode()` of Safe v1.3.0.
+    const SAFE_PROXY_BYTECODE: &str = "608060405234801561001057600080fd5b506040516101e63803806101e68339818101604052602081101561003357600080fd5b8101908080519060200190929190505050600073ffffffffffffffffffffffffffffffffffffffff168173ffffffffffffffffffffffffffffffffffffffff1614156100ca576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260228152602001806101c46022913960400191505060405180910390fd5b806000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055505060ab806101196000396000f3fe608060405273ffffffffffffffffffffffffffffffffffffffff600054167fa619486e0000000000000000000000000000000000000000000000000000000060003514156050578060005260206000f35b3660008037600080366000845af43d6000803e60008114156070573d6000fd5b3d6000f3fea2646970667358221220d1429297349653a4918076d650332de1a1068c5f3e07c5c82360c277770b955264736f6c63430007060033496e76616c69642073696e676c65746f6e20616464726573732070726f7669646564";
+    /// `setup([0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F], 1, 0x0, 0x, CompatibilityFallbackHandler, 0x0, 0, 0x0)`.
+    const SAFE_INITIALIZER: &str = "b63e800d0000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000f48f2b2d2a534e402487b3ee7c18c33aec0fe5e400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f0000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fn test_create_address() {
+        let sender = Address::from("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        let test_cases = [
+            (0u64, "0xcd234A471b72ba2F1Ccf0A70FCABA648a5eeCD8d"),
+            (1, "0x343c43A37D37dfF08AE8C4A11544c718AbB4fCF8"),
+            (0x1234, "0xe57C87ba715DD75F735EBb2644c07375f4C4F0E1"),
+        ];
+        for (nonce, expected) in test_cases {
+            let actual = create_address(sender, U256::from(nonce));
+            assert_eq!(actual.to_string(), expected, "nonce: {nonce}");
+        }
+    }
+
+    /// Examples are taken from https://eips.ethereum.org/EIPS/eip-1014#examples
+    #[test]
+    fn test_create2_address() {
+        let actual = create2_address(
+            Address::from("0x0000000000000000000000000000000000000000"),
+            H256::default(),
+            init_code_hash(&[0x00]),
+        );
+        assert_eq!(
+            actual.to_string(),
+            "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"
+        );
+
+        let init_code = "deadbeef".decode_hex().unwrap();
+        assert_eq!(
+            init_code_hash(&init_code).to_hex(),
+            "d4fd4e189132273036449fc9e11198c739161b4c0116a9a2dccdfa1c492006f1"
+        );
+        let actual = create2_address(
+            Address::from("0x00000000000000000000000000000000deadbeef"),
+            U256::from(0xcafebabe_u64).to_big_endian(),
+            init_code_hash(&init_code),
+        );
+        assert_eq!(
+            actual.to_string(),
+            "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7"
+        );
+    }
+
+    #[test]
+    fn test_simple_account_address() {
+        let deployment = SimpleAccountDeployment {
+            factory: Address::from("0x9406Cc6185a346906296840746125a0E44976454"),
+            account_implementation: Address::from("0x8ABB13360b87Be5EEb1B98647A016adD927a136c"),
+            proxy_bytecode: "60806040526040516104".decode_hex().unwrap(),
+            owner: Address::from("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            salt: U256::from(7u64),
+        };
+        assert_eq!(
+            deployment.init_code_hash().unwrap().to_hex(),
+            "9fa68386e389130ff34d0c03e582800be3ac47aa7590f0491dede47a75d6371d"
+        );
+        assert_eq!(
+            deployment.address().unwrap().to_string(),
+            "0x9f2BDA335d375d6ce775DA5488f5f42c6c89482E"
+        );
+    }
+
+    /// Safe v1.3.0 deployed with the canonical `GnosisSafeProxyFactory` and `GnosisSafe` singleton.
+    #[test]
+    fn test_safe_proxy_address() {
+        let deployment = SafeProxyDeployment {
+            factory: Address::from("0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2"),
+            proxy_bytecode: SAFE_PROXY_BYTECODE.decode_hex().unwrap(),
+            singleton: Address::from("0xd9Db270c1B5E3Bd161E8c8503c55cEABeE709552"),
+            initializer: SAFE_INITIALIZER.decode_hex().unwrap(),
+            salt_nonce: U256::from(5u64),
+        };
+        assert_eq!(
+            deployment.init_code_hash().to_hex(),
+            "56e3081a3d1bb38ed4eed1a39f7729c3cc77c7825794c15bbf326f3047fd779c"
+        );
+        assert_eq!(
+            deployment.address().to_string(),
+            "0x7cA83125A1eaA1E95B0d83D65d563f4306558d50"
+        );
+    }
+}
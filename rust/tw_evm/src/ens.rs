@@ -0,0 +1,165 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+//! ENS name hashing and DNS encoding.
+//! Resolver calldata builders are located at [`crate::abi::prebuild::ens`].
+//!
+//! Names are expected to be normalized according to ENSIP-15 (https://docs.ens.domains/ensip/15)
+//! by the caller. The module doesn't normalize them, so names that differ in case or in Unicode
+//! representation have different hashes.
+
+use tw_coin_entry::error::{SigningError, SigningErrorType};
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_memory::Data;
+
+const LABEL_SEPARATOR: char = '.';
+
+pub type EnsResult<T> = Result<T, EnsError>;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum EnsError {
+    /// The name contains an empty label, e.g `foo..eth` or `foo.eth.`.
+    EmptyLabel,
+    /// The label doesn't fit into the DNS wire format.
+    LabelTooLong,
+}
+
+impl From<EnsError> for SigningError {
+    fn from(_: EnsError) -> Self {
+        SigningError(SigningErrorType::Error_invalid_params)
+    }
+}
+
+/// Returns `keccak256(label)`. The label is expected to be normalized.
+pub fn labelhash(label: &str) -> H256 {
+    H256::try_from(keccak256(label.as_bytes()).as_slice()).expect("Expected 32 byte array")
+}
+
+/// Computes the ENS node of a normalized name.
+/// https://docs.ens.domains/ensip/1#namehash-algorithm
+pub fn namehash(name: &str) -> EnsResult<H256> {
+    let node = labels(name)?
+        .into_iter()
+        .rev()
+        .fold(H256::default(), |node, label| {
+            let mut preimage = Vec::with_capacity(H256::LEN * 2);
+            preimage.extend_from_slice(node.as_slice());
+            preimage.extend_from_slice(labelhash(label).as_slice());
+            H256::try_from(keccak256(&preimage).as_slice()).expect("Expected 32 byte array")
+        });
+    Ok(node)
+}
+
+/// Encodes a normalized name in the DNS wire format,
+/// as required by `resolve(bytes name, bytes data)` of ENSIP-10 wildcard resolvers.
+/// https://docs.ens.domains/ensip/10
+pub fn dns_encode(name: &str) -> EnsResult<Data> {
+    let mut encoded = Vec::with_capacity(name.len() + 2);
+    for label in labels(name)? {
+        let len = u8::try_from(label.len()).map_err(|_| EnsError::LabelTooLong)?;
+        encoded.push(len);
+        encoded.extend_from_slice(label.as_bytes());
+    }
+    // The root label.
+    encoded.push(0);
+    Ok(encoded)
+}
+
+/// Splits the name into labels. The empty name is the root name without labels.
+fn labels(name: &str) -> EnsResult<Vec<&str>> {
+    if name.is_empty() {
+        return Ok(Vec::default());
+    }
+
+    let labels: Vec<_> = name.split(LABEL_SEPARATOR).collect();
+    if labels.iter().any(|label| label.is_empty()) {
+        return Err(EnsError::EmptyLabel);
+    }
+    Ok(labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    /// Examples are taken from https://docs.ens.domains/ensip/1
+    #[test]
+    fn test_namehash() {
+        let test_cases = [
+            (
+                "",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                "eth",
+                "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae",
+            ),
+            (
+                "foo.eth",
+                "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f",
+            ),
+            (
+                "vitalik.eth",
+                "ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835",
+            ),
+            (
+                "_my-name.eth",
+                "ededa2ef7869f272409d5cfde3f82e4b01317b2dbfd8f7fde70931ba6ef23668",
+            ),
+            (
+                "café.eth",
+                "a7369e1df22e06ec6d91162508e400d7af475860638f927e6d1085bb0134a74a",
+            ),
+            (
+                "🚀.eth",
+                "fb4826c8682290c58ce9375c4cac0a4904d6b3b237684b60e0a6eaba308eac62",
+            ),
+        ];
+        for (name, expected) in test_cases {
+            assert_eq!(namehash(name).unwrap().to_hex(), expected, "name: {name}");
+        }
+    }
+
+    #[test]
+    fn test_namehash_error() {
+        assert_eq!(namehash("foo..eth"), Err(EnsError::EmptyLabel));
+        assert_eq!(namehash("foo.eth."), Err(EnsError::EmptyLabel));
+        assert_eq!(namehash("."), Err(EnsError::EmptyLabel));
+    }
+
+    #[test]
+    fn test_labelhash() {
+        assert_eq!(
+            labelhash("vitalik").to_hex(),
+            "af2caa1c2ca1d027f1ac823b529d0a67cd144264b2789fa2ea4d63a67c7103cc"
+        );
+    }
+
+    #[test]
+    fn test_dns_encode() {
+        assert_eq!(
+            dns_encode("vitalik.eth").unwrap().to_hex(),
+            "07766974616c696b0365746800"
+        );
+        // The label length is in bytes.
+        assert_eq!(
+            dns_encode("café.eth").unwrap().to_hex(),
+            "05636166c3a90365746800"
+        );
+        assert_eq!(dns_encode("").unwrap().to_hex(), "00");
+    }
+
+    #[test]
+    fn test_dns_encode_error() {
+        assert_eq!(dns_encode("foo..eth"), Err(EnsError::EmptyLabel));
+        assert_eq!(
+            dns_encode(&format!("{}.eth", "a".repeat(256))),
+            Err(EnsError::LabelTooLong)
+        );
+    }
+}
@@ -6,8 +6,14 @@
 
 use crate::evm_context::EvmContext;
 use crate::modules::abi_encoder::AbiEncoder;
+use crate::modules::authorization_signer::AuthorizationSigner;
+use crate::modules::deployment_address::DeploymentAddress;
+use crate::modules::message_signer::EthMessageSigner;
 use crate::modules::rlp_encoder::RlpEncoder;
+use crate::modules::tx_decoder::TxDecoder;
+use tw_coin_entry::coin_context::CoinContext;
 use tw_memory::Data;
+use tw_proto::Ethereum::Proto as EthProto;
 use tw_proto::EthereumAbi::Proto as AbiProto;
 use tw_proto::EthereumRlp::Proto as RlpProto;
 use tw_proto::{deserialize, serialize, ProtoResult};
@@ -22,6 +28,45 @@ pub trait EvmEntry {
         RlpEncoder::<Self::Context>::encode_with_proto(input)
     }
 
+    /// Decodes an RLP encoded item or a list of items.
+    #[inline]
+    fn decode_rlp(input: RlpProto::DecodingInput<'_>) -> RlpProto::DecodingOutput<'static> {
+        RlpEncoder::<Self::Context>::decode_with_proto(input)
+    }
+
+    /// Decodes a signed or unsigned transaction and recovers the sender if the transaction is signed.
+    #[inline]
+    fn decode_transaction(
+        input: EthProto::TransactionDecodingInput<'_>,
+    ) -> EthProto::TransactionDecodingOutput<'static> {
+        TxDecoder::<Self::Context>::decode_transaction_proto(input)
+    }
+
+    /// Signs an EIP-7702 authorization to set the code of the signer's account.
+    #[inline]
+    fn sign_authorization(
+        coin: &dyn CoinContext,
+        input: EthProto::AuthorizationSigningInput<'_>,
+    ) -> EthProto::AuthorizationSigningOutput<'static> {
+        AuthorizationSigner::<Self::Context>::sign_authorization_proto_for_coin(coin, input)
+    }
+
+    /// Computes an address of a contract or an ERC-4337 smart account before it's deployed.
+    #[inline]
+    fn compute_deployment_address(
+        input: EthProto::DeploymentAddressInput<'_>,
+    ) -> EthProto::DeploymentAddressOutput<'static> {
+        DeploymentAddress::<Self::Context>::compute_address_proto(input)
+    }
+
+    /// Returns a display-ready tree of an EIP-712 typed data.
+    #[inline]
+    fn describe_typed_data(
+        input: EthProto::TypedDataDescribingInput<'_>,
+    ) -> EthProto::TypedDataDescribingOutput<'static> {
+        EthMessageSigner::describe_typed_data(input)
+    }
+
     /// Decodes function call data to human readable json format, according to input abi json.
     #[inline]
     fn decode_abi_contract_call(
@@ -46,6 +91,22 @@ pub trait EvmEntry {
         AbiEncoder::<Self::Context>::decode_value(input)
     }
 
+    /// Decodes an event log according to a given ABI.
+    #[inline]
+    fn decode_abi_log(
+        input: AbiProto::LogDecodingInput<'_>,
+    ) -> AbiProto::LogDecodingOutput<'static> {
+        AbiEncoder::<Self::Context>::decode_log(input)
+    }
+
+    /// Decodes revert data of a failed call, including custom errors declared in a given ABI.
+    #[inline]
+    fn decode_abi_revert(
+        input: AbiProto::RevertDecodingInput<'_>,
+    ) -> AbiProto::RevertDecodingOutput<'static> {
+        AbiEncoder::<Self::Context>::decode_revert(input)
+    }
+
     /// Returns the function type signature, of the form "baz(int32,uint256)".
     #[inline]
     fn get_abi_function_signature(input: AbiProto::FunctionGetTypeInput<'_>) -> String {
@@ -59,6 +120,22 @@ pub trait EvmEntry {
     ) -> AbiProto::FunctionEncodingOutput<'static> {
         AbiEncoder::<Self::Context>::encode_contract_call(input)
     }
+
+    /// Encodes parameters in the non-standard packed mode, i.e `abi.encodePacked(...)`.
+    #[inline]
+    fn encode_abi_packed(
+        input: AbiProto::PackedEncodingInput<'_>,
+    ) -> AbiProto::PackedEncodingOutput<'static> {
+        AbiEncoder::<Self::Context>::encode_packed(input)
+    }
+
+    /// Interprets a smart contract call as a human readable intent with risk flags.
+    #[inline]
+    fn interpret_abi_contract_call(
+        input: AbiProto::ContractCallInterpretingInput<'_>,
+    ) -> AbiProto::ContractCallInterpretingOutput<'static> {
+        AbiEncoder::<Self::Context>::interpret_contract_call(input)
+    }
 }
 
 /// The [`EvmEntry`] trait extension.
@@ -66,6 +143,21 @@ pub trait EvmEntryExt {
     /// Encodes an item or a list of items as Eth RLP binary format.
     fn encode_rlp(&self, input: &[u8]) -> ProtoResult<Data>;
 
+    /// Decodes an RLP encoded item or a list of items.
+    fn decode_rlp(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Decodes a signed or unsigned transaction and recovers the sender if the transaction is signed.
+    fn decode_transaction(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Signs an EIP-7702 authorization to set the code of the signer's account.
+    fn sign_authorization(&self, coin: &dyn CoinContext, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Computes an address of a contract or an ERC-4337 smart account before it's deployed.
+    fn compute_deployment_address(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Returns a display-ready tree of an EIP-712 typed data.
+    fn describe_typed_data(&self, input: &[u8]) -> ProtoResult<Data>;
+
     /// Decodes function call data to human readable json format, according to input abi json.
     fn decode_abi_contract_call(&self, input: &[u8]) -> ProtoResult<Data>;
 
@@ -80,6 +172,18 @@ pub trait EvmEntryExt {
 
     /// Decodes an Eth ABI value according to a given type.
     fn decode_abi_value(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Decodes an event log according to a given ABI.
+    fn decode_abi_log(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Decodes revert data of a failed call, including custom errors declared in a given ABI.
+    fn decode_abi_revert(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Encodes parameters in the non-standard packed mode, i.e `abi.encodePacked(...)`.
+    fn encode_abi_packed(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Interprets a smart contract call as a human readable intent with risk flags.
+    fn interpret_abi_contract_call(&self, input: &[u8]) -> ProtoResult<Data>;
 }
 
 impl<T> EvmEntryExt for T
@@ -92,6 +196,36 @@ where
         serialize(&output)
     }
 
+    fn decode_rlp(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_rlp(input);
+        serialize(&output)
+    }
+
+    fn decode_transaction(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_transaction(input);
+        serialize(&output)
+    }
+
+    fn sign_authorization(&self, coin: &dyn CoinContext, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::sign_authorization(coin, input);
+        serialize(&output)
+    }
+
+    fn compute_deployment_address(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::compute_deployment_address(input);
+        serialize(&output)
+    }
+
+    fn describe_typed_data(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::describe_typed_data(input);
+        serialize(&output)
+    }
+
     fn decode_abi_contract_call(&self, input: &[u8]) -> ProtoResult<Data> {
         let input = deserialize(input)?;
         let output = <Self as EvmEntry>::decode_abi_contract_call(input);
@@ -120,4 +254,28 @@ where
         let output = <Self as EvmEntry>::decode_abi_value(input);
         serialize(&output)
     }
+
+    fn decode_abi_log(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_abi_log(input);
+        serialize(&output)
+    }
+
+    fn decode_abi_revert(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_abi_revert(input);
+        serialize(&output)
+    }
+
+    fn encode_abi_packed(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::encode_abi_packed(input);
+        serialize(&output)
+    }
+
+    fn interpret_abi_contract_call(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::interpret_abi_contract_call(input);
+        serialize(&output)
+    }
 }
@@ -6,6 +6,8 @@
 
 pub mod abi;
 pub mod address;
+pub mod contract_address;
+pub mod ens;
 pub mod evm_context;
 pub mod evm_entry;
 pub mod message;
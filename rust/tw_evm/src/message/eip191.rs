@@ -4,33 +4,70 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::address::Address;
 use crate::message::{EthMessage, MessageSigningResult};
 use tw_hash::sha3::keccak256;
 use tw_hash::H256;
+use tw_memory::Data;
 
 /// cbindgen:ignore
 pub const ETHEREUM_PREFIX: u8 = 0x19;
 /// cbindgen:ignore
 pub const ETHEREUM_MESSAGE_PREFIX: &str = "Ethereum Signed Message:\n";
+/// cbindgen:ignore
+pub const INTENDED_VALIDATOR_VERSION: u8 = 0x00;
+
+/// EIP-191 signed data version.
+/// https://eips.ethereum.org/EIPS/eip-191#version-byte
+pub enum Eip191Version {
+    /// Version `0x00`: data with an intended validator, i.e the contract that verifies the signature.
+    IntendedValidator(Address),
+    /// Version `0x45`: `personal_sign` message.
+    PersonalSign,
+}
 
 pub struct Eip191Message {
-    user_message: String,
+    version: Eip191Version,
+    user_message: Data,
 }
 
 impl Eip191Message {
+    /// Creates a `personal_sign` message from a text.
     pub fn new<S: Into<String>>(user_message: S) -> Eip191Message {
+        Eip191Message::from_bytes(user_message.into().into_bytes())
+    }
+
+    /// Creates a `personal_sign` message from binary data.
+    pub fn from_bytes(user_message: Data) -> Eip191Message {
         Eip191Message {
-            user_message: user_message.into(),
+            version: Eip191Version::PersonalSign,
+            user_message,
+        }
+    }
+
+    /// Creates a version `0x00` message to be verified by the `validator` contract.
+    pub fn with_intended_validator(validator: Address, user_message: Data) -> Eip191Message {
+        Eip191Message {
+            version: Eip191Version::IntendedValidator(validator),
+            user_message,
         }
     }
 
     fn data_to_sign(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(self.user_message.len() * 2);
-
         data.push(ETHEREUM_PREFIX);
-        data.extend_from_slice(ETHEREUM_MESSAGE_PREFIX.as_bytes());
-        data.extend_from_slice(self.user_message.len().to_string().as_bytes());
-        data.extend_from_slice(self.user_message.as_bytes());
+
+        match self.version {
+            Eip191Version::IntendedValidator(validator) => {
+                data.push(INTENDED_VALIDATOR_VERSION);
+                data.extend_from_slice(validator.as_slice());
+            },
+            Eip191Version::PersonalSign => {
+                data.extend_from_slice(ETHEREUM_MESSAGE_PREFIX.as_bytes());
+                data.extend_from_slice(self.user_message.len().to_string().as_bytes());
+            },
+        }
+        data.extend_from_slice(&self.user_message);
 
         data
     }
@@ -42,3 +79,37 @@ impl EthMessage for Eip191Message {
         Ok(H256::try_from(hash.as_slice()).expect("Expected 32 byte hash"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_personal_sign_bytes() {
+        // The text and its binary representation are hashed equally.
+        let text = Eip191Message::new("Foo").hash().unwrap();
+        let bytes = Eip191Message::from_bytes(b"Foo".to_vec()).hash().unwrap();
+        assert_eq!(text, bytes);
+
+        let hash = Eip191Message::from_bytes(vec![0xde, 0xad, 0xbe, 0xef])
+            .hash()
+            .unwrap();
+        assert_eq!(
+            hash.to_hex(),
+            "d1c7f1a06a4f9a535077e50ad23244ce2c6ae443fcd412965226f3df5d28eaaa"
+        );
+    }
+
+    #[test]
+    fn test_intended_validator() {
+        let validator = Address::from("0x3535353535353535353535353535353535353535");
+        let hash = Eip191Message::with_intended_validator(validator, vec![0xde, 0xad, 0xbe, 0xef])
+            .hash()
+            .unwrap();
+        assert_eq!(
+            hash.to_hex(),
+            "a65a18c4b3737fe889c797e7c48745b8fad6d93d518fa057f76e54a2fb31c1e9"
+        );
+    }
+}
@@ -9,13 +9,14 @@ use crate::abi::non_empty_array::NonEmptyBytes;
 use crate::abi::token::Token;
 use crate::address::Address;
 use crate::message::eip712::property::{Property, PropertyType};
+use crate::message::eip712::typed_data_tree::{TypedDataTree, TypedField, TypedValue};
 use crate::message::{EthMessage, MessageSigningError, MessageSigningResult};
 use itertools::Itertools;
 use serde::Deserialize;
 use serde_json::Value as Json;
 use std::collections::HashMap;
 use std::str::FromStr;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_hash::sha3::keccak256;
 use tw_hash::{H160, H256};
 use tw_memory::Data;
@@ -27,8 +28,24 @@ const PREFIX: &[u8; 2] = b"\x19\x01";
 /// cbindgen:ignore
 const EIP712_DOMAIN: &str = "EIP712Domain";
 
+/// A value of a struct field that is missing in V4.
+static NULL_VALUE: Json = Json::Null;
+
 type CustomTypes = HashMap<String, Vec<Property>>;
 
+/// The `eth_signTypedData` version the message is encoded with.
+/// https://docs.metamask.io/wallet/concepts/signing-methods/#eth_signtypeddata_v4
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Eip712Version {
+    /// `eth_signTypedData_v3`: arrays are not supported,
+    /// and fields missing in the data are not encoded.
+    V3,
+    /// `eth_signTypedData_v4`: arrays are supported,
+    /// and a missing or `null` struct is encoded as a zero word.
+    #[default]
+    V4,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Eip712Message {
     types: CustomTypes,
@@ -36,19 +53,23 @@ pub struct Eip712Message {
     #[serde(rename = "primaryType")]
     primary_type: String,
     message: Json,
+    #[serde(skip)]
+    version: Eip712Version,
 }
 
 impl Eip712Message {
     /// Tries to construct an EIP712 message from the given string.
     pub fn new<S: AsRef<str>>(message_to_sign: S) -> MessageSigningResult<Eip712Message> {
         let eip712_msg: Eip712Message = serde_json::from_str(message_to_sign.as_ref())
-            .map_err(|_| MessageSigningError::TypeValueMismatch)?;
+            .map_err(|_| MessageSigningError::InvalidTypedData)?;
+        eip712_msg.check_domain()
+    }
 
-        // Check if the given message is actually EIP712.
-        if !eip712_msg.types.contains_key(EIP712_DOMAIN) {
-            return Err(MessageSigningError::TypeValueMismatch);
-        }
-        Ok(eip712_msg)
+    /// Tries to construct an EIP712 message from the given typed data JSON value.
+    pub fn from_json(typed_data: Json) -> MessageSigningResult<Eip712Message> {
+        let eip712_msg: Eip712Message = serde_json::from_value(typed_data)
+            .map_err(|_| MessageSigningError::InvalidTypedData)?;
+        eip712_msg.check_domain()
     }
 
     pub fn new_checked<S: AsRef<str>>(
@@ -58,29 +79,56 @@ impl Eip712Message {
         let msg = Eip712Message::new(message_to_sign)?;
 
         // Check if `domain.chainId` is expected.
-        let chain_id_value = msg.domain["chainId"].clone();
-        let chain_id = U256::from_u64_or_decimal_str(chain_id_value)
-            .map_err(|_| MessageSigningError::TypeValueMismatch)?;
+        let chain_id_value = msg
+            .domain
+            .get("chainId")
+            .ok_or(MessageSigningError::MissingField)?;
+        let chain_id = parse_u256(chain_id_value)?;
         if chain_id != expected_chain_id {
             return Err(MessageSigningError::InvalidChainId);
         }
 
         Ok(msg)
     }
+
+    /// Sets the `eth_signTypedData` version. [`Eip712Version::V4`] is used by default.
+    pub fn with_version(mut self, version: Eip712Version) -> Eip712Message {
+        self.version = version;
+        self
+    }
+
+    /// Returns a display-ready tree of the domain and the message.
+    /// The values are validated the same way as they are encoded on [`EthMessage::hash`].
+    pub fn typed_data_tree(&self) -> MessageSigningResult<TypedDataTree> {
+        let encoder = self.encoder();
+        Ok(TypedDataTree {
+            domain: encoder.typed_fields(EIP712_DOMAIN, &self.domain)?,
+            primary_type: self.primary_type.clone(),
+            message: encoder.typed_fields(&self.primary_type, &self.message)?,
+        })
+    }
+
+    /// Checks if the message is actually EIP712.
+    fn check_domain(self) -> MessageSigningResult<Eip712Message> {
+        if !self.types.contains_key(EIP712_DOMAIN) {
+            return Err(MessageSigningError::MissingDomainType);
+        }
+        Ok(self)
+    }
+
+    fn encoder(&self) -> Encoder<'_> {
+        Encoder {
+            custom_types: &self.types,
+            version: self.version,
+        }
+    }
 }
 
 impl EthMessage for Eip712Message {
     fn hash(&self) -> MessageSigningResult<H256> {
-        let domain_hash = encode_data(
-            &self.types,
-            PropertyType::Custom(EIP712_DOMAIN.to_string()),
-            &self.domain,
-        )?;
-        let primary_data_hash = encode_data(
-            &self.types,
-            PropertyType::Custom(self.primary_type.clone()),
-            &self.message,
-        )?;
+        let encoder = self.encoder();
+        let domain_hash = encoder.encode_custom(EIP712_DOMAIN, &self.domain)?;
+        let primary_data_hash = encoder.encode_custom(&self.primary_type, &self.message)?;
 
         let concat = [
             PREFIX.as_slice(),
@@ -94,138 +142,255 @@ impl EthMessage for Eip712Message {
     }
 }
 
-fn encode_data(
-    custom_types: &CustomTypes,
-    data_type: PropertyType,
-    data: &Json,
-) -> MessageSigningResult<Vec<u8>> {
-    match data_type {
-        PropertyType::Bool => encode_bool(data),
-        PropertyType::String => encode_string(data),
-        PropertyType::Int => encode_i256(data),
-        PropertyType::Uint => encode_u256(data),
-        PropertyType::Address => encode_address(data),
-        PropertyType::FixBytes { len } => encode_fix_bytes(data, len.get()),
-        PropertyType::Bytes => encode_bytes(data),
-        PropertyType::Custom(custom) => encode_custom(custom_types, &custom, data),
-        PropertyType::Array(element_type) => encode_array(custom_types, *element_type, data, None),
-        PropertyType::FixArray { len, element_type } => {
-            encode_array(custom_types, *element_type, data, Some(len.get()))
-        },
+struct Encoder<'a> {
+    custom_types: &'a CustomTypes,
+    version: Eip712Version,
+}
+
+impl<'a> Encoder<'a> {
+    fn encode_data(&self, data_type: &PropertyType, data: &Json) -> MessageSigningResult<Data> {
+        match data_type {
+            PropertyType::Bool => encode_bool(data),
+            PropertyType::String => encode_string(data),
+            PropertyType::Int => encode_i256(data),
+            PropertyType::Uint => encode_u256(data),
+            PropertyType::Address => encode_address(data),
+            PropertyType::FixBytes { len } => encode_fix_bytes(data, len.get()),
+            PropertyType::Bytes => encode_bytes(data),
+            PropertyType::Custom(_) if self.is_null_struct(data) => Ok(H256::default().into_vec()),
+            PropertyType::Custom(custom) => self.encode_custom(custom, data),
+            PropertyType::Array(element_type) => self.encode_array(element_type, data, None),
+            PropertyType::FixArray { len, element_type } => {
+                self.encode_array(element_type, data, Some(len.get()))
+            },
+        }
+    }
+
+    fn encode_array(
+        &self,
+        element_type: &PropertyType,
+        data: &Json,
+        expected_len: Option<usize>,
+    ) -> MessageSigningResult<Data> {
+        let mut encoded_items = vec![];
+        for item in self.array_items(data, expected_len)? {
+            let mut encoded = self.encode_data(element_type, item)?;
+            encoded_items.append(&mut encoded);
+        }
+
+        Ok(keccak256(&encoded_items))
+    }
+
+    fn encode_custom(&self, data_ident: &str, data: &Json) -> MessageSigningResult<Data> {
+        let fields = self.struct_fields(data_ident, data)?;
+
+        let type_hash = encode_custom_type::type_hash(data_ident, self.custom_types)?;
+        let checked_bytes = NonEmptyBytes::new(type_hash)
+            .map_err(|_| MessageSigningError::InvalidParameterValue)?;
+        let mut encoded_tokens = encode_tokens(&[Token::FixedBytes(checked_bytes)]);
+
+        for (_, field_type, field_value) in fields {
+            let mut encoded = self.encode_data(&field_type, field_value)?;
+            encoded_tokens.append(&mut encoded);
+        }
+
+        Ok(keccak256(&encoded_tokens))
+    }
+
+    fn typed_value(
+        &self,
+        data_type: &PropertyType,
+        data: &Json,
+    ) -> MessageSigningResult<TypedValue> {
+        let value = match data_type {
+            PropertyType::Bool => parse_bool(data)?.to_string(),
+            PropertyType::String => parse_string(data)?.to_string(),
+            PropertyType::Int => parse_i256(data)?.to_string(),
+            PropertyType::Uint => parse_u256(data)?.to_string(),
+            PropertyType::Address => parse_address(data)?.to_string(),
+            PropertyType::FixBytes { len } => parse_fix_bytes(data, len.get())?.to_hex_prefixed(),
+            PropertyType::Bytes => parse_bytes(data)?.to_hex_prefixed(),
+            PropertyType::Custom(_) if self.is_null_struct(data) => return Ok(TypedValue::Null),
+            PropertyType::Custom(custom) => {
+                return Ok(TypedValue::Struct {
+                    type_name: custom.clone(),
+                    fields: self.typed_fields(custom, data)?,
+                })
+            },
+            PropertyType::Array(element_type) => {
+                return self.typed_array(element_type, data, None);
+            },
+            PropertyType::FixArray { len, element_type } => {
+                return self.typed_array(element_type, data, Some(len.get()));
+            },
+        };
+        Ok(TypedValue::Primitive { value })
+    }
+
+    fn typed_array(
+        &self,
+        element_type: &PropertyType,
+        data: &Json,
+        expected_len: Option<usize>,
+    ) -> MessageSigningResult<TypedValue> {
+        let items = self
+            .array_items(data, expected_len)?
+            .iter()
+            .map(|item| self.typed_value(element_type, item))
+            .collect::<MessageSigningResult<Vec<_>>>()?;
+        Ok(TypedValue::Array { items })
+    }
+
+    fn typed_fields(&self, data_ident: &str, data: &Json) -> MessageSigningResult<Vec<TypedField>> {
+        self.struct_fields(data_ident, data)?
+            .into_iter()
+            .map(|(property, field_type, field_value)| {
+                Ok(TypedField {
+                    name: property.name.clone(),
+                    field_type: property.property_type.clone(),
+                    value: self.typed_value(&field_type, field_value)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the declared fields of the `data_ident` struct along with their values.
+    /// Missing fields are skipped in V3, and missing structs are considered `null` in V4.
+    fn struct_fields<'b>(
+        &self,
+        data_ident: &str,
+        data: &'b Json,
+    ) -> MessageSigningResult<Vec<(&'a Property, PropertyType, &'b Json)>> {
+        let properties = self
+            .custom_types
+            .get(data_ident)
+            .ok_or(MessageSigningError::UnknownType)?;
+        let object = data
+            .as_object()
+            .ok_or(MessageSigningError::TypeValueMismatch)?;
+
+        let mut fields = Vec::with_capacity(properties.len());
+        for property in properties.iter() {
+            let field_type = PropertyType::from_str(&property.property_type)?;
+            let field_value = match (object.get(&property.name), self.version) {
+                (Some(value), _) => value,
+                (None, Eip712Version::V3) => continue,
+                (None, Eip712Version::V4) if matches!(field_type, PropertyType::Custom(_)) => {
+                    &NULL_VALUE
+                },
+                (None, Eip712Version::V4) => return Err(MessageSigningError::MissingField),
+            };
+            fields.push((property, field_type, field_value));
+        }
+        Ok(fields)
+    }
+
+    fn array_items<'b>(
+        &self,
+        data: &'b Json,
+        expected_len: Option<usize>,
+    ) -> MessageSigningResult<&'b Vec<Json>> {
+        if self.version == Eip712Version::V3 {
+            return Err(MessageSigningError::ArraysNotSupported);
+        }
+
+        let elements = data
+            .as_array()
+            .ok_or(MessageSigningError::TypeValueMismatch)?;
+
+        // Check if the type definition actually matches the length of items to be encoded.
+        if expected_len.is_some() && Some(elements.len()) != expected_len {
+            return Err(MessageSigningError::LengthMismatch);
+        }
+        Ok(elements)
+    }
+
+    fn is_null_struct(&self, data: &Json) -> bool {
+        self.version == Eip712Version::V4 && data.is_null()
     }
 }
 
 fn encode_bool(value: &Json) -> MessageSigningResult<Data> {
-    let bin = value
-        .as_bool()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-    Ok(encode_tokens(&[Token::Bool(bin)]))
+    Ok(encode_tokens(&[Token::Bool(parse_bool(value)?)]))
 }
 
 fn encode_string(value: &Json) -> MessageSigningResult<Data> {
-    let string = value
-        .as_str()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-    let hash = keccak256(string.as_bytes());
+    let hash = keccak256(parse_string(value)?.as_bytes());
     let checked_bytes = NonEmptyBytes::new(hash).expect("`hash` must not be empty");
     Ok(encode_tokens(&[Token::FixedBytes(checked_bytes)]))
 }
 
 fn encode_u256(value: &Json) -> MessageSigningResult<Data> {
-    let uint = U256::from_u64_or_decimal_str(value.clone())
-        .map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    Ok(encode_tokens(&[Token::u256(uint)]))
+    Ok(encode_tokens(&[Token::u256(parse_u256(value)?)]))
 }
 
 fn encode_i256(value: &Json) -> MessageSigningResult<Data> {
-    let int = I256::from_i64_or_decimal_str(value.clone())
-        .map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    Ok(encode_tokens(&[Token::i256(int)]))
+    Ok(encode_tokens(&[Token::i256(parse_i256(value)?)]))
 }
 
 fn encode_address(value: &Json) -> MessageSigningResult<Data> {
-    let addr_str = value
-        .as_str()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-    // H160 doesn't require the string to be `0x` prefixed.
-    let addr_data =
-        H160::from_str(addr_str).map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    let addr = Address::from_bytes(addr_data);
-    Ok(encode_tokens(&[Token::Address(addr)]))
+    Ok(encode_tokens(&[Token::Address(parse_address(value)?)]))
 }
 
 fn encode_fix_bytes(value: &Json, expected_len: usize) -> MessageSigningResult<Data> {
-    let str = value
-        .as_str()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-    let fix_bytes = str
-        .decode_hex()
-        .map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    if fix_bytes.len() != expected_len {
-        return Err(MessageSigningError::TypeValueMismatch);
-    }
+    let fix_bytes = parse_fix_bytes(value, expected_len)?;
     let checked_bytes =
         NonEmptyBytes::new(fix_bytes).map_err(|_| MessageSigningError::InvalidParameterValue)?;
     Ok(encode_tokens(&[Token::FixedBytes(checked_bytes)]))
 }
 
 fn encode_bytes(value: &Json) -> MessageSigningResult<Data> {
-    let str = value
-        .as_str()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-    let bytes = str
-        .decode_hex()
-        .map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    let hash = keccak256(&bytes);
-    Ok(encode_tokens(&[Token::Bytes(hash)]))
+    let hash = keccak256(&parse_bytes(value)?);
+    let checked_bytes = NonEmptyBytes::new(hash).expect("`hash` must not be empty");
+    Ok(encode_tokens(&[Token::FixedBytes(checked_bytes)]))
 }
 
-fn encode_array(
-    custom_types: &CustomTypes,
-    element_type: PropertyType,
-    data: &Json,
-    expected_len: Option<usize>,
-) -> MessageSigningResult<Data> {
-    let elements = data
-        .as_array()
-        .ok_or(MessageSigningError::InvalidParameterValue)?;
-
-    // Check if the type definition actually matches the length of items to be encoded.
-    if expected_len.is_some() && Some(elements.len()) != expected_len {
-        return Err(MessageSigningError::TypeValueMismatch)?;
-    }
+fn parse_bool(value: &Json) -> MessageSigningResult<bool> {
+    value
+        .as_bool()
+        .ok_or(MessageSigningError::TypeValueMismatch)
+}
+
+fn parse_string(value: &Json) -> MessageSigningResult<&str> {
+    value.as_str().ok_or(MessageSigningError::TypeValueMismatch)
+}
 
-    let mut encoded_items = vec![];
-    for item in elements {
-        let mut encoded = encode_data(custom_types, element_type.clone(), item)?;
-        encoded_items.append(&mut encoded);
+fn parse_u256(value: &Json) -> MessageSigningResult<U256> {
+    if !(value.is_u64() || value.is_string()) {
+        return Err(MessageSigningError::TypeValueMismatch);
     }
+    U256::from_u64_or_decimal_str(value.clone())
+        .map_err(|_| MessageSigningError::InvalidParameterValue)
+}
 
-    Ok(keccak256(&encoded_items))
+fn parse_i256(value: &Json) -> MessageSigningResult<I256> {
+    if !(value.is_i64() || value.is_string()) {
+        return Err(MessageSigningError::TypeValueMismatch);
+    }
+    I256::from_i64_or_decimal_str(value.clone())
+        .map_err(|_| MessageSigningError::InvalidParameterValue)
 }
 
-fn encode_custom(
-    custom_types: &CustomTypes,
-    data_ident: &str,
-    data: &Json,
-) -> MessageSigningResult<Data> {
-    let data_properties = custom_types
-        .get(data_ident)
-        .ok_or(MessageSigningError::TypeValueMismatch)?;
+fn parse_address(value: &Json) -> MessageSigningResult<Address> {
+    let addr_str = parse_string(value)?;
+    // H160 doesn't require the string to be `0x` prefixed.
+    let addr_data =
+        H160::from_str(addr_str).map_err(|_| MessageSigningError::InvalidParameterValue)?;
+    Ok(Address::from_bytes(addr_data))
+}
 
-    let type_hash = encode_custom_type::type_hash(data_ident, custom_types)?;
-    let checked_bytes =
-        NonEmptyBytes::new(type_hash).map_err(|_| MessageSigningError::InvalidParameterValue)?;
-    let mut encoded_tokens = encode_tokens(&[Token::FixedBytes(checked_bytes)]);
-
-    for field in data_properties.iter() {
-        let field_value = &data[&field.name];
-        let field_property = PropertyType::from_str(&field.property_type)?;
-        let mut encoded = encode_data(custom_types, field_property, field_value)?;
-        encoded_tokens.append(&mut encoded);
+fn parse_fix_bytes(value: &Json, expected_len: usize) -> MessageSigningResult<Data> {
+    let fix_bytes = parse_bytes(value)?;
+    if fix_bytes.len() != expected_len {
+        return Err(MessageSigningError::LengthMismatch);
     }
+    Ok(fix_bytes)
+}
 
-    Ok(keccak256(&encoded_tokens))
+fn parse_bytes(value: &Json) -> MessageSigningResult<Data> {
+    parse_string(value)?
+        .decode_hex()
+        .map_err(|_| MessageSigningError::InvalidParameterValue)
 }
 
 mod encode_custom_type {
@@ -246,7 +411,7 @@ mod encode_custom_type {
     ) -> MessageSigningResult<String> {
         let deps = {
             let mut temp = build_dependencies(data_type, custom_types)
-                .ok_or(MessageSigningError::TypeValueMismatch)?;
+                .ok_or(MessageSigningError::UnknownType)?;
             temp.remove(data_type);
             let mut temp = temp.into_iter().collect::<Vec<_>>();
             temp.sort_unstable();
@@ -309,7 +474,6 @@ mod encode_custom_type {
 mod tests {
     use super::*;
     use std::collections::HashSet;
-    use tw_encoding::hex::ToHex;
 
     #[test]
     fn test_build_dependencies() {
@@ -402,4 +566,224 @@ mod tests {
             "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
         );
     }
+
+    const MAIL_TYPES: &str = r#"{
+        "EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "chainId", "type": "uint256" }
+        ],
+        "Person": [
+            { "name": "name", "type": "string" },
+            { "name": "wallet", "type": "address" }
+        ],
+        "Mail": [
+            { "name": "from", "type": "Person" },
+            { "name": "to", "type": "Person" },
+            { "name": "contents", "type": "string" }
+        ]
+    }"#;
+
+    fn mail_message(message: Json) -> Eip712Message {
+        Eip712Message::from_json(serde_json::json!({
+            "types": serde_json::from_str::<Json>(MAIL_TYPES).unwrap(),
+            "primaryType": "Mail",
+            "domain": { "name": "Ether Mail", "chainId": 1 },
+            "message": message,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_typed_data_tree() {
+        let msg = mail_message(serde_json::json!({
+            "from": { "name": "Cow", "wallet": "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826" },
+            "contents": "Hello, Bob!"
+        }));
+        let tree = serde_json::to_value(msg.typed_data_tree().unwrap()).unwrap();
+        let expected = serde_json::json!({
+            "domain": [
+                { "name": "name", "type": "string", "value": { "kind": "primitive", "value": "Ether Mail" } },
+                { "name": "chainId", "type": "uint256", "value": { "kind": "primitive", "value": "1" } }
+            ],
+            "primaryType": "Mail",
+            "message": [
+                {
+                    "name": "from",
+                    "type": "Person",
+                    "value": {
+                        "kind": "struct",
+                        "typeName": "Person",
+                        "fields": [
+                            { "name": "name", "type": "string", "value": { "kind": "primitive", "value": "Cow" } },
+                            { "name": "wallet", "type": "address", "value": { "kind": "primitive", "value": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" } }
+                        ]
+                    }
+                },
+                { "name": "to", "type": "Person", "value": { "kind": "null" } },
+                { "name": "contents", "type": "string", "value": { "kind": "primitive", "value": "Hello, Bob!" } }
+            ]
+        });
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn test_typed_data_tree_array() {
+        let msg = Eip712Message::from_json(serde_json::json!({
+            "types": {
+                "EIP712Domain": [{ "name": "name", "type": "string" }],
+                "Message": [{ "name": "hashes", "type": "bytes4[2]" }]
+            },
+            "primaryType": "Message",
+            "domain": { "name": "Test" },
+            "message": { "hashes": ["0xdeadbeef", "0x01020304"] }
+        }))
+        .unwrap();
+
+        let tree = msg.typed_data_tree().unwrap();
+        let hash = |value: &str| TypedValue::Primitive {
+            value: value.to_string(),
+        };
+        assert_eq!(
+            tree.message,
+            vec![TypedField {
+                name: "hashes".to_string(),
+                field_type: "bytes4[2]".to_string(),
+                value: TypedValue::Array {
+                    items: vec![hash("0xdeadbeef"), hash("0x01020304")],
+                },
+            }]
+        );
+
+        // Arrays are not supported by V3.
+        let msg = msg.with_version(Eip712Version::V3);
+        assert!(matches!(
+            msg.typed_data_tree(),
+            Err(MessageSigningError::ArraysNotSupported)
+        ));
+        assert!(matches!(
+            msg.hash(),
+            Err(MessageSigningError::ArraysNotSupported)
+        ));
+    }
+
+    #[test]
+    fn test_hash_missing_struct() {
+        // The example from the EIP-712 specification.
+        let spec_message = |message: Json| {
+            Eip712Message::from_json(serde_json::json!({
+                "types": serde_json::from_str::<Json>(MAIL_TYPES).unwrap(),
+                "primaryType": "Mail",
+                "domain": {
+                    "name": "Ether Mail",
+                    "version": "1",
+                    "chainId": 1,
+                    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+                },
+                "message": message,
+            }))
+            .unwrap()
+        };
+
+        // V3 and V4 are equal for the complete message.
+        let msg = spec_message(serde_json::json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }));
+        let expected = "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+        assert_eq!(msg.hash().unwrap().to_hex(), expected);
+        let msg = msg.with_version(Eip712Version::V3);
+        assert_eq!(msg.hash().unwrap().to_hex(), expected);
+
+        // The missing `to` struct is encoded as a zero word, the same as `null`.
+        let expected = "56467729e7f0d32c0111820eb989210f3b1a435e3b1029f5e4dfad8ee77df6b0";
+        for to in [None, Some(Json::Null)] {
+            let mut message = serde_json::json!({
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "contents": "Hello, Bob!"
+            });
+            if let Some(to) = to {
+                message["to"] = to;
+            }
+            assert_eq!(spec_message(message).hash().unwrap().to_hex(), expected);
+        }
+
+        // The missing `to` field is not encoded at all.
+        let msg = spec_message(serde_json::json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "contents": "Hello, Bob!"
+        }))
+        .with_version(Eip712Version::V3);
+        assert_eq!(
+            msg.hash().unwrap().to_hex(),
+            "1c56b078dda6622ff8bc81b6f91b81df220a247b0090e4e7fb1ed0103e8d63cf"
+        );
+    }
+
+    #[test]
+    fn test_strict_errors() {
+        let msg = mail_message(serde_json::json!({
+            "from": { "name": "Cow" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }));
+        assert!(matches!(msg.hash(), Err(MessageSigningError::MissingField)));
+
+        let msg = mail_message(serde_json::json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": 1
+        }));
+        assert!(matches!(
+            msg.typed_data_tree(),
+            Err(MessageSigningError::TypeValueMismatch)
+        ));
+
+        let msg = mail_message(serde_json::json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }));
+        assert!(matches!(
+            msg.hash(),
+            Err(MessageSigningError::InvalidParameterValue)
+        ));
+
+        let msg = Eip712Message::from_json(serde_json::json!({
+            "types": {
+                "EIP712Domain": [{ "name": "name", "type": "string" }],
+                "Message": [{ "name": "hash", "type": "bytes4" }]
+            },
+            "primaryType": "Message",
+            "domain": { "name": "Test" },
+            "message": { "hash": "0xdeadbeef01" }
+        }))
+        .unwrap();
+        assert!(matches!(
+            msg.hash(),
+            Err(MessageSigningError::LengthMismatch)
+        ));
+
+        let msg = Eip712Message::from_json(serde_json::json!({
+            "types": {
+                "EIP712Domain": [{ "name": "name", "type": "string" }],
+                "Message": [{ "name": "owner", "type": "Person" }]
+            },
+            "primaryType": "Message",
+            "domain": { "name": "Test" },
+            "message": { "owner": {} }
+        }))
+        .unwrap();
+        assert!(matches!(msg.hash(), Err(MessageSigningError::UnknownType)));
+
+        let no_domain = r#"{"types": {}, "primaryType": "Message", "domain": {}, "message": {}}"#;
+        assert!(matches!(
+            Eip712Message::new(no_domain),
+            Err(MessageSigningError::MissingDomainType)
+        ));
+        assert!(matches!(
+            Eip712Message::new("Hello, Bob!"),
+            Err(MessageSigningError::InvalidTypedData)
+        ));
+    }
 }
@@ -6,3 +6,4 @@
 
 pub mod eip712_message;
 pub mod property;
+pub mod typed_data_tree;
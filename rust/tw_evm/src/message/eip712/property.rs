@@ -83,6 +83,10 @@ impl TypeConstructor for PropertyType {
         Err(AbiError(AbiErrorKind::Error_invalid_param_type))
     }
 
+    fn tuple(_components: Vec<(Option<String>, Self)>) -> AbiResult<Self> {
+        Err(AbiError(AbiErrorKind::Error_invalid_param_type))
+    }
+
     fn custom(s: &str) -> AbiResult<Self> {
         Ok(PropertyType::Custom(s.to_string()))
     }
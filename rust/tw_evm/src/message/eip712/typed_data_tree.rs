@@ -0,0 +1,53 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+//! Display-ready representation of an EIP-712 typed data,
+//! built by [`crate::message::eip712::eip712_message::Eip712Message::typed_data_tree`].
+
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedDataTree {
+    /// Fields of the `EIP712Domain` struct.
+    pub domain: Vec<TypedField>,
+    pub primary_type: String,
+    /// Fields of the `primary_type` struct.
+    pub message: Vec<TypedField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TypedField {
+    pub name: String,
+    /// The type as it's declared, e.g `uint256`, `Person` or `Person[]`.
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub value: TypedValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TypedValue {
+    /// A formatted atomic value:
+    /// * `bool` - `true` or `false`.
+    /// * `int` and `uint` - decimal number.
+    /// * `address` - EIP-55 checksummed address.
+    /// * `bytes` and `bytesN` - `0x`-prefixed hex.
+    /// * `string` - as is.
+    Primitive {
+        value: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Struct {
+        type_name: String,
+        fields: Vec<TypedField>,
+    },
+    Array {
+        items: Vec<TypedValue>,
+    },
+    /// A missing or `null` struct, allowed in [`super::eip712_message::Eip712Version::V4`].
+    Null,
+}
@@ -10,6 +10,7 @@ use tw_hash::H256;
 pub mod eip191;
 pub mod eip712;
 pub mod signature;
+pub mod siwe;
 
 pub type EthMessageBoxed = Box<dyn EthMessage>;
 pub type MessageSigningResult<T> = Result<T, MessageSigningError>;
@@ -18,8 +19,23 @@ pub type MessageSigningResult<T> = Result<T, MessageSigningError>;
 pub enum MessageSigningError {
     InvalidParameterType,
     InvalidParameterValue,
+    /// The JSON kind of a value doesn't match its declared type, e.g a number is given for a `string` field.
     TypeValueMismatch,
     InvalidChainId,
+    /// The message is not an EIP-712 typed data JSON.
+    InvalidTypedData,
+    /// `types` doesn't declare `EIP712Domain`.
+    MissingDomainType,
+    /// A struct type is referenced but not declared in `types`.
+    UnknownType,
+    /// A value of the declared field is missing.
+    MissingField,
+    /// The length of a fixed-size array or fixed bytes doesn't match its declared type.
+    LengthMismatch,
+    /// Arrays are not supported by `eth_signTypedData_v3`.
+    ArraysNotSupported,
+    /// The message is expected to be signed by another account.
+    AddressMismatch,
     Internal,
 }
 
@@ -29,9 +45,18 @@ impl From<MessageSigningError> for SigningError {
             MessageSigningError::InvalidParameterType
             | MessageSigningError::InvalidParameterValue
             | MessageSigningError::TypeValueMismatch
-            | MessageSigningError::InvalidChainId => {
+            | MessageSigningError::InvalidChainId
+            | MessageSigningError::InvalidTypedData
+            | MessageSigningError::MissingDomainType
+            | MessageSigningError::UnknownType
+            | MessageSigningError::MissingField
+            | MessageSigningError::LengthMismatch
+            | MessageSigningError::ArraysNotSupported => {
                 SigningError(SigningErrorType::Error_invalid_params)
             },
+            MessageSigningError::AddressMismatch => {
+                SigningError(SigningErrorType::Error_invalid_address)
+            },
             MessageSigningError::Internal => SigningError(SigningErrorType::Error_internal),
         }
     }
@@ -6,6 +6,7 @@
 
 use crate::signature::{
     eip155_replay_protection, legacy_replay_protection, remove_replay_protection,
+    ETHEREUM_SIGNATURE_V_OFFSET,
 };
 use std::str::FromStr;
 use tw_encoding::hex::DecodeHex;
@@ -49,6 +50,27 @@ impl MessageSignature {
         })
     }
 
+    pub fn r(&self) -> H256 {
+        self.r
+    }
+
+    pub fn s(&self) -> H256 {
+        self.s
+    }
+
+    pub fn v(&self) -> u8 {
+        self.v
+    }
+
+    /// Returns the signature with the legacy `v` (27 or 28), as expected by `ecrecover` in smart contracts.
+    pub fn to_legacy(&self) -> MessageSignature {
+        MessageSignature {
+            r: self.r,
+            s: self.s,
+            v: remove_replay_protection(self.v) + ETHEREUM_SIGNATURE_V_OFFSET,
+        }
+    }
+
     pub fn to_bytes(&self) -> H520 {
         let mut bytes = Vec::with_capacity(H520::LEN);
         bytes.extend_from_slice(self.r.as_slice());
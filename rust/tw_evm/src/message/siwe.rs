@@ -0,0 +1,504 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+//! Sign-In with Ethereum messages.
+//! https://eips.ethereum.org/EIPS/eip-4361
+
+use crate::address::Address;
+use crate::message::eip191::Eip191Message;
+use crate::message::{EthMessage, MessageSigningError, MessageSigningResult};
+use std::fmt;
+use std::str::FromStr;
+use tw_hash::H256;
+
+/// cbindgen:ignore
+const HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+/// cbindgen:ignore
+const URI_TAG: &str = "URI: ";
+/// cbindgen:ignore
+const VERSION_TAG: &str = "Version: ";
+/// cbindgen:ignore
+const CHAIN_ID_TAG: &str = "Chain ID: ";
+/// cbindgen:ignore
+const NONCE_TAG: &str = "Nonce: ";
+/// cbindgen:ignore
+const ISSUED_AT_TAG: &str = "Issued At: ";
+/// cbindgen:ignore
+const EXPIRATION_TIME_TAG: &str = "Expiration Time: ";
+/// cbindgen:ignore
+const NOT_BEFORE_TAG: &str = "Not Before: ";
+/// cbindgen:ignore
+const REQUEST_ID_TAG: &str = "Request ID: ";
+/// cbindgen:ignore
+const RESOURCES_TAG: &str = "Resources:";
+/// cbindgen:ignore
+const RESOURCE_PREFIX: &str = "- ";
+
+/// The only version defined by EIP-4361.
+/// cbindgen:ignore
+pub const SIWE_VERSION: &str = "1";
+/// cbindgen:ignore
+const MIN_NONCE_LEN: usize = 8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SiweMessage {
+    /// Optional URI scheme of the origin, e.g `https`.
+    pub scheme: Option<String>,
+    /// RFC 3986 authority requesting the signing, e.g `example.com` or `localhost:3000`.
+    pub domain: String,
+    /// EIP-55 checksummed address of the signer.
+    pub address: Address,
+    /// Optional human-readable assertion the user signs, must not contain line breaks.
+    pub statement: Option<String>,
+    /// RFC 3986 URI referring to the subject of the signing.
+    pub uri: String,
+    pub version: String,
+    pub chain_id: u64,
+    /// At least 8 alphanumeric characters.
+    pub nonce: String,
+    /// RFC 3339 date-time, e.g `2021-09-30T16:25:24Z`.
+    pub issued_at: String,
+    /// Optional RFC 3339 date-time the message is no longer valid at.
+    pub expiration_time: Option<String>,
+    /// Optional RFC 3339 date-time the message becomes valid at.
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+impl SiweMessage {
+    /// Checks if all fields comply with EIP-4361,
+    /// so the message can be formatted to a canonical string.
+    pub fn validate(&self) -> MessageSigningResult<()> {
+        if let Some(ref scheme) = self.scheme {
+            ensure(is_uri_scheme(scheme))?;
+        }
+        ensure(is_authority(&self.domain))?;
+        if let Some(ref statement) = self.statement {
+            ensure(!statement.is_empty() && !statement.contains('\n'))?;
+        }
+        ensure(is_uri(&self.uri))?;
+        ensure(self.version == SIWE_VERSION)?;
+        ensure(
+            self.nonce.len() >= MIN_NONCE_LEN
+                && self.nonce.chars().all(|ch| ch.is_ascii_alphanumeric()),
+        )?;
+
+        let issued_at = parse_date_time(&self.issued_at)?;
+        let expiration_time = self
+            .expiration_time
+            .as_deref()
+            .map(parse_date_time)
+            .transpose()?;
+        let not_before = self
+            .not_before
+            .as_deref()
+            .map(parse_date_time)
+            .transpose()?;
+        if let Some(expiration_time) = expiration_time {
+            ensure(issued_at < expiration_time)?;
+            if let Some(not_before) = not_before {
+                ensure(not_before < expiration_time)?;
+            }
+        }
+
+        if let Some(ref request_id) = self.request_id {
+            ensure(!request_id.chars().any(char::is_whitespace))?;
+        }
+        for resource in self.resources.iter() {
+            ensure(is_uri(resource))?;
+        }
+        Ok(())
+    }
+
+    /// Checks if the message is valid at the given unix timestamp in seconds,
+    /// i.e `not_before <= timestamp < expiration_time`.
+    pub fn is_valid_at(&self, timestamp: i64) -> bool {
+        let expired = match self.expiration_time.as_deref().map(parse_date_time) {
+            Some(Ok(expiration_time)) => expiration_time <= timestamp,
+            Some(Err(_)) => return false,
+            None => false,
+        };
+        let premature = match self.not_before.as_deref().map(parse_date_time) {
+            Some(Ok(not_before)) => timestamp < not_before,
+            Some(Err(_)) => return false,
+            None => false,
+        };
+        !expired && !premature
+    }
+}
+
+impl FromStr for SiweMessage {
+    type Err = MessageSigningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.split('\n');
+        let mut next_line = || {
+            lines
+                .next()
+                .ok_or(MessageSigningError::InvalidParameterValue)
+        };
+
+        let origin = next_line()?
+            .strip_suffix(HEADER_SUFFIX)
+            .ok_or(MessageSigningError::InvalidParameterValue)?;
+        let (scheme, domain) = match origin.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme.to_string()), domain),
+            None => (None, origin),
+        };
+
+        // The address must be EIP-55 checksummed.
+        let address_str = next_line()?;
+        let address = Address::from_str(address_str)
+            .map_err(|_| MessageSigningError::InvalidParameterValue)?;
+        ensure(address.to_string() == address_str)?;
+
+        ensure(next_line()?.is_empty())?;
+        let statement = match next_line()? {
+            "" => None,
+            statement => {
+                ensure(next_line()?.is_empty())?;
+                Some(statement.to_string())
+            },
+        };
+
+        let uri = required_field(next_line()?, URI_TAG)?;
+        let version = required_field(next_line()?, VERSION_TAG)?;
+        let chain_id_str = required_field(next_line()?, CHAIN_ID_TAG)?;
+        let nonce = required_field(next_line()?, NONCE_TAG)?;
+        let issued_at = required_field(next_line()?, ISSUED_AT_TAG)?;
+
+        // Chain ID is a decimal number without leading zeros.
+        ensure(chain_id_str.chars().all(|ch| ch.is_ascii_digit()))?;
+        ensure(chain_id_str == "0" || !chain_id_str.starts_with('0'))?;
+        let chain_id = chain_id_str
+            .parse()
+            .map_err(|_| MessageSigningError::InvalidParameterValue)?;
+
+        let mut optional_lines = lines.peekable();
+        let mut optional_field = |tag: &str| {
+            let value = optional_lines.peek()?.strip_prefix(tag)?.to_string();
+            optional_lines.next();
+            Some(value)
+        };
+        let expiration_time = optional_field(EXPIRATION_TIME_TAG);
+        let not_before = optional_field(NOT_BEFORE_TAG);
+        let request_id = optional_field(REQUEST_ID_TAG);
+
+        let mut resources = Vec::new();
+        match optional_lines.next() {
+            Some(RESOURCES_TAG) => {
+                for line in optional_lines {
+                    let resource = line
+                        .strip_prefix(RESOURCE_PREFIX)
+                        .ok_or(MessageSigningError::InvalidParameterValue)?;
+                    resources.push(resource.to_string());
+                }
+            },
+            Some(_) => return Err(MessageSigningError::InvalidParameterValue),
+            None => (),
+        }
+
+        let msg = SiweMessage {
+            scheme,
+            domain: domain.to_string(),
+            address,
+            statement,
+            uri,
+            version,
+            chain_id,
+            nonce,
+            issued_at,
+            expiration_time,
+            not_before,
+            request_id,
+            resources,
+        };
+        msg.validate()?;
+        Ok(msg)
+    }
+}
+
+/// Formats the message in the canonical form, expected to be called on a [`SiweMessage::validate`]d message.
+impl fmt::Display for SiweMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref scheme) = self.scheme {
+            write!(f, "{scheme}://")?;
+        }
+        writeln!(f, "{}{HEADER_SUFFIX}", self.domain)?;
+        writeln!(f, "{}", self.address)?;
+        writeln!(f)?;
+        if let Some(ref statement) = self.statement {
+            writeln!(f, "{statement}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{URI_TAG}{}", self.uri)?;
+        writeln!(f, "{VERSION_TAG}{}", self.version)?;
+        writeln!(f, "{CHAIN_ID_TAG}{}", self.chain_id)?;
+        writeln!(f, "{NONCE_TAG}{}", self.nonce)?;
+        write!(f, "{ISSUED_AT_TAG}{}", self.issued_at)?;
+        if let Some(ref expiration_time) = self.expiration_time {
+            write!(f, "\n{EXPIRATION_TIME_TAG}{expiration_time}")?;
+        }
+        if let Some(ref not_before) = self.not_before {
+            write!(f, "\n{NOT_BEFORE_TAG}{not_before}")?;
+        }
+        if let Some(ref request_id) = self.request_id {
+            write!(f, "\n{REQUEST_ID_TAG}{request_id}")?;
+        }
+        if !self.resources.is_empty() {
+            write!(f, "\n{RESOURCES_TAG}")?;
+            for resource in self.resources.iter() {
+                write!(f, "\n{RESOURCE_PREFIX}{resource}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// SIWE messages are signed as EIP-191 personal messages.
+impl EthMessage for SiweMessage {
+    fn hash(&self) -> MessageSigningResult<H256> {
+        Eip191Message::new(self.to_string()).hash()
+    }
+}
+
+fn ensure(condition: bool) -> MessageSigningResult<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(MessageSigningError::InvalidParameterValue)
+    }
+}
+
+fn required_field(line: &str, tag: &str) -> MessageSigningResult<String> {
+    line.strip_prefix(tag)
+        .map(str::to_string)
+        .ok_or(MessageSigningError::InvalidParameterValue)
+}
+
+/// `scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`
+fn is_uri_scheme(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_alphabetic())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'))
+}
+
+/// `authority = [ userinfo "@" ] host [ ":" port ]`
+fn is_authority(s: &str) -> bool {
+    !s.is_empty()
+        && !s
+            .chars()
+            .any(|ch| ch.is_whitespace() || matches!(ch, '/' | '?' | '#'))
+}
+
+/// `URI = scheme ":" hier-part [ "?" query ] [ "#" fragment ]`
+fn is_uri(s: &str) -> bool {
+    match s.split_once(':') {
+        Some((scheme, rest)) => {
+            is_uri_scheme(scheme) && !rest.is_empty() && !s.chars().any(char::is_whitespace)
+        },
+        None => false,
+    }
+}
+
+/// Parses an RFC 3339 date-time, e.g `2021-09-30T16:25:24.000Z`, to a unix timestamp in seconds.
+fn parse_date_time(s: &str) -> MessageSigningResult<i64> {
+    fn number(s: &str, range: std::ops::Range<usize>) -> MessageSigningResult<i64> {
+        let digits = s
+            .get(range)
+            .ok_or(MessageSigningError::InvalidParameterValue)?;
+        ensure(digits.chars().all(|ch| ch.is_ascii_digit()))?;
+        digits
+            .parse()
+            .map_err(|_| MessageSigningError::InvalidParameterValue)
+    }
+
+    // `YYYY-MM-DDTHH:MM:SS`
+    let bytes = s.as_bytes();
+    ensure(bytes.len() > 19)?;
+    ensure(bytes[4] == b'-' && bytes[7] == b'-' && bytes[13] == b':' && bytes[16] == b':')?;
+    ensure(matches!(bytes[10], b'T' | b't'))?;
+
+    let year = number(s, 0..4)?;
+    let month = number(s, 5..7)?;
+    let day = number(s, 8..10)?;
+    let hour = number(s, 11..13)?;
+    let minute = number(s, 14..16)?;
+    // Allow leap seconds.
+    let second = number(s, 17..19)?;
+    ensure((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))?;
+    ensure(hour < 24 && minute < 60 && second <= 60)?;
+
+    // Skip the optional fraction of a second.
+    let mut offset_str = &s[19..];
+    if let Some(fraction) = offset_str.strip_prefix('.') {
+        let fraction_len = fraction.chars().take_while(char::is_ascii_digit).count();
+        ensure(fraction_len > 0)?;
+        offset_str = &fraction[fraction_len..];
+    }
+
+    let offset = match offset_str {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match offset_str.get(..1) {
+                Some("+") => 1,
+                Some("-") => -1,
+                _ => return Err(MessageSigningError::InvalidParameterValue),
+            };
+            ensure(offset_str.len() == 6 && offset_str.as_bytes()[3] == b':')?;
+            let offset_hour = number(offset_str, 1..3)?;
+            let offset_minute = number(offset_str, 4..6)?;
+            ensure(offset_hour < 24 && offset_minute < 60)?;
+            sign * (offset_hour * 3600 + offset_minute * 60)
+        },
+    };
+
+    let days = days_from_civil(year, month, day);
+    Ok(days * 86400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01.
+/// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    const MESSAGE: &str = "https://example.com wants you to sign in with your Ethereum account:
+0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F
+
+I accept the ExampleOrg Terms of Service: https://example.com/tos
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Expiration Time: 2021-10-01T16:25:24.000+02:00
+Request ID: some_id
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+
+    #[test]
+    fn test_parse_siwe_message() {
+        let msg = SiweMessage::from_str(MESSAGE).unwrap();
+        assert_eq!(msg.scheme.as_deref(), Some("https"));
+        assert_eq!(msg.domain, "example.com");
+        assert_eq!(
+            msg.address,
+            Address::from("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F")
+        );
+        assert_eq!(
+            msg.statement.as_deref(),
+            Some("I accept the ExampleOrg Terms of Service: https://example.com/tos")
+        );
+        assert_eq!(msg.chain_id, 1);
+        assert_eq!(msg.nonce, "32891756");
+        assert_eq!(msg.not_before, None);
+        assert_eq!(msg.request_id.as_deref(), Some("some_id"));
+        assert_eq!(msg.resources.len(), 2);
+
+        // The canonical message must be the same.
+        assert_eq!(msg.to_string(), MESSAGE);
+        assert_eq!(
+            msg.hash().unwrap().to_hex(),
+            Eip191Message::new(MESSAGE).hash().unwrap().to_hex()
+        );
+    }
+
+    #[test]
+    fn test_build_siwe_message() {
+        let msg = SiweMessage {
+            scheme: None,
+            domain: "localhost:3000".to_string(),
+            address: Address::from("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            statement: None,
+            uri: "http://localhost:3000".to_string(),
+            version: SIWE_VERSION.to_string(),
+            chain_id: 137,
+            nonce: "abcdEFGH1234".to_string(),
+            issued_at: "2024-01-01T00:00:00Z".to_string(),
+            expiration_time: None,
+            not_before: Some("2024-01-01T00:10:00Z".to_string()),
+            request_id: None,
+            resources: Vec::default(),
+        };
+        msg.validate().unwrap();
+
+        let expected = "localhost:3000 wants you to sign in with your Ethereum account:
+0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F
+
+
+URI: http://localhost:3000
+Version: 1
+Chain ID: 137
+Nonce: abcdEFGH1234
+Issued At: 2024-01-01T00:00:00Z
+Not Before: 2024-01-01T00:10:00Z";
+        assert_eq!(msg.to_string(), expected);
+        assert_eq!(SiweMessage::from_str(expected).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_siwe_message_time_validity() {
+        let msg = SiweMessage::from_str(MESSAGE).unwrap();
+        // 2021-09-30T16:25:24Z
+        let issued_at = 1_633_019_124;
+        // 2021-10-01T16:25:24+02:00
+        let expiration_time = issued_at + 86_400 - 7_200;
+
+        assert!(msg.is_valid_at(issued_at));
+        assert!(msg.is_valid_at(expiration_time - 1));
+        assert!(!msg.is_valid_at(expiration_time));
+    }
+
+    #[test]
+    fn test_parse_invalid_siwe_message() {
+        let invalid = [
+            // Address isn't checksummed.
+            MESSAGE.replace(
+                "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+                "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f",
+            ),
+            // Nonce is too short.
+            MESSAGE.replace("Nonce: 32891756", "Nonce: 1234"),
+            // Unsupported version.
+            MESSAGE.replace("Version: 1", "Version: 2"),
+            // Invalid date.
+            MESSAGE.replace("2021-09-30T16:25:24Z", "2021-09-31T16:25:24Z"),
+            // Expired before issued.
+            MESSAGE.replace("2021-10-01T16:25:24.000+02:00", "2021-09-30T16:25:24Z"),
+            // Unexpected field.
+            MESSAGE.replace("Request ID: some_id", "Request: some_id"),
+            // Missing blank line before `URI`.
+            MESSAGE.replace("Terms of Service: https://example.com/tos\n", "x"),
+        ];
+        for msg in invalid {
+            SiweMessage::from_str(&msg).unwrap_err();
+        }
+    }
+}
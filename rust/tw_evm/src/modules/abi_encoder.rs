@@ -4,11 +4,15 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::abi::contract::Contract;
 use crate::abi::decode::{decode_params, decode_value};
+use crate::abi::encode::encode_packed;
 use crate::abi::function::Function;
+use crate::abi::interpreter::{CallIntent, CallInterpreter, ContractInterface, RiskFlag};
 use crate::abi::param::Param;
 use crate::abi::param_token::NamedToken;
 use crate::abi::param_type::ParamType;
+use crate::abi::revert::RevertReason;
 use crate::abi::token::Token;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::abi_output_error;
@@ -20,7 +24,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::str::FromStr;
-use tw_hash::H32;
+use tw_hash::{sha3::keccak256, H256, H32};
 use tw_misc::traits::ToBytesVec;
 use tw_number::{I256, U256};
 use tw_proto::EthereumAbi::Proto;
@@ -73,6 +77,36 @@ impl<Context: EvmContext> AbiEncoder<Context> {
             .unwrap_or_else(|err| abi_output_error!(Proto::FunctionEncodingOutput, err))
     }
 
+    #[inline]
+    pub fn decode_log(input: Proto::LogDecodingInput<'_>) -> Proto::LogDecodingOutput<'static> {
+        Self::decode_log_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::LogDecodingOutput, err))
+    }
+
+    #[inline]
+    pub fn decode_revert(
+        input: Proto::RevertDecodingInput<'_>,
+    ) -> Proto::RevertDecodingOutput<'static> {
+        Self::decode_revert_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::RevertDecodingOutput, err))
+    }
+
+    #[inline]
+    pub fn encode_packed(
+        input: Proto::PackedEncodingInput<'_>,
+    ) -> Proto::PackedEncodingOutput<'static> {
+        Self::encode_packed_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::PackedEncodingOutput, err))
+    }
+
+    #[inline]
+    pub fn interpret_contract_call(
+        input: Proto::ContractCallInterpretingInput<'_>,
+    ) -> Proto::ContractCallInterpretingOutput<'static> {
+        Self::interpret_contract_call_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::ContractCallInterpretingOutput, err))
+    }
+
     fn decode_contract_call_impl(
         input: Proto::ContractCallDecodingInput,
     ) -> AbiResult<Proto::ContractCallDecodingOutput<'static>> {
@@ -161,6 +195,170 @@ impl<Context: EvmContext> AbiEncoder<Context> {
         })
     }
 
+    fn decode_log_impl(
+        input: Proto::LogDecodingInput<'_>,
+    ) -> AbiResult<Proto::LogDecodingOutput<'static>> {
+        let topics = input
+            .topics
+            .iter()
+            .map(|topic| {
+                H256::try_from(topic.as_ref())
+                    .map_err(|_| AbiError(AbiErrorKind::Error_decoding_data))
+            })
+            .collect::<AbiResult<Vec<_>>>()?;
+
+        let contract: Contract = serde_json::from_str(&input.smart_contract_abi_json)
+            .map_err(|_| AbiError(AbiErrorKind::Error_invalid_abi))?;
+
+        // Anonymous events can only be found by name.
+        let event = if input.event_name.is_empty() {
+            let first_topic = topics
+                .first()
+                .ok_or(AbiError(AbiErrorKind::Error_decoding_data))?;
+            contract
+                .event_by_topic(first_topic)
+                .ok_or(AbiError(AbiErrorKind::Error_abi_mismatch))?
+        } else {
+            contract.event(&input.event_name)?
+        };
+
+        let decoded_tokens = event.decode_log(&topics, &input.data)?;
+        let decoded_protos = decoded_tokens
+            .into_iter()
+            .map(Self::named_token_to_proto)
+            .collect();
+
+        Ok(Proto::LogDecodingOutput {
+            event_signature: event.signature().into(),
+            tokens: decoded_protos,
+            ..Proto::LogDecodingOutput::default()
+        })
+    }
+
+    fn decode_revert_impl(
+        input: Proto::RevertDecodingInput<'_>,
+    ) -> AbiResult<Proto::RevertDecodingOutput<'static>> {
+        let contract: Option<Contract> = if input.smart_contract_abi_json.is_empty() {
+            None
+        } else {
+            let contract = serde_json::from_str(&input.smart_contract_abi_json)
+                .map_err(|_| AbiError(AbiErrorKind::Error_invalid_abi))?;
+            Some(contract)
+        };
+
+        let reason = RevertReason::decode(&input.encoded, contract.as_ref())?;
+        let decoded_protos = reason
+            .params()
+            .into_iter()
+            .map(Self::named_token_to_proto)
+            .collect();
+
+        Ok(Proto::RevertDecodingOutput {
+            error_signature: reason.signature().into(),
+            tokens: decoded_protos,
+            reason: reason.description().unwrap_or_default().into(),
+            ..Proto::RevertDecodingOutput::default()
+        })
+    }
+
+    fn encode_packed_impl(
+        input: Proto::PackedEncodingInput<'_>,
+    ) -> AbiResult<Proto::PackedEncodingOutput<'static>> {
+        let tokens = input
+            .tokens
+            .into_iter()
+            .map(Self::token_from_proto)
+            .collect::<AbiResult<Vec<_>>>()?;
+
+        let encoded = encode_packed(&tokens)?;
+        let hash = keccak256(&encoded);
+        Ok(Proto::PackedEncodingOutput {
+            encoded: encoded.into(),
+            hash: hash.into(),
+            ..Proto::PackedEncodingOutput::default()
+        })
+    }
+
+    fn interpret_contract_call_impl(
+        input: Proto::ContractCallInterpretingInput<'_>,
+    ) -> AbiResult<Proto::ContractCallInterpretingOutput<'static>> {
+        let mut interpreter = CallInterpreter::with_prebuild();
+        for abi in input.abis {
+            let contract: Contract = serde_json::from_str(&abi.smart_contract_abi_json)
+                .map_err(|_| AbiError(AbiErrorKind::Error_invalid_abi))?;
+            interpreter.register(abi.name, contract);
+        }
+
+        let hint = match input.interface_hint {
+            Proto::ContractInterface::Custom => None,
+            Proto::ContractInterface::ERC20 => Some(ContractInterface::Erc20),
+            Proto::ContractInterface::ERC721 => Some(ContractInterface::Erc721),
+            Proto::ContractInterface::ERC1155 => Some(ContractInterface::Erc1155),
+            Proto::ContractInterface::ERC4337Account => Some(ContractInterface::Erc4337Account),
+            Proto::ContractInterface::UniswapV3SwapRouter => {
+                Some(ContractInterface::UniswapV3SwapRouter)
+            },
+            Proto::ContractInterface::Unknown | Proto::ContractInterface::NativeTransfer => None,
+        };
+
+        let intent = interpreter.interpret(&input.encoded, hint)?;
+        Ok(Proto::ContractCallInterpretingOutput {
+            intent: Some(Self::call_intent_to_proto(intent)),
+            ..Proto::ContractCallInterpretingOutput::default()
+        })
+    }
+
+    fn call_intent_to_proto(intent: CallIntent) -> Proto::ContractCallIntent<'static> {
+        let interface = match intent.interface {
+            ContractInterface::Custom => Proto::ContractInterface::Custom,
+            ContractInterface::Erc20 => Proto::ContractInterface::ERC20,
+            ContractInterface::Erc721 => Proto::ContractInterface::ERC721,
+            ContractInterface::Erc1155 => Proto::ContractInterface::ERC1155,
+            ContractInterface::Erc4337Account => Proto::ContractInterface::ERC4337Account,
+            ContractInterface::UniswapV3SwapRouter => Proto::ContractInterface::UniswapV3SwapRouter,
+            ContractInterface::NativeTransfer => Proto::ContractInterface::NativeTransfer,
+            ContractInterface::Unknown => Proto::ContractInterface::Unknown,
+        };
+        let risks = intent
+            .risks
+            .into_iter()
+            .map(|risk| match risk {
+                RiskFlag::UnlimitedApproval => Proto::RiskFlag::UnlimitedApproval,
+                RiskFlag::ApprovalForAll => Proto::RiskFlag::ApprovalForAll,
+            })
+            .collect();
+
+        Proto::ContractCallIntent {
+            contract_name: Cow::Owned(intent.contract_name),
+            interface,
+            function_signature: Cow::Owned(intent.function_signature),
+            params: intent
+                .params
+                .into_iter()
+                .map(Self::named_token_to_proto)
+                .collect(),
+            description: Cow::Owned(intent.description),
+            risks,
+            inner_calls: intent
+                .inner_calls
+                .into_iter()
+                .map(Self::call_intent_to_proto)
+                .collect(),
+            to: intent
+                .to
+                .map(|to| Cow::Owned(to.to_string()))
+                .unwrap_or_default(),
+            value: intent
+                .value
+                .map(|value| Cow::Owned(value.to_big_endian_compact()))
+                .unwrap_or_default(),
+            selector: intent
+                .selector
+                .map(|selector| Cow::Owned(selector.to_vec()))
+                .unwrap_or_default(),
+        }
+    }
+
     fn get_function_signature_impl(input: Proto::FunctionGetTypeInput<'_>) -> String {
         let function_inputs = input
             .inputs
@@ -0,0 +1,74 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::evm_context::EvmContext;
+use crate::modules::chain_id;
+use crate::modules::tx_builder::TxBuilder;
+use crate::transaction::signature::EthSignature;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_proto::Ethereum::Proto;
+
+pub struct AuthorizationSigner<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> AuthorizationSigner<Context> {
+    /// Signs an EIP-7702 authorization to be carried by a set-code transaction.
+    pub fn sign_authorization_proto(
+        input: Proto::AuthorizationSigningInput<'_>,
+    ) -> Proto::AuthorizationSigningOutput<'static> {
+        Self::sign_authorization_proto_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::AuthorizationSigningOutput, e))
+    }
+
+    /// Same as [`AuthorizationSigner::sign_authorization_proto`], but the chain ID is checked
+    /// against the coin if `AuthorizationSigningInput.check_chain_id` is set.
+    pub fn sign_authorization_proto_for_coin(
+        coin: &dyn CoinContext,
+        input: Proto::AuthorizationSigningInput<'_>,
+    ) -> Proto::AuthorizationSigningOutput<'static> {
+        chain_id::check_authorization_input(coin, &input)
+            .and_then(|_| Self::sign_authorization_proto_impl(input))
+            .unwrap_or_else(|e| signing_output_error!(Proto::AuthorizationSigningOutput, e))
+    }
+
+    fn sign_authorization_proto_impl(
+        input: Proto::AuthorizationSigningInput<'_>,
+    ) -> SigningResult<Proto::AuthorizationSigningOutput<'static>> {
+        let Some(ref authorization) = input.authorization else {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        };
+        let authorization = TxBuilder::<Context>::authorization_from_proto(authorization)?;
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+
+        let pre_hash = authorization.pre_hash();
+        let signed = authorization.sign(&private_key)?;
+
+        let authorization = Proto::Authorization {
+            chain_id: Cow::from(signed.authorization.chain_id.to_big_endian_compact()),
+            address: Cow::from(signed.authorization.address.to_string()),
+            nonce: Cow::from(signed.authorization.nonce.to_big_endian_compact()),
+        };
+        // `v` is a y-parity, so it's always either 0 or 1.
+        let y_parity = u32::try_from(signed.signature.v())?;
+
+        Ok(Proto::AuthorizationSigningOutput {
+            authorization: Some(Proto::SignedAuthorization {
+                authorization: Some(authorization),
+                y_parity,
+                r: Cow::from(signed.signature.r().to_big_endian().to_vec()),
+                s: Cow::from(signed.signature.s().to_big_endian().to_vec()),
+            }),
+            pre_hash: Cow::from(pre_hash.to_vec()),
+            ..Proto::AuthorizationSigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+//! Chain ID policy: signing inputs can opt in to be checked against the chain ID
+//! of the coin stated in `registry.json`, so that a transaction, an authorization
+//! or a message is not signed for another chain by mistake.
+
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+
+/// Returns the chain ID of the coin.
+/// Fails if `registry.json` doesn't specify the chain ID.
+pub fn coin_chain_id(coin: &dyn CoinContext) -> SigningResult<U256> {
+    let chain_id = coin
+        .chain_id()
+        .ok_or(SigningError(SigningErrorType::Error_not_supported))?;
+    Ok(U256::from_str(chain_id)?)
+}
+
+/// Checks if `chain_id` is the chain ID of the coin.
+pub fn check_chain_id(coin: &dyn CoinContext, chain_id: U256) -> SigningResult<()> {
+    if chain_id != coin_chain_id(coin)? {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    Ok(())
+}
+
+/// Checks `AuthorizationSigningInput.authorization.chain_id`
+/// if `AuthorizationSigningInput.check_chain_id` is set.
+pub fn check_authorization_input(
+    coin: &dyn CoinContext,
+    input: &Proto::AuthorizationSigningInput<'_>,
+) -> SigningResult<()> {
+    if !input.check_chain_id {
+        return Ok(());
+    }
+    let Some(ref authorization) = input.authorization else {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    };
+    let chain_id = U256::from_big_endian_slice(&authorization.chain_id)?;
+    check_chain_id(coin, chain_id)
+}
+
+/// Checks `SigningInput.chain_id` if `SigningInput.check_chain_id` is set.
+pub fn check_signing_input(
+    coin: &dyn CoinContext,
+    input: &Proto::SigningInput<'_>,
+) -> SigningResult<()> {
+    if !input.check_chain_id {
+        return Ok(());
+    }
+    let chain_id = U256::from_big_endian_slice(&input.chain_id)?;
+    check_chain_id(coin, chain_id)
+}
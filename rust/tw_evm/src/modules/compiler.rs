@@ -5,9 +5,11 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::evm_context::EvmContext;
+use crate::modules::chain_id;
 use crate::modules::tx_builder::TxBuilder;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::SigningResult;
@@ -40,13 +42,39 @@ impl<Context: EvmContext> Compiler<Context> {
             .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
     }
 
+    /// Same as [`Compiler::preimage_hashes`], but the chain ID is checked against the coin
+    /// if `SigningInput.check_chain_id` is set.
+    #[inline]
+    pub fn preimage_hashes_for_coin(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        chain_id::check_signing_input(coin, &input)
+            .and_then(|_| Self::preimage_hashes_impl(input))
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    /// Same as [`Compiler::compile`], but the chain ID is checked against the coin
+    /// if `SigningInput.check_chain_id` is set.
+    #[inline]
+    pub fn compile_for_coin(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        chain_id::check_signing_input(coin, &input)
+            .and_then(|_| Self::compile_impl(input, signatures, public_keys))
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
     fn preimage_hashes_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
         let chain_id = U256::from_big_endian_slice(&input.chain_id)?;
 
         let unsigned = TxBuilder::<Context>::tx_from_proto(&input)?;
-        let prehash = unsigned.pre_hash(chain_id);
+        let prehash = unsigned.pre_hash(chain_id)?;
         let preimage_data = unsigned.encode(chain_id);
 
         Ok(CompilerProto::PreSigningOutput {
@@ -70,7 +98,7 @@ impl<Context: EvmContext> Compiler<Context> {
 
         let unsigned = TxBuilder::<Context>::tx_from_proto(&input)?;
 
-        let pre_hash = unsigned.pre_hash(chain_id);
+        let pre_hash = unsigned.pre_hash(chain_id)?;
 
         let signed = unsigned.try_into_signed(signature, chain_id)?;
 
@@ -83,6 +111,7 @@ impl<Context: EvmContext> Compiler<Context> {
             s: eth_signature.s().to_big_endian_compact().into(),
             data: signed.payload().into(),
             pre_hash: pre_hash.to_vec().into(),
+            network_encoded: signed.encode_network().unwrap_or_default().into(),
             ..Proto::SigningOutput::default()
         })
     }
@@ -0,0 +1,140 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::contract_address::{
+    create2_address, create_address, init_code_hash, BarzDeployment, SafeProxyDeployment,
+    SimpleAccountDeployment,
+};
+use crate::evm_context::EvmContext;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tw_coin_entry::error::{AddressResult, SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::DecodeHex;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Barz::Proto as BarzProto;
+use tw_proto::Ethereum::Proto;
+use Proto::mod_DeploymentAddressInput::OneOfdeployment as DeploymentType;
+
+pub struct DeploymentAddress<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> DeploymentAddress<Context> {
+    /// Computes an address of a contract or a smart account before it's deployed.
+    pub fn compute_address_proto(
+        input: Proto::DeploymentAddressInput<'_>,
+    ) -> Proto::DeploymentAddressOutput<'static> {
+        Self::compute_address_proto_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DeploymentAddressOutput, e))
+    }
+
+    fn compute_address_proto_impl(
+        input: Proto::DeploymentAddressInput<'_>,
+    ) -> SigningResult<Proto::DeploymentAddressOutput<'static>> {
+        let (address, init_code_hash) = match input.deployment {
+            DeploymentType::create(ref create) => {
+                let sender = Self::parse_address(&create.sender)?;
+                let nonce = U256::from_big_endian_slice(&create.nonce)?;
+                (create_address(sender, nonce), None)
+            },
+            DeploymentType::create2(ref create2) => Self::create2_from_proto(create2)?,
+            DeploymentType::simple_account(ref simple_account) => {
+                let deployment = Self::simple_account_from_proto(simple_account)?;
+                (deployment.address()?, Some(deployment.init_code_hash()?))
+            },
+            DeploymentType::barz(ref barz) => {
+                let deployment = Self::barz_from_proto(barz)?;
+                (deployment.address(), Some(deployment.init_code_hash()))
+            },
+            DeploymentType::safe(ref safe) => {
+                let deployment = Self::safe_from_proto(safe)?;
+                (deployment.address(), Some(deployment.init_code_hash()))
+            },
+            DeploymentType::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params));
+            },
+        };
+
+        Ok(Proto::DeploymentAddressOutput {
+            address: Cow::from(address.to_string()),
+            init_code_hash: init_code_hash
+                .map(|hash| Cow::from(hash.into_vec()))
+                .unwrap_or_default(),
+            ..Proto::DeploymentAddressOutput::default()
+        })
+    }
+
+    fn create2_from_proto(
+        create2: &Proto::Create2Deployment,
+    ) -> SigningResult<(Address, Option<H256>)> {
+        let deployer = Self::parse_address(&create2.deployer)?;
+        let salt = U256::from_big_endian_slice(&create2.salt)?.to_big_endian();
+
+        let init_code_hash = if create2.init_code_hash.is_empty() {
+            init_code_hash(&create2.init_code)
+        } else {
+            H256::try_from(create2.init_code_hash.as_ref())
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?
+        };
+
+        Ok((
+            create2_address(deployer, salt, init_code_hash),
+            Some(init_code_hash),
+        ))
+    }
+
+    fn simple_account_from_proto(
+        simple_account: &Proto::SimpleAccountDeployment,
+    ) -> SigningResult<SimpleAccountDeployment> {
+        Ok(SimpleAccountDeployment {
+            factory: Self::parse_address(&simple_account.factory)?,
+            account_implementation: Self::parse_address(&simple_account.account_implementation)?,
+            proxy_bytecode: simple_account.proxy_bytecode.to_vec(),
+            owner: Self::parse_address(&simple_account.owner)?,
+            salt: U256::from_big_endian_slice(&simple_account.salt)?,
+        })
+    }
+
+    fn barz_from_proto(barz: &BarzProto::ContractAddressInput) -> SigningResult<BarzDeployment> {
+        Ok(BarzDeployment {
+            factory: Self::parse_address(&barz.factory)?,
+            account_facet: Self::parse_address(&barz.account_facet)?,
+            verification_facet: Self::parse_address(&barz.verification_facet)?,
+            entry_point: Self::parse_address(&barz.entry_point)?,
+            facet_registry: Self::parse_address(&barz.facet_registry)?,
+            default_fallback: Self::parse_address(&barz.default_fallback)?,
+            bytecode: Self::parse_hex(&barz.bytecode)?,
+            owner_public_key: Self::parse_hex(&barz.public_key)?,
+            salt: U256::from(barz.salt),
+        })
+    }
+
+    fn safe_from_proto(safe: &Proto::SafeProxyDeployment) -> SigningResult<SafeProxyDeployment> {
+        Ok(SafeProxyDeployment {
+            factory: Self::parse_address(&safe.factory)?,
+            proxy_bytecode: safe.proxy_bytecode.to_vec(),
+            singleton: Self::parse_address(&safe.singleton)?,
+            initializer: safe.initializer.to_vec(),
+            salt_nonce: U256::from_big_endian_slice(&safe.salt_nonce)?,
+        })
+    }
+
+    #[inline]
+    fn parse_address(addr: &str) -> AddressResult<Address> {
+        Context::Address::from_str(addr).map(Context::Address::into)
+    }
+
+    #[inline]
+    fn parse_hex(hex: &str) -> SigningResult<Data> {
+        hex.decode_hex()
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+    }
+}
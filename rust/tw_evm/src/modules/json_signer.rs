@@ -4,12 +4,26 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::address::Address;
 use crate::evm_context::EvmContext;
+use crate::modules::signer::Signer;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value as Json;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::modules::json_signer::JsonSigner;
+use tw_encoding::hex::DecodeHex;
+use tw_encoding::{base64, hex};
+use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::tw::PrivateKey;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use Proto::mod_Transaction::OneOftransaction_oneof as TransactionType;
+use Proto::TransactionMode as TxMode;
 
 #[derive(Default)]
 pub struct EthJsonSigner<Context: EvmContext> {
@@ -17,14 +31,514 @@ pub struct EthJsonSigner<Context: EvmContext> {
 }
 
 impl<Context: EvmContext> JsonSigner for EthJsonSigner<Context> {
+    /// Signs either the canonical JSON form of `Ethereum.Proto.SigningInput`,
+    /// or an `eth_signTransaction` RPC transaction object.
+    /// Returns the hex encoded signed transaction.
     #[inline]
     fn sign_json(
         &self,
-        _coin: &dyn CoinContext,
-        _input_json: &str,
-        _key: &PrivateKey,
+        coin: &dyn CoinContext,
+        input_json: &str,
+        key: &PrivateKey,
     ) -> SigningResult<String> {
-        // TODO implement when `quick_protobuf` is replaced with `rust-protobuf`.
-        Err(SigningError(SigningErrorType::Error_internal))
+        let input_json: Json = serde_json::from_str(input_json)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+
+        let mut input = if input_json.get("transaction").is_some() {
+            Self::signing_input_from_proto_json(input_json)?
+        } else {
+            Self::signing_input_from_rpc_json(input_json, key)?
+        };
+        input.private_key = Cow::from(key.key().into_vec());
+
+        let output = Signer::<Context>::sign_proto_for_coin(coin, input);
+        if output.error != SigningErrorType::OK {
+            return Err(SigningError(output.error));
+        }
+        Ok(hex::encode(output.encoded, false))
+    }
+}
+
+impl<Context: EvmContext> EthJsonSigner<Context> {
+    fn signing_input_from_proto_json(
+        input_json: Json,
+    ) -> SigningResult<Proto::SigningInput<'static>> {
+        let input: ProtoSigningInputJson = serde_json::from_value(input_json)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+
+        Ok(Proto::SigningInput {
+            chain_id: input.chain_id.into(),
+            nonce: input.nonce.into(),
+            tx_mode: tx_mode_from_json(&input.tx_mode)?,
+            gas_price: input.gas_price.into(),
+            gas_limit: input.gas_limit.into(),
+            max_inclusion_fee_per_gas: input.max_inclusion_fee_per_gas.into(),
+            max_fee_per_gas: input.max_fee_per_gas.into(),
+            to_address: input.to_address.into(),
+            transaction: Some(Proto::Transaction {
+                transaction_oneof: input.transaction.into_proto(),
+            }),
+            access_list: input
+                .access_list
+                .into_iter()
+                .map(|access| Proto::Access {
+                    address: access.address.into(),
+                    stored_keys: access.stored_keys.into_iter().map(Cow::from).collect(),
+                })
+                .collect(),
+            ..Proto::SigningInput::default()
+        })
+    }
+
+    fn signing_input_from_rpc_json(
+        input_json: Json,
+        key: &PrivateKey,
+    ) -> SigningResult<Proto::SigningInput<'static>> {
+        let tx: RpcTransactionJson = serde_json::from_value(input_json)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+
+        // Check if the transaction is supposed to be signed by the given key.
+        if let Some(ref from) = tx.from {
+            let private_key = secp256k1::PrivateKey::try_from(key.key().as_slice())?;
+            let signer = Address::with_secp256k1_pubkey(&private_key.public());
+            let from: Address = Context::Address::from_str(from)?.into();
+            if from != signer {
+                return Err(SigningError(SigningErrorType::Error_invalid_address));
+            }
+        }
+
+        // These fields have no sensible defaults, so they must be specified explicitly.
+        if tx.chain_id.is_none() || tx.nonce.is_none() || tx.gas.is_none() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        let tx_mode = match tx.tx_type {
+            // The transaction type is a single byte by EIP-2718.
+            Some(ref tx_type) => match u8::try_from(parse_quantity(tx_type)?)? {
+                0 => TxMode::Legacy,
+                1 => TxMode::AccessList,
+                2 => TxMode::Enveloped,
+                _ => return Err(SigningError(SigningErrorType::Error_not_supported)),
+            },
+            None if tx.max_fee_per_gas.is_some() => TxMode::Enveloped,
+            None => TxMode::Legacy,
+        };
+
+        // `input` is the preferred field name, `data` is kept for backward compatibility.
+        let data = match tx.input.as_ref().or(tx.data.as_ref()) {
+            Some(data) => data
+                .decode_hex()
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?,
+            None => Data::default(),
+        };
+
+        let access_list = tx
+            .access_list
+            .into_iter()
+            .map(|access| {
+                let stored_keys = access
+                    .storage_keys
+                    .iter()
+                    .map(|key| key.decode_hex().map(Cow::from))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+                Ok(Proto::Access {
+                    address: access.address.into(),
+                    stored_keys,
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        // The transaction is a contract deployment if `to` is omitted,
+        // so the init code is expected to be given in `input`.
+        let to_address = match tx.to {
+            Some(to) if to.is_empty() => {
+                return Err(SigningError(SigningErrorType::Error_invalid_address))
+            },
+            Some(to) => to,
+            None if data.is_empty() => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+            None => String::default(),
+        };
+
+        let contract_generic = Proto::mod_Transaction::ContractGeneric {
+            amount: quantity_to_proto(&tx.value)?,
+            data: data.into(),
+        };
+
+        Ok(Proto::SigningInput {
+            chain_id: quantity_to_proto(&tx.chain_id)?,
+            nonce: quantity_to_proto(&tx.nonce)?,
+            tx_mode,
+            gas_price: quantity_to_proto(&tx.gas_price)?,
+            gas_limit: quantity_to_proto(&tx.gas)?,
+            max_inclusion_fee_per_gas: quantity_to_proto(&tx.max_priority_fee_per_gas)?,
+            max_fee_per_gas: quantity_to_proto(&tx.max_fee_per_gas)?,
+            to_address: to_address.into(),
+            transaction: Some(Proto::Transaction {
+                transaction_oneof: TransactionType::contract_generic(contract_generic),
+            }),
+            access_list,
+            ..Proto::SigningInput::default()
+        })
+    }
+}
+
+/// Canonical proto3 JSON form of `Ethereum.Proto.SigningInput`:
+/// field names are in lowerCamelCase, `bytes` are base64 encoded.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProtoSigningInputJson {
+    #[serde(default, deserialize_with = "base64_bytes")]
+    chain_id: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    nonce: Data,
+    /// Either the enum value name or its number.
+    #[serde(default)]
+    tx_mode: Json,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    gas_price: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    gas_limit: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    max_inclusion_fee_per_gas: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    max_fee_per_gas: Data,
+    #[serde(default)]
+    to_address: String,
+    transaction: TransactionJson,
+    #[serde(default)]
+    access_list: Vec<AccessJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TransactionJson {
+    Transfer(ContractGenericJson),
+    Erc20Transfer(Erc20TransferJson),
+    Erc20Approve(Erc20ApproveJson),
+    Erc721Transfer(Erc721TransferJson),
+    Erc1155Transfer(Erc1155TransferJson),
+    ContractGeneric(ContractGenericJson),
+    Batch(BatchJson),
+    Erc721SafeTransfer(Erc721SafeTransferJson),
+    Erc721Approve(Erc721ApproveJson),
+    Erc721SetApprovalForAll(SetApprovalForAllJson),
+    Erc1155BatchTransfer(Erc1155BatchTransferJson),
+    Erc1155SetApprovalForAll(SetApprovalForAllJson),
+}
+
+impl TransactionJson {
+    fn into_proto(self) -> TransactionType<'static> {
+        match self {
+            TransactionJson::Transfer(transfer) => {
+                TransactionType::transfer(Proto::mod_Transaction::Transfer {
+                    amount: transfer.amount.into(),
+                    data: transfer.data.into(),
+                })
+            },
+            TransactionJson::Erc20Transfer(transfer) => {
+                TransactionType::erc20_transfer(Proto::mod_Transaction::ERC20Transfer {
+                    to: transfer.to.into(),
+                    amount: transfer.amount.into(),
+                })
+            },
+            TransactionJson::Erc20Approve(approve) => {
+                TransactionType::erc20_approve(Proto::mod_Transaction::ERC20Approve {
+                    spender: approve.spender.into(),
+                    amount: approve.amount.into(),
+                })
+            },
+            TransactionJson::Erc721Transfer(transfer) => {
+                TransactionType::erc721_transfer(Proto::mod_Transaction::ERC721Transfer {
+                    from: transfer.from.into(),
+                    to: transfer.to.into(),
+                    token_id: transfer.token_id.into(),
+                })
+            },
+            TransactionJson::Erc1155Transfer(transfer) => {
+                TransactionType::erc1155_transfer(Proto::mod_Transaction::ERC1155Transfer {
+                    from: transfer.from.into(),
+                    to: transfer.to.into(),
+                    token_id: transfer.token_id.into(),
+                    value: transfer.value.into(),
+                    data: transfer.data.into(),
+                })
+            },
+            TransactionJson::ContractGeneric(call) => {
+                TransactionType::contract_generic(Proto::mod_Transaction::ContractGeneric {
+                    amount: call.amount.into(),
+                    data: call.data.into(),
+                })
+            },
+            TransactionJson::Batch(batch) => {
+                TransactionType::batch(Proto::mod_Transaction::Batch {
+                    calls: batch
+                        .calls
+                        .into_iter()
+                        .map(|call| Proto::mod_Transaction::mod_Batch::BatchedCall {
+                            address: call.address.into(),
+                            amount: call.amount.into(),
+                            payload: call.payload.into(),
+                        })
+                        .collect(),
+                    multicall3_address: batch.multicall3_address.into(),
+                })
+            },
+            TransactionJson::Erc721SafeTransfer(transfer) => {
+                TransactionType::erc721_safe_transfer(Proto::mod_Transaction::ERC721SafeTransfer {
+                    from: transfer.from.into(),
+                    to: transfer.to.into(),
+                    token_id: transfer.token_id.into(),
+                    data: transfer.data.into(),
+                })
+            },
+            TransactionJson::Erc721Approve(approve) => {
+                TransactionType::erc721_approve(Proto::mod_Transaction::ERC721Approve {
+                    spender: approve.spender.into(),
+                    token_id: approve.token_id.into(),
+                })
+            },
+            TransactionJson::Erc721SetApprovalForAll(approval) => {
+                TransactionType::erc721_set_approval_for_all(approval.into_proto())
+            },
+            TransactionJson::Erc1155BatchTransfer(transfer) => {
+                TransactionType::erc1155_batch_transfer(
+                    Proto::mod_Transaction::ERC1155BatchTransfer {
+                        from: transfer.from.into(),
+                        to: transfer.to.into(),
+                        token_ids: transfer.token_ids.into_iter().map(Cow::from).collect(),
+                        values: transfer.values.into_iter().map(Cow::from).collect(),
+                        data: transfer.data.into(),
+                    },
+                )
+            },
+            TransactionJson::Erc1155SetApprovalForAll(approval) => {
+                TransactionType::erc1155_set_approval_for_all(approval.into_proto())
+            },
+        }
+    }
+}
+
+/// Both `Transfer` and `ContractGeneric` consist of `amount` and `data`.
+#[derive(Deserialize)]
+struct ContractGenericJson {
+    #[serde(default, deserialize_with = "base64_bytes")]
+    amount: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    data: Data,
+}
+
+#[derive(Deserialize)]
+struct Erc20TransferJson {
+    #[serde(default)]
+    to: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    amount: Data,
+}
+
+#[derive(Deserialize)]
+struct Erc20ApproveJson {
+    #[serde(default)]
+    spender: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    amount: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Erc721TransferJson {
+    #[serde(default)]
+    from: String,
+    #[serde(default)]
+    to: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    token_id: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Erc1155TransferJson {
+    #[serde(default)]
+    from: String,
+    #[serde(default)]
+    to: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    token_id: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    value: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    data: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Erc721SafeTransferJson {
+    #[serde(default)]
+    from: String,
+    #[serde(default)]
+    to: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    token_id: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    data: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Erc721ApproveJson {
+    #[serde(default)]
+    spender: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    token_id: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Erc1155BatchTransferJson {
+    #[serde(default)]
+    from: String,
+    #[serde(default)]
+    to: String,
+    #[serde(default, deserialize_with = "base64_bytes_list")]
+    token_ids: Vec<Data>,
+    #[serde(default, deserialize_with = "base64_bytes_list")]
+    values: Vec<Data>,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    data: Data,
+}
+
+/// Both ERC721 and ERC1155 `setApprovalForAll` consist of `operator` and `approved`.
+#[derive(Deserialize)]
+struct SetApprovalForAllJson {
+    #[serde(default)]
+    operator: String,
+    #[serde(default)]
+    approved: bool,
+}
+
+impl SetApprovalForAllJson {
+    fn into_proto(self) -> Proto::mod_Transaction::SetApprovalForAll<'static> {
+        Proto::mod_Transaction::SetApprovalForAll {
+            operator: self.operator.into(),
+            approved: self.approved,
+        }
     }
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchJson {
+    #[serde(default)]
+    calls: Vec<BatchedCallJson>,
+    #[serde(default)]
+    multicall3_address: String,
+}
+
+#[derive(Deserialize)]
+struct BatchedCallJson {
+    #[serde(default)]
+    address: String,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    amount: Data,
+    #[serde(default, deserialize_with = "base64_bytes")]
+    payload: Data,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessJson {
+    #[serde(default)]
+    address: String,
+    #[serde(default, deserialize_with = "base64_bytes_list")]
+    stored_keys: Vec<Data>,
+}
+
+/// `eth_signTransaction` transaction object, where quantities and data are hex encoded.
+/// https://ethereum.github.io/execution-apis/api-documentation
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcTransactionJson {
+    #[serde(rename = "type")]
+    tx_type: Option<String>,
+    chain_id: Option<String>,
+    nonce: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    gas: Option<String>,
+    gas_price: Option<String>,
+    max_fee_per_gas: Option<String>,
+    max_priority_fee_per_gas: Option<String>,
+    value: Option<String>,
+    input: Option<String>,
+    data: Option<String>,
+    #[serde(default)]
+    access_list: Vec<RpcAccessJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcAccessJson {
+    address: String,
+    #[serde(default)]
+    storage_keys: Vec<String>,
+}
+
+fn tx_mode_from_json(tx_mode: &Json) -> SigningResult<TxMode> {
+    let tx_mode = match tx_mode {
+        Json::Null => TxMode::Legacy,
+        Json::String(name) => match name.as_str() {
+            "Legacy" => TxMode::Legacy,
+            "Enveloped" => TxMode::Enveloped,
+            "AccessList" => TxMode::AccessList,
+            // Other transaction types require fields that are not supported in JSON yet.
+            "UserOp" | "Blob" | "SetCode" | "ZkSync" => {
+                return Err(SigningError(SigningErrorType::Error_not_supported))
+            },
+            _ => return Err(SigningError(SigningErrorType::Error_input_parse)),
+        },
+        Json::Number(number) => match number.as_i64() {
+            Some(0) => TxMode::Legacy,
+            Some(1) => TxMode::Enveloped,
+            Some(3) => TxMode::AccessList,
+            Some(2 | 4 | 5 | 6) => return Err(SigningError(SigningErrorType::Error_not_supported)),
+            _ => return Err(SigningError(SigningErrorType::Error_input_parse)),
+        },
+        _ => return Err(SigningError(SigningErrorType::Error_input_parse)),
+    };
+    Ok(tx_mode)
+}
+
+fn parse_quantity(quantity: &str) -> SigningResult<U256> {
+    if !quantity.starts_with("0x") {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    Ok(U256::from_str(quantity)?)
+}
+
+fn quantity_to_proto(quantity: &Option<String>) -> SigningResult<Cow<'static, [u8]>> {
+    match quantity {
+        Some(quantity) => Ok(parse_quantity(quantity)?.to_big_endian_compact().into()),
+        None => Ok(Cow::default()),
+    }
+}
+
+fn base64_bytes<'de, D>(deserializer: D) -> Result<Data, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let encoded = String::deserialize(deserializer)?;
+    base64::decode(&encoded, false).map_err(|e| serde::de::Error::custom(format!("{e:?}")))
+}
+
+fn base64_bytes_list<'de, D>(deserializer: D) -> Result<Vec<Data>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|encoded| base64::decode(encoded, false))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| serde::de::Error::custom(format!("{e:?}")))
+}
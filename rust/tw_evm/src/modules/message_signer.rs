@@ -4,20 +4,24 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::address::Address;
 use crate::message::eip191::Eip191Message;
-use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::eip712::eip712_message::{Eip712Message, Eip712Version};
 use crate::message::signature::{MessageSignature, SignatureType};
-use crate::message::{EthMessage, EthMessageBoxed};
+use crate::message::siwe::SiweMessage;
+use crate::message::{EthMessage, EthMessageBoxed, MessageSigningError};
+use crate::modules::chain_id;
 use std::borrow::Cow;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
-use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::modules::message_signer::MessageSigner;
 use tw_coin_entry::signing_output_error;
-use tw_encoding::hex::ToHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::ecdsa::signature::VerifySignature;
 use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
 use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -33,19 +37,19 @@ impl MessageSigner for EthMessageSigner {
 
     fn message_preimage_hashes(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::MessageSigningInput<'_>,
     ) -> Self::MessagePreSigningOutput {
-        Self::message_preimage_hashes_impl(input)
+        Self::message_preimage_hashes_impl(coin, input)
             .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
     }
 
     fn sign_message(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::MessageSigningInput<'_>,
     ) -> Self::MessageSigningOutput {
-        Self::sign_message_impl(input)
+        Self::sign_message_impl(coin, input)
             .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
     }
 
@@ -59,9 +63,35 @@ impl MessageSigner for EthMessageSigner {
 }
 
 impl EthMessageSigner {
+    /// Returns a display-ready tree of an EIP-712 typed data.
+    pub fn describe_typed_data(
+        input: Proto::TypedDataDescribingInput<'_>,
+    ) -> Proto::TypedDataDescribingOutput<'static> {
+        Self::describe_typed_data_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::TypedDataDescribingOutput, e))
+    }
+
+    fn describe_typed_data_impl(
+        input: Proto::TypedDataDescribingInput<'_>,
+    ) -> SigningResult<Proto::TypedDataDescribingOutput<'static>> {
+        let version = Self::typed_data_version(input.typed_data_version);
+        let tree = Eip712Message::new(input.message)?
+            .with_version(version)
+            .typed_data_tree()?;
+        let typed_data_json = serde_json::to_string(&tree)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+
+        Ok(Proto::TypedDataDescribingOutput {
+            typed_data_json: Cow::Owned(typed_data_json),
+            ..Proto::TypedDataDescribingOutput::default()
+        })
+    }
+
     fn message_preimage_hashes_impl(
-        input: Proto::MessageSigningInput<'_>,
+        coin: &dyn CoinContext,
+        mut input: Proto::MessageSigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        Self::apply_chain_id_policy(coin, &mut input)?;
         let msg = Self::message_from_proto(input)?;
         let hash = msg.hash()?.to_vec();
         Ok(CompilerProto::PreSigningOutput {
@@ -72,13 +102,26 @@ impl EthMessageSigner {
     }
 
     fn sign_message_impl(
-        input: Proto::MessageSigningInput<'_>,
+        coin: &dyn CoinContext,
+        mut input: Proto::MessageSigningInput<'_>,
     ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        Self::apply_chain_id_policy(coin, &mut input)?;
         let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
         let signature_type =
             Self::signature_type_from_proto(input.message_type, input.chain_id.clone());
 
-        let msg = Self::message_from_proto(input)?;
+        let msg = match input.message_type {
+            // Sign in with the account stated in the message only.
+            Proto::MessageType::MessageType_siwe => {
+                let siwe = Self::siwe_message_from_proto(&input)?;
+                let signer = Address::with_secp256k1_pubkey(&private_key.public());
+                if siwe.address != signer {
+                    return Err(MessageSigningError::AddressMismatch.into());
+                }
+                siwe.into_boxed()
+            },
+            _ => Self::message_from_proto(input)?,
+        };
 
         let hash_to_sign = msg.hash()?;
         let secp_sign = private_key.sign(hash_to_sign)?;
@@ -93,39 +136,134 @@ impl EthMessageSigner {
     fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
         let public_key = secp256k1::PublicKey::try_from(input.public_key.as_ref())?;
 
-        let msg_hash = Self::message_from_str(&input.message)?.hash()?;
+        let msg_hash = Self::message_from_verifying_input(&input)?.hash()?;
         let secp_signature =
             MessageSignature::from_str(&input.signature)?.to_secp256k1_signature()?;
 
         let actual_public_key = secp256k1::PublicKey::recover(secp_signature.clone(), msg_hash)?;
-        let valid = actual_public_key == public_key
+        let mut valid = actual_public_key == public_key
             && public_key.verify(VerifySignature::from(secp_signature), msg_hash);
 
+        // A SIWE message is expected to be signed by the account stated in the message.
+        if let Ok(siwe) = SiweMessage::from_str(&input.message) {
+            valid &= siwe.address == Address::with_secp256k1_pubkey(&public_key);
+        }
+
         Ok(valid)
     }
 
+    /// If `check_chain_id` is set, checks `chain_id` against the coin, or sets it to the coin chain ID if omitted.
+    /// The chain IDs stated in the message are then checked against `chain_id` as usual.
+    fn apply_chain_id_policy(
+        coin: &dyn CoinContext,
+        input: &mut Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<()> {
+        if !input.check_chain_id {
+            return Ok(());
+        }
+
+        let coin_chain_id = u64::try_from(chain_id::coin_chain_id(coin)?)?;
+        match input.chain_id {
+            Some(ref expected) if expected.chain_id != coin_chain_id => {
+                Err(MessageSigningError::InvalidChainId.into())
+            },
+            _ => {
+                input.chain_id = Some(Proto::MaybeChainId {
+                    chain_id: coin_chain_id,
+                });
+                Ok(())
+            },
+        }
+    }
+
     fn message_from_proto(input: Proto::MessageSigningInput<'_>) -> SigningResult<EthMessageBoxed> {
         match input.message_type {
-            Proto::MessageType::MessageType_legacy
-            | Proto::MessageType::MessageType_eip155
-            | Proto::MessageType::MessageType_immutable_x => {
+            Proto::MessageType::MessageType_legacy | Proto::MessageType::MessageType_eip155 => {
+                let user_message = Self::message_bytes(&input.message, input.encoding)?;
+                Ok(Eip191Message::from_bytes(user_message).into_boxed())
+            },
+            Proto::MessageType::MessageType_immutable_x => {
                 Ok(Eip191Message::new(input.message).into_boxed())
             },
+            Proto::MessageType::MessageType_intended_validator => {
+                let validator = Address::from_str(&input.validator)?;
+                let user_message = Self::message_bytes(&input.message, input.encoding)?;
+                Ok(Eip191Message::with_intended_validator(validator, user_message).into_boxed())
+            },
             Proto::MessageType::MessageType_typed
-            | Proto::MessageType::MessageType_typed_eip155 => match input.chain_id {
-                Some(expected_chain_id) => {
-                    let expected_chain_id = U256::from(expected_chain_id.chain_id);
-                    Ok(Eip712Message::new_checked(input.message, expected_chain_id)?.into_boxed())
-                },
-                None => Ok(Eip712Message::new(input.message)?.into_boxed()),
+            | Proto::MessageType::MessageType_typed_eip155 => {
+                let typed_data = match input.chain_id {
+                    Some(expected_chain_id) => {
+                        let expected_chain_id = U256::from(expected_chain_id.chain_id);
+                        Eip712Message::new_checked(input.message, expected_chain_id)?
+                    },
+                    None => Eip712Message::new(input.message)?,
+                };
+                let version = Self::typed_data_version(input.typed_data_version);
+                Ok(typed_data.with_version(version).into_boxed())
+            },
+            Proto::MessageType::MessageType_siwe => {
+                Ok(Self::siwe_message_from_proto(&input)?.into_boxed())
+            },
+        }
+    }
+
+    fn siwe_message_from_proto(
+        input: &Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<SiweMessage> {
+        let siwe = SiweMessage::from_str(&input.message)?;
+        if let Some(ref expected_chain_id) = input.chain_id {
+            if siwe.chain_id != expected_chain_id.chain_id {
+                return Err(MessageSigningError::InvalidChainId.into());
+            }
+        }
+        Ok(siwe)
+    }
+
+    fn message_from_verifying_input(
+        input: &Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<EthMessageBoxed> {
+        if !input.validator.is_empty() {
+            let validator = Address::from_str(&input.validator)?;
+            let user_message = Self::message_bytes(&input.message, input.encoding)?;
+            return Ok(
+                Eip191Message::with_intended_validator(validator, user_message).into_boxed(),
+            );
+        }
+
+        match Eip712Message::new(&input.message) {
+            Ok(typed_data) => {
+                let version = Self::typed_data_version(input.typed_data_version);
+                Ok(typed_data.with_version(version).into_boxed())
             },
+            Err(_) => {
+                let user_message = Self::message_bytes(&input.message, input.encoding)?;
+                Ok(Eip191Message::from_bytes(user_message).into_boxed())
+            },
+        }
+    }
+
+    fn typed_data_version(version: Proto::TypedDataVersion) -> Eip712Version {
+        match version {
+            Proto::TypedDataVersion::TypedDataVersion_v4 => Eip712Version::V4,
+            Proto::TypedDataVersion::TypedDataVersion_v3 => Eip712Version::V3,
         }
     }
 
-    fn message_from_str(user_message: &str) -> SigningResult<EthMessageBoxed> {
-        match Eip712Message::new(user_message) {
-            Ok(typed_data) => Ok(typed_data.into_boxed()),
-            Err(_) => Ok(Eip191Message::new(user_message).into_boxed()),
+    /// Returns the binary data of an EIP-191 message.
+    fn message_bytes(user_message: &str, encoding: Proto::MessageEncoding) -> SigningResult<Data> {
+        match encoding {
+            // `personal_sign` treats `0x`-prefixed hex strings as binary data.
+            Proto::MessageEncoding::MessageEncoding_auto => match user_message.strip_prefix("0x") {
+                Some(hex) => Ok(hex
+                    .decode_hex()
+                    .unwrap_or_else(|_| user_message.as_bytes().to_vec())),
+                None => Ok(user_message.as_bytes().to_vec()),
+            },
+            Proto::MessageEncoding::MessageEncoding_text => Ok(user_message.as_bytes().to_vec()),
+            Proto::MessageEncoding::MessageEncoding_hex => user_message
+                .decode_hex()
+                .map_err(|_| MessageSigningError::InvalidParameterValue.into()),
         }
     }
 
@@ -135,9 +273,10 @@ impl EthMessageSigner {
     ) -> SignatureType {
         match msg_type {
             Proto::MessageType::MessageType_immutable_x => SignatureType::Standard,
-            Proto::MessageType::MessageType_legacy | Proto::MessageType::MessageType_typed => {
-                SignatureType::Legacy
-            },
+            Proto::MessageType::MessageType_legacy
+            | Proto::MessageType::MessageType_typed
+            | Proto::MessageType::MessageType_siwe
+            | Proto::MessageType::MessageType_intended_validator => SignatureType::Legacy,
             Proto::MessageType::MessageType_eip155
             | Proto::MessageType::MessageType_typed_eip155 => {
                 let chain_id = maybe_chain_id.unwrap_or_default().chain_id;
@@ -5,9 +5,13 @@
 // file LICENSE at the root of the source code distribution tree.
 
 pub mod abi_encoder;
+pub mod authorization_signer;
+pub mod chain_id;
 pub mod compiler;
+pub mod deployment_address;
 pub mod json_signer;
 pub mod message_signer;
 pub mod rlp_encoder;
 pub mod signer;
 pub mod tx_builder;
+pub mod tx_decoder;
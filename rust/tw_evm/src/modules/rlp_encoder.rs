@@ -7,6 +7,7 @@
 use crate::evm_context::EvmContext;
 use crate::rlp::buffer::RlpBuffer;
 use crate::rlp::list::RlpList;
+use crate::rlp::reader::{RlpItem, RlpReader};
 use crate::rlp::RlpEncode;
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -39,6 +40,11 @@ impl<Context: EvmContext> RlpEncoder<Context> {
             .unwrap_or_else(|err| signing_output_error!(Proto::EncodingOutput, err))
     }
 
+    pub fn decode_with_proto(input: Proto::DecodingInput<'_>) -> Proto::DecodingOutput<'static> {
+        Self::decode_with_proto_impl(input)
+            .unwrap_or_else(|err| signing_output_error!(Proto::DecodingOutput, err))
+    }
+
     fn encode_with_proto_impl(
         input: Proto::EncodingInput<'_>,
     ) -> SigningResult<Proto::EncodingOutput<'static>> {
@@ -89,4 +95,45 @@ impl<Context: EvmContext> RlpEncoder<Context> {
         };
         Ok(encoded_item)
     }
+
+    fn decode_with_proto_impl(
+        input: Proto::DecodingInput<'_>,
+    ) -> SigningResult<Proto::DecodingOutput<'static>> {
+        let mut reader = RlpReader::new(input.encoded.as_ref());
+        let rlp_item = reader.next_item()?;
+        reader.finish()?;
+
+        let initial_depth = 0;
+        let item = Self::decode_proto_item(initial_depth, rlp_item)?;
+        Ok(Proto::DecodingOutput {
+            item: Some(item),
+            ..Proto::DecodingOutput::default()
+        })
+    }
+
+    fn decode_proto_item(
+        depth: usize,
+        rlp_item: RlpItem<'_>,
+    ) -> SigningResult<Proto::RlpItem<'static>> {
+        use Proto::mod_RlpItem::OneOfitem as Item;
+
+        if depth >= RECURSION_LIMIT {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        let item = match rlp_item {
+            RlpItem::Data(data) => Item::data(Cow::from(data.to_vec())),
+            RlpItem::List(mut rlp_nested_list) => {
+                let mut items = Vec::new();
+                let new_depth = depth + 1;
+
+                while !rlp_nested_list.is_empty() {
+                    let nested_item = rlp_nested_list.next_item()?;
+                    items.push(Self::decode_proto_item(new_depth, nested_item)?);
+                }
+                Item::list(Proto::RlpList { items })
+            },
+        };
+        Ok(Proto::RlpItem { item })
+    }
 }
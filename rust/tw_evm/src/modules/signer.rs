@@ -4,11 +4,15 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::address::Address;
 use crate::evm_context::EvmContext;
+use crate::modules::chain_id;
 use crate::modules::tx_builder::TxBuilder;
 use std::borrow::Cow;
 use std::marker::PhantomData;
-use tw_coin_entry::error::SigningResult;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::signing_output_error;
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::traits::SigningKeyTrait;
@@ -28,15 +32,28 @@ impl<Context: EvmContext> Signer<Context> {
             .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
     }
 
-    fn sign_proto_impl(
+    /// Same as [`Signer::sign_proto`], but the chain ID is checked against the coin
+    /// if `SigningInput.check_chain_id` is set.
+    #[inline]
+    pub fn sign_proto_for_coin(
+        coin: &dyn CoinContext,
         input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        chain_id::check_signing_input(coin, &input)
+            .and_then(|_| Self::sign_proto_impl(input))
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_proto_impl(
+        mut input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let chain_id = U256::from_big_endian_slice(&input.chain_id)?;
         let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        Self::prepare_zksync_sender(&mut input, &private_key)?;
 
         let unsigned = TxBuilder::<Context>::tx_from_proto(&input)?;
 
-        let pre_hash = unsigned.pre_hash(chain_id);
+        let pre_hash = unsigned.pre_hash(chain_id)?;
         let signature = private_key.sign(pre_hash)?;
 
         let signed = unsigned.try_into_signed(signature, chain_id)?;
@@ -54,7 +71,38 @@ impl<Context: EvmContext> Signer<Context> {
             s: Cow::from(eth_signature.s().to_big_endian().to_vec()),
             data: Cow::from(signed.payload()),
             pre_hash: Cow::from(pre_hash.to_vec()),
+            network_encoded: Cow::from(signed.encode_network().unwrap_or_default()),
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// The sender of a zkSync transaction is a part of the signed data.
+    /// Derives it from the private key if omitted, or checks that it matches the key otherwise.
+    /// Smart contract accounts are signed with `custom_signature`, so the sender is not checked.
+    fn prepare_zksync_sender(
+        input: &mut Proto::SigningInput<'_>,
+        private_key: &secp256k1::PrivateKey,
+    ) -> SigningResult<()> {
+        if input.tx_mode != Proto::TransactionMode::ZkSync {
+            return Ok(());
+        }
+        let Some(ref mut params) = input.zksync_params else {
+            return Ok(());
+        };
+        if !params.custom_signature.is_empty() {
+            return Ok(());
+        }
+
+        let signer = Address::with_secp256k1_pubkey(&private_key.public());
+        if params.from.is_empty() {
+            params.from = Cow::from(signer.to_string());
+            return Ok(());
+        }
+
+        let from: Address = Context::Address::from_str(&params.from)?.into();
+        if from != signer {
+            return Err(SigningError(SigningErrorType::Error_invalid_address));
+        }
+        Ok(())
+    }
 }
@@ -4,19 +4,38 @@
 // terms governing use, modification, and redistribution, is contained in the
 // file LICENSE at the root of the source code distribution tree.
 
+use crate::abi::prebuild::barz::BarzAccount;
+use crate::abi::prebuild::biconomy::BiconomySmartAccount;
 use crate::abi::prebuild::erc1155::Erc1155;
 use crate::abi::prebuild::erc20::Erc20;
-use crate::abi::prebuild::erc4337::{Erc4337SimpleAccount, ExecuteArgs};
+use crate::abi::prebuild::erc4337::{Erc4337SimpleAccount, ExecuteArgs, Operation};
 use crate::abi::prebuild::erc721::Erc721;
+use crate::abi::prebuild::erc7579::Erc7579Account;
+use crate::abi::prebuild::kernel::Kernel;
+use crate::abi::prebuild::multicall3::{Call3, Call3Value, Multicall3, MULTICALL3_ADDRESS};
+use crate::abi::prebuild::safe::Safe4337Module;
 use crate::address::{Address, EvmAddress};
 use crate::evm_context::EvmContext;
+use crate::transaction::access_list::{AccessList, AccessListItem};
+use crate::transaction::authorization::{Authorization, AuthorizationList, SignedAuthorization};
+use crate::transaction::blob::BlobSidecar;
+use crate::transaction::intrinsic_gas::check_gas_limit;
+use crate::transaction::signature::secp256k1_signature_from_parts;
 use crate::transaction::transaction_eip1559::TransactionEip1559;
+use crate::transaction::transaction_eip2930::TransactionEip2930;
+use crate::transaction::transaction_eip4844::TransactionEip4844;
+use crate::transaction::transaction_eip7702::TransactionEip7702;
 use crate::transaction::transaction_non_typed::TransactionNonTyped;
+use crate::transaction::transaction_zksync::{
+    FactoryDep, PaymasterParams, TransactionZkSync, DEFAULT_GAS_PER_PUBDATA,
+};
 use crate::transaction::user_operation::UserOperation;
+use crate::transaction::user_operation_v0_7::UserOperationV0_7;
 use crate::transaction::UnsignedTransactionBox;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_coin_entry::error::{AddressResult, SigningError, SigningErrorType, SigningResult};
+use tw_hash::H256;
 use tw_memory::Data;
 use tw_number::U256;
 use tw_proto::Common::Proto::SigningError as CommonError;
@@ -29,6 +48,16 @@ pub struct TxBuilder<Context: EvmContext> {
 impl<Context: EvmContext> TxBuilder<Context> {
     pub fn tx_from_proto(
         input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<Box<dyn UnsignedTransactionBox>> {
+        let tx = Self::unchecked_tx_from_proto(input)?;
+        if input.check_intrinsic_gas {
+            check_gas_limit(tx.as_ref())?;
+        }
+        Ok(tx)
+    }
+
+    fn unchecked_tx_from_proto(
+        input: &Proto::SigningInput<'_>,
     ) -> SigningResult<Box<dyn UnsignedTransactionBox>> {
         use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
         use Proto::TransactionMode as TxMode;
@@ -79,6 +108,56 @@ impl<Context: EvmContext> TxBuilder<Context> {
                 let payload = Erc1155::encode_safe_transfer_from(from, to, token_id, value, data)?;
                 (U256::zero(), payload, Some(contract_address))
             },
+            Tx::erc721_safe_transfer(ref erc721_transfer) => {
+                let from = Self::parse_address(&erc721_transfer.from)?;
+                let token_to_address = Self::parse_address(&erc721_transfer.to)?;
+                let token_id = U256::from_big_endian_slice(&erc721_transfer.token_id)?;
+                let data = erc721_transfer.data.to_vec();
+                let contract_address = Self::parse_address(&input.to_address)?;
+
+                let payload =
+                    Erc721::encode_safe_transfer_from(from, token_to_address, token_id, data)?;
+                (U256::zero(), payload, Some(contract_address))
+            },
+            Tx::erc721_approve(ref erc721_approve) => {
+                let spender = Self::parse_address(&erc721_approve.spender)?;
+                let token_id = U256::from_big_endian_slice(&erc721_approve.token_id)?;
+                let contract_address = Self::parse_address(&input.to_address)?;
+
+                let payload = Erc721::encode_approve(spender, token_id)?;
+                (U256::zero(), payload, Some(contract_address))
+            },
+            Tx::erc721_set_approval_for_all(ref approval) => {
+                let operator = Self::parse_address(&approval.operator)?;
+                let contract_address = Self::parse_address(&input.to_address)?;
+
+                let payload = Erc721::encode_set_approval_for_all(operator, approval.approved)?;
+                (U256::zero(), payload, Some(contract_address))
+            },
+            Tx::erc1155_batch_transfer(ref erc1155_transfer) => {
+                // Each token ID must have the corresponding amount.
+                if erc1155_transfer.token_ids.len() != erc1155_transfer.values.len() {
+                    return Err(SigningError(SigningErrorType::Error_invalid_params));
+                }
+
+                let from = Self::parse_address(&erc1155_transfer.from)?;
+                let to = Self::parse_address(&erc1155_transfer.to)?;
+                let token_ids = Self::parse_u256_list(&erc1155_transfer.token_ids)?;
+                let values = Self::parse_u256_list(&erc1155_transfer.values)?;
+                let data = erc1155_transfer.data.to_vec();
+                let contract_address = Self::parse_address(&input.to_address)?;
+
+                let payload =
+                    Erc1155::encode_safe_batch_transfer_from(from, to, token_ids, values, data)?;
+                (U256::zero(), payload, Some(contract_address))
+            },
+            Tx::erc1155_set_approval_for_all(ref approval) => {
+                let operator = Self::parse_address(&approval.operator)?;
+                let contract_address = Self::parse_address(&input.to_address)?;
+
+                let payload = Erc1155::encode_set_approval_for_all(operator, approval.approved)?;
+                (U256::zero(), payload, Some(contract_address))
+            },
             Tx::contract_generic(ref contract_generic) => {
                 let amount = U256::from_big_endian_slice(&contract_generic.amount)?;
                 let payload = contract_generic.data.to_vec();
@@ -89,20 +168,32 @@ impl<Context: EvmContext> TxBuilder<Context> {
                 (amount, payload, to_address)
             },
             Tx::batch(ref batch) => {
-                if input.tx_mode != TxMode::UserOp {
-                    return Err(SigningError(SigningErrorType::Error_invalid_params));
-                }
-
-                // Payload should match ERC4337 standard.
                 let calls: Vec<_> = batch
                     .calls
                     .iter()
                     .map(Self::erc4337_execute_call_from_proto)
                     .collect::<Result<Vec<_>, _>>()?;
-                let payload = Erc4337SimpleAccount::encode_execute_batch(calls)?;
 
-                return Self::user_operation_from_proto(input, payload)
-                    .map(UserOperation::into_boxed);
+                match input.tx_mode {
+                    TxMode::UserOp => {
+                        // Payload should match ERC4337 standard.
+                        let payload = Self::erc4337_execute_batch_payload(input, calls)?;
+                        return Self::user_operation_from_proto(input, payload);
+                    },
+                    // Regular accounts batch value transfers via Multicall3.
+                    // Multicall3 is the `msg.sender` of the calls, so a call with a payload
+                    // (e.g ERC-20 `approve`) would act on behalf of Multicall3 instead of the sender.
+                    TxMode::Legacy | TxMode::Enveloped => {
+                        if calls.iter().any(|call| !call.data.is_empty()) {
+                            return Err(SigningError(SigningErrorType::Error_invalid_params));
+                        }
+                        let multicall3 = Self::parse_address_optional(&batch.multicall3_address)?
+                            .unwrap_or_else(|| Address::from(MULTICALL3_ADDRESS));
+                        let (amount, payload) = Self::multicall3_payload(calls)?;
+                        (amount, payload, Some(multicall3))
+                    },
+                    _ => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+                }
             },
             Tx::None => return Err(SigningError(SigningErrorType::Error_invalid_params)),
         };
@@ -114,21 +205,128 @@ impl<Context: EvmContext> TxBuilder<Context> {
             TxMode::Enveloped => {
                 Self::transaction_eip1559_from_proto(input, eth_amount, payload, to)?.into_boxed()
             },
+            TxMode::AccessList => {
+                Self::transaction_eip2930_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
+            TxMode::Blob => {
+                let to = to.ok_or(SigningError(SigningErrorType::Error_invalid_address))?;
+                Self::transaction_eip4844_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
+            TxMode::SetCode => {
+                let to = to.ok_or(SigningError(SigningErrorType::Error_invalid_address))?;
+                Self::transaction_eip7702_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
+            TxMode::ZkSync => {
+                let to = to.ok_or(SigningError(SigningErrorType::Error_invalid_address))?;
+                Self::transaction_zksync_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
             TxMode::UserOp => {
                 let to = to.ok_or(SigningError(SigningErrorType::Error_invalid_address))?;
                 // Payload should match the ERC4337 standard.
-                let payload = Erc4337SimpleAccount::encode_execute(ExecuteArgs {
-                    to,
-                    value: eth_amount,
-                    data: payload,
-                })?;
+                let payload = Self::erc4337_execute_payload(
+                    input,
+                    ExecuteArgs {
+                        to,
+                        value: eth_amount,
+                        data: payload,
+                    },
+                )?;
 
-                Self::user_operation_from_proto(input, payload)?.into_boxed()
+                Self::user_operation_from_proto(input, payload)?
             },
         };
         Ok(tx)
     }
 
+    /// Encodes a single call according to the smart contract account type.
+    fn erc4337_execute_payload(
+        input: &Proto::SigningInput,
+        args: ExecuteArgs,
+    ) -> SigningResult<Data> {
+        use Proto::SCAccountType;
+
+        let (account_type, delegate_call) = input
+            .user_operation
+            .as_ref()
+            .map(|user_op| (user_op.account_type, user_op.delegate_call))
+            .unwrap_or_default();
+
+        let payload = match (account_type, delegate_call) {
+            (SCAccountType::SimpleAccount, false) => Erc4337SimpleAccount::encode_execute(args)?,
+            (SCAccountType::Safe4337, false) => {
+                Safe4337Module::encode_execute_user_op(args, Operation::Call)?
+            },
+            (SCAccountType::Safe4337, true) => {
+                Safe4337Module::encode_execute_user_op(args, Operation::DelegateCall)?
+            },
+            (SCAccountType::Kernel, false) => Kernel::encode_execute(args, Operation::Call)?,
+            (SCAccountType::Kernel, true) => Kernel::encode_execute(args, Operation::DelegateCall)?,
+            (SCAccountType::Biconomy, false) => BiconomySmartAccount::encode_execute(args)?,
+            (SCAccountType::Barz, false) => BarzAccount::encode_execute(args)?,
+            (SCAccountType::Erc7579, false) => Erc7579Account::encode_execute(args)?,
+            (SCAccountType::Erc7579, true) => Erc7579Account::encode_execute_delegate_call(args)?,
+            // The account doesn't support `delegatecall`.
+            (
+                SCAccountType::SimpleAccount | SCAccountType::Biconomy | SCAccountType::Barz,
+                true,
+            ) => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+        };
+        Ok(payload)
+    }
+
+    /// Encodes a batch of calls according to the smart contract account type.
+    fn erc4337_execute_batch_payload(
+        input: &Proto::SigningInput,
+        calls: Vec<ExecuteArgs>,
+    ) -> SigningResult<Data> {
+        use Proto::SCAccountType;
+
+        let (account_type, delegate_call) = input
+            .user_operation
+            .as_ref()
+            .map(|user_op| (user_op.account_type, user_op.delegate_call))
+            .unwrap_or_default();
+        // Batched calls cannot be delegated.
+        if delegate_call {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        let payload = match account_type {
+            SCAccountType::SimpleAccount => Erc4337SimpleAccount::encode_execute_batch(calls)?,
+            SCAccountType::Safe4337 => Safe4337Module::encode_execute_user_op_batch(calls)?,
+            SCAccountType::Kernel => Kernel::encode_execute_batch(calls)?,
+            SCAccountType::Biconomy => BiconomySmartAccount::encode_execute_batch(calls)?,
+            SCAccountType::Barz => BarzAccount::encode_execute_batch(calls)?,
+            SCAccountType::Erc7579 => Erc7579Account::encode_execute_batch(calls)?,
+        };
+        Ok(payload)
+    }
+
+    /// Encodes the calls as `aggregate3`, or `aggregate3Value` if any of the calls transfers value.
+    /// Returns the total value to be sent to Multicall3 along with the payload.
+    /// The batch is atomic: it reverts if any of the calls fails.
+    fn multicall3_payload(calls: Vec<ExecuteArgs>) -> SigningResult<(U256, Data)> {
+        if calls.iter().all(|call| call.value.is_zero()) {
+            let calls = calls.into_iter().map(|call| Call3 {
+                target: call.to,
+                allow_failure: false,
+                call_data: call.data,
+            });
+            return Ok((U256::zero(), Multicall3::encode_aggregate3(calls)?));
+        }
+
+        let total_value = calls
+            .iter()
+            .try_fold(U256::zero(), |total, call| total.checked_add(call.value))?;
+        let calls = calls.into_iter().map(|call| Call3Value {
+            target: call.to,
+            allow_failure: false,
+            value: call.value,
+            call_data: call.data,
+        });
+        Ok((total_value, Multicall3::encode_aggregate3_value(calls)?))
+    }
+
     #[inline]
     fn erc4337_execute_call_from_proto(
         call: &Proto::mod_Transaction::mod_Batch::BatchedCall,
@@ -175,6 +373,7 @@ impl<Context: EvmContext> TxBuilder<Context> {
         let max_inclusion_fee_per_gas =
             U256::from_big_endian_slice(&input.max_inclusion_fee_per_gas)?;
         let max_fee_per_gas = U256::from_big_endian_slice(&input.max_fee_per_gas)?;
+        let access_list = Self::access_list_from_proto(&input.access_list)?;
 
         Ok(TransactionEip1559 {
             nonce,
@@ -184,17 +383,267 @@ impl<Context: EvmContext> TxBuilder<Context> {
             to: to_address,
             amount: eth_amount,
             payload,
+            access_list,
+        })
+    }
+
+    #[inline]
+    fn transaction_eip2930_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Option<Address>,
+    ) -> SigningResult<TransactionEip2930> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)?;
+        let gas_price = U256::from_big_endian_slice(&input.gas_price)?;
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)?;
+        let access_list = Self::access_list_from_proto(&input.access_list)?;
+
+        Ok(TransactionEip2930 {
+            nonce,
+            gas_price,
+            gas_limit,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+            access_list,
+        })
+    }
+
+    #[inline]
+    fn transaction_eip4844_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Address,
+    ) -> SigningResult<TransactionEip4844> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)?;
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)?;
+        let max_inclusion_fee_per_gas =
+            U256::from_big_endian_slice(&input.max_inclusion_fee_per_gas)?;
+        let max_fee_per_gas = U256::from_big_endian_slice(&input.max_fee_per_gas)?;
+        let max_fee_per_blob_gas = U256::from_big_endian_slice(&input.max_fee_per_blob_gas)?;
+        let access_list = Self::access_list_from_proto(&input.access_list)?;
+
+        let mut blob_versioned_hashes = input
+            .blob_versioned_hashes
+            .iter()
+            .map(|hash| {
+                H256::try_from(hash.as_ref())
+                    .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let sidecar = if input.blobs.is_empty() {
+            None
+        } else {
+            let blobs = input.blobs.iter().map(|blob| blob.to_vec()).collect();
+            let sidecar = BlobSidecar::from_blobs(blobs)?;
+
+            // Versioned hashes can be omitted, but if they are set, they must match the blobs.
+            let computed_hashes = sidecar.versioned_hashes();
+            if blob_versioned_hashes.is_empty() {
+                blob_versioned_hashes = computed_hashes;
+            } else if blob_versioned_hashes != computed_hashes {
+                return Err(SigningError(SigningErrorType::Error_invalid_params));
+            }
+            Some(sidecar)
+        };
+
+        // A blob transaction must carry at least one blob.
+        if blob_versioned_hashes.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        Ok(TransactionEip4844 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+            access_list,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+            sidecar,
+        })
+    }
+
+    #[inline]
+    fn transaction_eip7702_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Address,
+    ) -> SigningResult<TransactionEip7702> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)?;
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)?;
+        let max_inclusion_fee_per_gas =
+            U256::from_big_endian_slice(&input.max_inclusion_fee_per_gas)?;
+        let max_fee_per_gas = U256::from_big_endian_slice(&input.max_fee_per_gas)?;
+        let access_list = Self::access_list_from_proto(&input.access_list)?;
+
+        let authorization_list = input
+            .authorization_list
+            .iter()
+            .map(Self::signed_authorization_from_proto)
+            .collect::<SigningResult<Vec<_>>>()
+            .map(AuthorizationList)?;
+        // A set-code transaction must carry at least one authorization.
+        if authorization_list.0.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        Ok(TransactionEip7702 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+            access_list,
+            authorization_list,
+        })
+    }
+
+    #[inline]
+    fn transaction_zksync_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Address,
+    ) -> SigningResult<TransactionZkSync> {
+        let Some(ref params) = input.zksync_params else {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        };
+
+        let nonce = U256::from_big_endian_slice(&input.nonce)?;
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)?;
+        let max_inclusion_fee_per_gas =
+            U256::from_big_endian_slice(&input.max_inclusion_fee_per_gas)?;
+        let max_fee_per_gas = U256::from_big_endian_slice(&input.max_fee_per_gas)?;
+
+        let from = Self::parse_address(&params.from)?;
+        let gas_per_pubdata = if params.gas_per_pubdata.is_empty() {
+            U256::from(DEFAULT_GAS_PER_PUBDATA)
+        } else {
+            U256::from_big_endian_slice(&params.gas_per_pubdata)?
+        };
+        let factory_deps = params
+            .factory_deps
+            .iter()
+            .map(|bytecode| FactoryDep::new(bytecode.to_vec()))
+            .collect::<SigningResult<Vec<_>>>()?;
+        let custom_signature =
+            Some(params.custom_signature.to_vec()).filter(|signature| !signature.is_empty());
+        let paymaster_params =
+            Self::parse_address_optional(&params.paymaster)?.map(|paymaster| PaymasterParams {
+                paymaster,
+                paymaster_input: params.paymaster_input.to_vec(),
+            });
+
+        Ok(TransactionZkSync {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            from,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+            gas_per_pubdata,
+            factory_deps,
+            custom_signature,
+            paymaster_params,
+        })
+    }
+
+    pub fn authorization_from_proto(
+        authorization: &Proto::Authorization,
+    ) -> SigningResult<Authorization> {
+        let chain_id = U256::from_big_endian_slice(&authorization.chain_id)?;
+        let address = Self::parse_address(&authorization.address)?;
+        let nonce = U256::from_big_endian_slice(&authorization.nonce)?;
+
+        Ok(Authorization {
+            chain_id,
+            address,
+            nonce,
+        })
+    }
+
+    fn signed_authorization_from_proto(
+        signed: &Proto::SignedAuthorization,
+    ) -> SigningResult<SignedAuthorization> {
+        let Some(ref authorization) = signed.authorization else {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        };
+        let authorization = Self::authorization_from_proto(authorization)?;
+
+        let y_parity = match signed.y_parity {
+            0 => 0,
+            1 => 1,
+            _ => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+        };
+        let r = U256::from_big_endian_slice(&signed.r)?;
+        let s = U256::from_big_endian_slice(&signed.s)?;
+        let signature = secp256k1_signature_from_parts(r, s, y_parity)?;
+
+        Ok(SignedAuthorization::new(authorization, signature))
+    }
+
+    fn access_list_from_proto(access_list: &[Proto::Access]) -> SigningResult<AccessList> {
+        access_list
+            .iter()
+            .map(Self::access_list_item_from_proto)
+            .collect::<SigningResult<Vec<_>>>()
+            .map(AccessList)
+    }
+
+    fn access_list_item_from_proto(access: &Proto::Access) -> SigningResult<AccessListItem> {
+        let address = Self::parse_address(&access.address)?;
+        let storage_keys = access
+            .stored_keys
+            .iter()
+            .map(|key| {
+                H256::try_from(key.as_ref())
+                    .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(AccessListItem {
+            address,
+            storage_keys,
         })
     }
 
     fn user_operation_from_proto(
         input: &Proto::SigningInput,
         erc4337_payload: Data,
-    ) -> SigningResult<UserOperation> {
+    ) -> SigningResult<Box<dyn UnsignedTransactionBox>> {
         let Some(ref user_op) = input.user_operation else {
             return Err(SigningError(CommonError::Error_invalid_params))
         };
 
+        match user_op.version {
+            Proto::UserOperationVersion::V0_6 => {
+                Self::user_operation_v0_6_from_proto(input, user_op, erc4337_payload)
+                    .map(UserOperation::into_boxed)
+            },
+            Proto::UserOperationVersion::V0_7 => {
+                Self::user_operation_v0_7_from_proto(input, user_op, erc4337_payload)
+                    .map(UserOperationV0_7::into_boxed)
+            },
+        }
+    }
+
+    fn user_operation_v0_6_from_proto(
+        input: &Proto::SigningInput,
+        user_op: &Proto::UserOperation,
+        erc4337_payload: Data,
+    ) -> SigningResult<UserOperation> {
         let nonce = U256::from_big_endian_slice(&input.nonce)?;
         let gas_limit = U256::from_big_endian_slice(&input.gas_limit)?;
         let max_inclusion_fee_per_gas =
@@ -221,6 +670,61 @@ impl<Context: EvmContext> TxBuilder<Context> {
         })
     }
 
+    fn user_operation_v0_7_from_proto(
+        input: &Proto::SigningInput,
+        user_op: &Proto::UserOperation,
+        erc4337_payload: Data,
+    ) -> SigningResult<UserOperationV0_7> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)?;
+        let call_gas_limit = Self::parse_u128(&input.gas_limit)?;
+        let max_inclusion_fee_per_gas = Self::parse_u128(&input.max_inclusion_fee_per_gas)?;
+        let max_fee_per_gas = Self::parse_u128(&input.max_fee_per_gas)?;
+
+        let entry_point = Self::parse_address(user_op.entry_point.as_ref())?;
+        let sender = Self::parse_address(user_op.sender.as_ref())?;
+        let factory = Self::parse_address_optional(user_op.factory.as_ref())?;
+        let verification_gas_limit = Self::parse_u128(&user_op.verification_gas_limit)?;
+        let pre_verification_gas = U256::from_big_endian_slice(&user_op.pre_verification_gas)?;
+
+        let paymaster = Self::parse_address_optional(user_op.paymaster.as_ref())?;
+        let paymaster_verification_gas_limit =
+            Self::parse_u128(&user_op.paymaster_verification_gas_limit)?;
+        let paymaster_post_op_gas_limit = Self::parse_u128(&user_op.paymaster_post_op_gas_limit)?;
+
+        Ok(UserOperationV0_7 {
+            nonce,
+            entry_point,
+            sender,
+            factory,
+            factory_data: user_op.factory_data.to_vec(),
+            call_gas_limit,
+            verification_gas_limit,
+            pre_verification_gas,
+            max_fee_per_gas,
+            max_inclusion_fee_per_gas,
+            paymaster,
+            paymaster_verification_gas_limit,
+            paymaster_post_op_gas_limit,
+            paymaster_data: user_op.paymaster_data.to_vec(),
+            payload: erc4337_payload,
+        })
+    }
+
+    /// Parses a big-endian `uint128` value, which is packed by pairs in EntryPoint v0.7.
+    #[inline]
+    fn parse_u128(value: &[u8]) -> SigningResult<u128> {
+        let value = U256::from_big_endian_slice(value)?;
+        Ok(u128::try_from(value)?)
+    }
+
+    /// Parses a list of big-endian `uint256` values, e.g ERC-1155 token IDs.
+    fn parse_u256_list<T: AsRef<[u8]>>(values: &[T]) -> SigningResult<Vec<U256>> {
+        values
+            .iter()
+            .map(|value| U256::from_big_endian_slice(value.as_ref()).map_err(SigningError::from))
+            .collect()
+    }
+
     #[inline]
     fn parse_address(addr: &str) -> AddressResult<Address> {
         Context::Address::from_str(addr).map(Context::Address::into)
@@ -0,0 +1,88 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::evm_context::EvmContext;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::decoder::DecodedTransaction;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Ethereum::Proto;
+
+/// Please note `v` can be zero, but it must not be empty.
+const SIGNATURE_V_MIN_LEN: usize = 1;
+
+pub struct TxDecoder<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> TxDecoder<Context> {
+    pub fn decode_transaction_proto(
+        input: Proto::TransactionDecodingInput<'_>,
+    ) -> Proto::TransactionDecodingOutput<'static> {
+        Self::decode_transaction_proto_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::TransactionDecodingOutput, e))
+    }
+
+    fn decode_transaction_proto_impl(
+        input: Proto::TransactionDecodingInput<'_>,
+    ) -> SigningResult<Proto::TransactionDecodingOutput<'static>> {
+        let tx = DecodedTransaction::decode(&input.encoded)?;
+
+        let chain_id = tx
+            .chain_id
+            .map(|chain_id| chain_id.to_big_endian_compact())
+            .unwrap_or_default();
+        let to_address = tx.to.map(|to| to.to_string()).unwrap_or_default();
+
+        let mut output = Proto::TransactionDecodingOutput {
+            tx_type: tx.tx_type.type_number() as u32,
+            chain_id: Cow::from(chain_id),
+            nonce: Cow::from(tx.nonce.to_big_endian_compact()),
+            gas_price: Cow::from(tx.gas_price.to_big_endian_compact()),
+            gas_limit: Cow::from(tx.gas_limit.to_big_endian_compact()),
+            max_inclusion_fee_per_gas: Cow::from(
+                tx.max_inclusion_fee_per_gas.to_big_endian_compact(),
+            ),
+            max_fee_per_gas: Cow::from(tx.max_fee_per_gas.to_big_endian_compact()),
+            to_address: Cow::from(to_address),
+            amount: Cow::from(tx.amount.to_big_endian_compact()),
+            data: Cow::from(tx.payload),
+            access_list: Self::access_list_to_proto(tx.access_list),
+            pre_hash: Cow::from(tx.pre_hash.to_vec()),
+            ..Proto::TransactionDecodingOutput::default()
+        };
+
+        if let Some(signature) = tx.signature {
+            let v = signature
+                .v
+                .to_big_endian_compact_min_len(SIGNATURE_V_MIN_LEN);
+
+            output.v = Cow::from(v);
+            output.r = Cow::from(signature.r.to_big_endian().to_vec());
+            output.s = Cow::from(signature.s.to_big_endian().to_vec());
+            output.from_address = Cow::from(signature.sender.to_string());
+        }
+
+        Ok(output)
+    }
+
+    fn access_list_to_proto(access_list: AccessList) -> Vec<Proto::Access<'static>> {
+        access_list
+            .0
+            .into_iter()
+            .map(|item| Proto::Access {
+                address: Cow::from(item.address.to_string()),
+                stored_keys: item
+                    .storage_keys
+                    .into_iter()
+                    .map(|key| Cow::from(key.to_vec()))
+                    .collect(),
+            })
+            .collect()
+    }
+}
@@ -6,7 +6,10 @@
 
 use crate::address::Address;
 use crate::rlp::buffer::RlpBuffer;
-use crate::rlp::RlpEncode;
+use crate::rlp::reader::RlpItem;
+use crate::rlp::{RlpDecode, RlpEncode, RlpError, RlpResult};
+use tw_hash::{Hash, H160};
+use tw_memory::Data;
 use tw_number::U256;
 
 impl RlpEncode for U256 {
@@ -30,6 +33,12 @@ impl RlpEncode for Option<Address> {
     }
 }
 
+impl<const N: usize> RlpEncode for Hash<N> {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.append_data(self.as_slice())
+    }
+}
+
 impl<'a> RlpEncode for &'a [u8] {
     fn rlp_append(&self, buf: &mut RlpBuffer) {
         buf.append_data(self)
@@ -41,3 +50,41 @@ impl<'a> RlpEncode for &'a str {
         buf.append_data(self.as_bytes())
     }
 }
+
+impl RlpDecode for U256 {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        let data = item.data()?;
+        if data.first() == Some(&0) {
+            return Err(RlpError::NonCanonicalNumber);
+        }
+        U256::from_big_endian_slice(data).map_err(|_| RlpError::Overflow)
+    }
+}
+
+impl RlpDecode for Address {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        let bytes = H160::try_from(item.data()?).map_err(|_| RlpError::UnexpectedLength)?;
+        Ok(Address::from_bytes(bytes))
+    }
+}
+
+impl RlpDecode for Option<Address> {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        match item.data()? {
+            [] => Ok(None),
+            _ => Address::rlp_decode(item).map(Some),
+        }
+    }
+}
+
+impl<const N: usize> RlpDecode for Hash<N> {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        Hash::try_from(item.data()?).map_err(|_| RlpError::UnexpectedLength)
+    }
+}
+
+impl RlpDecode for Data {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        item.data().map(<[u8]>::to_vec)
+    }
+}
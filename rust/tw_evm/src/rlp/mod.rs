@@ -5,12 +5,50 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::reader::RlpItem;
+use tw_coin_entry::error::{SigningError, SigningErrorType};
 
 pub mod buffer;
 pub mod impls;
 pub mod list;
+pub mod reader;
+
+pub type RlpResult<T> = Result<T, RlpError>;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RlpError {
+    /// The input ends before the item declared by its header.
+    InputTooShort,
+    /// There are unexpected bytes after the last item.
+    TrailingBytes,
+    /// A single byte less than `0x80` is encoded with a string header.
+    NonCanonicalSingleByte,
+    /// A length is not encoded in its shortest form.
+    NonCanonicalLength,
+    /// A number has leading zero bytes.
+    NonCanonicalNumber,
+    /// A number or a length doesn't fit into the target type.
+    Overflow,
+    /// Expected a string, found a list.
+    UnexpectedList,
+    /// Expected a list, found a string.
+    UnexpectedString,
+    /// A string or a list has an unexpected length.
+    UnexpectedLength,
+}
+
+impl From<RlpError> for SigningError {
+    fn from(_err: RlpError) -> Self {
+        SigningError(SigningErrorType::Error_input_parse)
+    }
+}
 
 /// The trait should be implemented for all types that need to be encoded in RLP.
 pub trait RlpEncode {
     fn rlp_append(&self, buf: &mut RlpBuffer);
 }
+
+/// The trait should be implemented for all types that need to be decoded from RLP.
+pub trait RlpDecode: Sized {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self>;
+}
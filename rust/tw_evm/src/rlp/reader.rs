@@ -0,0 +1,220 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::rlp::{RlpDecode, RlpError, RlpResult};
+use std::mem::size_of;
+
+/// The longest string or list payload that can be encoded with a single-byte header.
+const MAX_SHORT_LEN: usize = 55;
+
+/// An RLP item that borrows the decoded data.
+#[derive(Clone, Copy, Debug)]
+pub enum RlpItem<'a> {
+    /// A byte string.
+    Data(&'a [u8]),
+    /// A list of items.
+    List(RlpReader<'a>),
+}
+
+impl<'a> RlpItem<'a> {
+    /// Returns the string payload or an error if the item is a list.
+    pub fn data(self) -> RlpResult<&'a [u8]> {
+        match self {
+            RlpItem::Data(data) => Ok(data),
+            RlpItem::List(_) => Err(RlpError::UnexpectedList),
+        }
+    }
+
+    /// Returns a reader over the list items or an error if the item is a string.
+    pub fn list(self) -> RlpResult<RlpReader<'a>> {
+        match self {
+            RlpItem::Data(_) => Err(RlpError::UnexpectedString),
+            RlpItem::List(reader) => Ok(reader),
+        }
+    }
+}
+
+/// Reads a sequence of RLP items.
+/// Rejects any input that is not encoded in the canonical (shortest) form.
+#[derive(Clone, Copy, Debug)]
+pub struct RlpReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> RlpReader<'a> {
+    /// Creates a reader over a sequence of encoded items.
+    pub fn new(data: &'a [u8]) -> RlpReader<'a> {
+        RlpReader { data }
+    }
+
+    /// Decodes a value from `data` that must contain exactly one RLP item.
+    pub fn decode_exact<T: RlpDecode>(data: &'a [u8]) -> RlpResult<T> {
+        let mut reader = RlpReader::new(data);
+        let value = reader.decode_next()?;
+        reader.finish()?;
+        Ok(value)
+    }
+
+    /// Whether there are no items left.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the number of remaining items.
+    pub fn count_items(&self) -> RlpResult<usize> {
+        let mut reader = *self;
+        let mut count = 0;
+        while !reader.is_empty() {
+            reader.next_item()?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Decodes the next item as a value of type `T`.
+    pub fn decode_next<T: RlpDecode>(&mut self) -> RlpResult<T> {
+        T::rlp_decode(self.next_item()?)
+    }
+
+    /// Reads the next item.
+    pub fn next_item(&mut self) -> RlpResult<RlpItem<'a>> {
+        let (&prefix, rest) = self.data.split_first().ok_or(RlpError::InputTooShort)?;
+
+        let (is_list, payload_len, rest) = match prefix {
+            // A single byte in the `[0x00, 0x7f]` range is its own RLP encoding.
+            0x00..=0x7f => {
+                let (item, rest) = self.data.split_at(1);
+                self.data = rest;
+                return Ok(RlpItem::Data(item));
+            },
+            0x80..=0xb7 => (false, (prefix - 0x80) as usize, rest),
+            0xb8..=0xbf => {
+                let (payload_len, rest) = read_long_length(rest, prefix - 0xb7)?;
+                (false, payload_len, rest)
+            },
+            0xc0..=0xf7 => (true, (prefix - 0xc0) as usize, rest),
+            0xf8..=0xff => {
+                let (payload_len, rest) = read_long_length(rest, prefix - 0xf7)?;
+                (true, payload_len, rest)
+            },
+        };
+
+        if rest.len() < payload_len {
+            return Err(RlpError::InputTooShort);
+        }
+        let (payload, rest) = rest.split_at(payload_len);
+
+        let item = if is_list {
+            RlpItem::List(RlpReader::new(payload))
+        } else {
+            // A single byte less than `0x80` must be encoded without a header.
+            if let [byte] = payload {
+                if *byte < 0x80 {
+                    return Err(RlpError::NonCanonicalSingleByte);
+                }
+            }
+            RlpItem::Data(payload)
+        };
+
+        self.data = rest;
+        Ok(item)
+    }
+
+    /// Checks if all items have been read.
+    pub fn finish(&self) -> RlpResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(RlpError::TrailingBytes)
+        }
+    }
+}
+
+/// Reads a big-endian length of `len_of_len` bytes that follows a long string or a long list prefix.
+fn read_long_length(data: &[u8], len_of_len: u8) -> RlpResult<(usize, &[u8])> {
+    let len_of_len = len_of_len as usize;
+    if data.len() < len_of_len {
+        return Err(RlpError::InputTooShort);
+    }
+    let (len_bytes, rest) = data.split_at(len_of_len);
+
+    if len_bytes[0] == 0 {
+        return Err(RlpError::NonCanonicalLength);
+    }
+    if len_of_len > size_of::<usize>() {
+        return Err(RlpError::Overflow);
+    }
+
+    let len = len_bytes
+        .iter()
+        .fold(0_usize, |acc, byte| (acc << 8) | *byte as usize);
+    // Short payloads must be encoded with a single-byte header.
+    if len <= MAX_SHORT_LEN {
+        return Err(RlpError::NonCanonicalLength);
+    }
+    Ok((len, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    fn read_single(encoded: &str) -> RlpResult<Vec<u8>> {
+        let encoded = encoded.decode_hex().unwrap();
+        let mut reader = RlpReader::new(&encoded);
+        let data = reader.next_item()?.data()?.to_vec();
+        reader.finish()?;
+        Ok(data)
+    }
+
+    #[test]
+    fn test_read_data() {
+        assert_eq!(read_single("80"), Ok(Vec::new()));
+        assert_eq!(read_single("7f"), Ok(vec![0x7f]));
+        assert_eq!(read_single("8180"), Ok(vec![0x80]));
+        assert_eq!(read_single("83646f67"), Ok(b"dog".to_vec()));
+
+        let long_string = vec![0xaa; 56];
+        let encoded = format!("b838{}", "aa".repeat(56));
+        assert_eq!(read_single(&encoded), Ok(long_string));
+    }
+
+    #[test]
+    fn test_read_list() {
+        let encoded = "c88363617483646f67".decode_hex().unwrap();
+        let mut list = RlpReader::new(&encoded)
+            .next_item()
+            .unwrap()
+            .list()
+            .unwrap();
+        assert_eq!(list.count_items(), Ok(2));
+        assert_eq!(list.next_item().unwrap().data(), Ok(b"cat".as_slice()));
+        assert_eq!(list.next_item().unwrap().data(), Ok(b"dog".as_slice()));
+        assert_eq!(list.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_read_non_canonical() {
+        // A single byte must be encoded as it is.
+        assert_eq!(read_single("8101"), Err(RlpError::NonCanonicalSingleByte));
+        // A short string must be encoded with a single-byte header.
+        let encoded = format!("b837{}", "aa".repeat(55));
+        assert_eq!(read_single(&encoded), Err(RlpError::NonCanonicalLength));
+        // A length must not have leading zeros.
+        let encoded = format!("b90038{}", "aa".repeat(56));
+        assert_eq!(read_single(&encoded), Err(RlpError::NonCanonicalLength));
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        assert_eq!(read_single(""), Err(RlpError::InputTooShort));
+        assert_eq!(read_single("83646f"), Err(RlpError::InputTooShort));
+        assert_eq!(read_single("b8"), Err(RlpError::InputTooShort));
+        assert_eq!(read_single("8080"), Err(RlpError::TrailingBytes));
+        assert_eq!(read_single("c0"), Err(RlpError::UnexpectedList));
+    }
+}
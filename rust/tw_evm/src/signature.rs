@@ -37,6 +37,16 @@ pub fn eip155_replay_protection(chain_id: U256, v: u8) -> NumberResult<U256> {
         .checked_add(v)
 }
 
+/// Splits an EIP155 `v` param into `chain_id` and `v` without replay protection.
+#[inline]
+pub fn split_eip155_replay_protection(v: U256) -> NumberResult<(U256, u8)> {
+    // v = chain_id + chain_id + 35u8 + recovery_id
+    let without_offset = v.checked_sub(35_u64)?;
+    let recovery_id = without_offset.low_u8() & 0x01;
+    let chain_id = without_offset.checked_div(2_u64)?;
+    Ok((chain_id, recovery_id))
+}
+
 /// Removes EIP155 or legacy replay protection.
 #[inline]
 pub fn remove_replay_protection(v: u8) -> u8 {
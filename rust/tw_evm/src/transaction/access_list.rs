@@ -0,0 +1,85 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::reader::RlpItem;
+use crate::rlp::{RlpDecode, RlpEncode, RlpResult};
+use tw_hash::H256;
+
+/// An address and storage keys that the transaction plans to access.
+/// https://eips.ethereum.org/EIPS/eip-2930
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<H256>,
+}
+
+/// A list of addresses and storage keys that the transaction plans to access.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessList(pub Vec<AccessListItem>);
+
+impl RlpEncode for AccessListItem {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+        self.address.rlp_append(buf);
+
+        buf.begin_list();
+        for key in self.storage_keys.iter() {
+            key.rlp_append(buf);
+        }
+        buf.finalize_list();
+
+        buf.finalize_list();
+    }
+}
+
+impl RlpEncode for AccessList {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+        for item in self.0.iter() {
+            item.rlp_append(buf);
+        }
+        buf.finalize_list();
+    }
+}
+
+impl<'a> RlpEncode for &'a AccessList {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        AccessList::rlp_append(self, buf)
+    }
+}
+
+impl RlpDecode for AccessListItem {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        let mut list = item.list()?;
+        let address = list.decode_next()?;
+
+        let mut keys_list = list.next_item()?.list()?;
+        list.finish()?;
+
+        let mut storage_keys = Vec::new();
+        while !keys_list.is_empty() {
+            storage_keys.push(keys_list.decode_next()?);
+        }
+        Ok(AccessListItem {
+            address,
+            storage_keys,
+        })
+    }
+}
+
+impl RlpDecode for AccessList {
+    fn rlp_decode(item: RlpItem<'_>) -> RlpResult<Self> {
+        let mut list = item.list()?;
+
+        let mut items = Vec::new();
+        while !list.is_empty() {
+            items.push(list.decode_next()?);
+        }
+        Ok(AccessList(items))
+    }
+}
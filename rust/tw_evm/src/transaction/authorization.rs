@@ -0,0 +1,132 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::list::RlpList;
+use crate::rlp::RlpEncode;
+use crate::transaction::signature::{EthSignature, Signature};
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_keypair::KeyPairResult;
+use tw_number::U256;
+
+/// A prefix that separates authorization digests from other signed payloads.
+const EIP7702_AUTHORIZATION_MAGIC: u8 = 0x05;
+
+/// An authorization to set the code of the signer's account to the code of the `address` contract.
+/// https://eips.ethereum.org/EIPS/eip-7702
+#[derive(Clone, Debug, PartialEq)]
+pub struct Authorization {
+    /// Zero if the authorization is valid on any chain.
+    pub chain_id: U256,
+    pub address: Address,
+    pub nonce: U256,
+}
+
+impl Authorization {
+    /// Returns the authorization digest: `keccak256(0x05 || rlp([chain_id, address, nonce]))`.
+    pub fn pre_hash(&self) -> H256 {
+        let mut list = RlpList::new();
+        list.append(self.chain_id)
+            .append(self.address)
+            .append(self.nonce);
+        let encoded = list.finish();
+
+        let mut preimage = Vec::with_capacity(encoded.len() + 1);
+        preimage.push(EIP7702_AUTHORIZATION_MAGIC);
+        preimage.extend_from_slice(encoded.as_slice());
+
+        let hash = keccak256(&preimage);
+        H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes")
+    }
+
+    /// Signs the authorization with the private key of the account that delegates its code.
+    pub fn sign(self, private_key: &secp256k1::PrivateKey) -> KeyPairResult<SignedAuthorization> {
+        let signature = private_key.sign(self.pre_hash())?;
+        Ok(SignedAuthorization::new(self, signature))
+    }
+}
+
+/// An authorization along with the signature of the account owner.
+pub struct SignedAuthorization {
+    pub authorization: Authorization,
+    pub signature: Signature,
+}
+
+impl SignedAuthorization {
+    #[inline]
+    pub fn new(authorization: Authorization, signature: secp256k1::Signature) -> Self {
+        SignedAuthorization {
+            authorization,
+            signature: Signature::new(signature),
+        }
+    }
+}
+
+impl RlpEncode for SignedAuthorization {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+        self.authorization.chain_id.rlp_append(buf);
+        self.authorization.address.rlp_append(buf);
+        self.authorization.nonce.rlp_append(buf);
+        // `v` is a y-parity (0 or 1) without replay protection.
+        self.signature.v().rlp_append(buf);
+        self.signature.r().rlp_append(buf);
+        self.signature.s().rlp_append(buf);
+        buf.finalize_list();
+    }
+}
+
+/// A list of signed authorizations carried by an EIP7702 transaction.
+#[derive(Default)]
+pub struct AuthorizationList(pub Vec<SignedAuthorization>);
+
+impl RlpEncode for AuthorizationList {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+        for item in self.0.iter() {
+            item.rlp_append(buf);
+        }
+        buf.finalize_list();
+    }
+}
+
+impl<'a> RlpEncode for &'a AuthorizationList {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        AuthorizationList::rlp_append(self, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_authorization_sign() {
+        let private_key = secp256k1::PrivateKey::try_from(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let authorization = Authorization {
+            chain_id: U256::from(1u64),
+            address: Address::from("0x3535353535353535353535353535353535353535"),
+            nonce: U256::from(1u64),
+        };
+        assert_eq!(
+            authorization.pre_hash().to_hex(),
+            "17a8451e0b7239d0cacd656debaf1842e5e3457b48183a364e8aa8fa0378606d"
+        );
+
+        let signed = authorization.sign(&private_key).unwrap();
+        let mut buf = RlpBuffer::new();
+        signed.rlp_append(&mut buf);
+        assert_eq!(buf.finish().to_hex(), "f85a019435353535353535353535353535353535353535350101a0c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955aa018b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1");
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use c_kzg::{ethereum_kzg_settings, Blob, KzgCommitment, KzgProof};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_memory::Data;
+
+pub const BYTES_PER_BLOB: usize = c_kzg::BYTES_PER_BLOB;
+
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// Blobs with their KZG commitments and proofs, that are broadcast along with an EIP4844 transaction
+/// but are not a part of the transaction itself.
+/// https://eips.ethereum.org/EIPS/eip-4844#networking
+pub struct BlobSidecar {
+    pub blobs: Vec<Data>,
+    pub commitments: Vec<Data>,
+    pub proofs: Vec<Data>,
+}
+
+impl BlobSidecar {
+    /// Computes KZG commitments and proofs of the given blobs
+    /// using the trusted setup of the Ethereum KZG ceremony.
+    pub fn from_blobs(blobs: Vec<Data>) -> SigningResult<BlobSidecar> {
+        let settings = ethereum_kzg_settings();
+
+        let mut commitments = Vec::with_capacity(blobs.len());
+        let mut proofs = Vec::with_capacity(blobs.len());
+        for blob in blobs.iter() {
+            let blob = Blob::from_bytes(blob).map_err(|_| kzg_error())?;
+
+            let commitment = KzgCommitment::blob_to_kzg_commitment(&blob, settings)
+                .map_err(|_| kzg_error())?
+                .to_bytes();
+            let proof = KzgProof::compute_blob_kzg_proof(&blob, &commitment, settings)
+                .map_err(|_| kzg_error())?
+                .to_bytes();
+
+            commitments.push(commitment.into_inner().to_vec());
+            proofs.push(proof.into_inner().to_vec());
+        }
+
+        Ok(BlobSidecar {
+            blobs,
+            commitments,
+            proofs,
+        })
+    }
+
+    /// Returns versioned hashes of the blob commitments.
+    pub fn versioned_hashes(&self) -> Vec<H256> {
+        self.commitments
+            .iter()
+            .map(|commitment| kzg_to_versioned_hash(commitment))
+            .collect()
+    }
+}
+
+/// Converts a KZG commitment to a versioned hash: `0x01 || sha256(commitment)[1..]`.
+pub fn kzg_to_versioned_hash(commitment: &[u8]) -> H256 {
+    let mut hash = sha256(commitment);
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    H256::try_from(hash.as_slice()).expect("sha256 returns 32 bytes")
+}
+
+/// Blob is either of an invalid size, or contains a non-canonical field element.
+fn kzg_error() -> SigningError {
+    SigningError(SigningErrorType::Error_invalid_params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_blob_sidecar_from_blobs() {
+        let mut blob = vec![0; BYTES_PER_BLOB];
+        blob[31] = 1;
+        blob[63] = 2;
+
+        let sidecar = BlobSidecar::from_blobs(vec![blob]).unwrap();
+        assert_eq!(
+            sidecar.commitments[0].to_hex(),
+            "a4af938c0703bfd85e942602f92ea4ed739bb61d9742743fbebc1b683cb181fbdf78b648cfa684bcac4d50f10bea9aa5"
+        );
+        assert_eq!(
+            sidecar.proofs[0].to_hex(),
+            "a2b5386e28ed0f7ae48ee97f2c8a3ea877d22dcf212219c6aecf8f30da2bbbd384b285ac147300ffb928da74cff161bd"
+        );
+        assert_eq!(
+            sidecar.versioned_hashes()[0].to_hex(),
+            "01fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b286"
+        );
+    }
+
+    #[test]
+    fn test_blob_sidecar_invalid_blob() {
+        // Blob of an invalid size.
+        assert!(BlobSidecar::from_blobs(vec![vec![0; 32]]).is_err());
+
+        // Field element is greater than the BLS modulus.
+        let blob = vec![0xff; BYTES_PER_BLOB];
+        assert!(BlobSidecar::from_blobs(vec![blob]).is_err());
+    }
+}
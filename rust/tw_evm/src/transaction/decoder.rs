@@ -0,0 +1,284 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::rlp::reader::RlpReader;
+use crate::signature::{split_eip155_replay_protection, ETHEREUM_SIGNATURE_V_OFFSET};
+use crate::transaction::access_list::AccessList;
+use crate::transaction::signature::secp256k1_signature_from_parts;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::{sha3::keccak256, H256};
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP2930_TX_TYPE: u8 = 0x01;
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// Transaction type according to EIP-2718.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransactionType {
+    /// Original transaction format, with no explicit type.
+    Legacy,
+    /// EIP-2930 transaction with an access list.
+    Eip2930,
+    /// EIP-1559 transaction with a priority fee.
+    Eip1559,
+}
+
+impl TransactionType {
+    /// Returns the EIP-2718 transaction type. Legacy transactions are considered as type 0.
+    pub fn type_number(self) -> u8 {
+        match self {
+            TransactionType::Legacy => 0,
+            TransactionType::Eip2930 => EIP2930_TX_TYPE,
+            TransactionType::Eip1559 => EIP1559_TX_TYPE,
+        }
+    }
+}
+
+/// Signature of a decoded transaction.
+#[derive(Clone, Debug)]
+pub struct DecodedSignature {
+    /// `v` as it is encoded in the transaction, i.e. with replay protection if any.
+    pub v: U256,
+    pub r: U256,
+    pub s: U256,
+    /// The address recovered from the signature.
+    pub sender: Address,
+}
+
+impl DecodedSignature {
+    fn recover(
+        v: U256,
+        r: U256,
+        s: U256,
+        recovery_id: u8,
+        pre_hash: H256,
+    ) -> SigningResult<DecodedSignature> {
+        let signature = secp256k1_signature_from_parts(r, s, recovery_id)?;
+        let public = secp256k1::PublicKey::recover(signature, pre_hash)?;
+        Ok(DecodedSignature {
+            v,
+            r,
+            s,
+            sender: Address::with_secp256k1_pubkey(&public),
+        })
+    }
+}
+
+/// A signed or unsigned transaction decoded from its binary representation.
+#[derive(Clone, Debug)]
+pub struct DecodedTransaction {
+    pub tx_type: TransactionType,
+    /// `None` if the transaction is not replay-protected (pre-EIP155).
+    pub chain_id: Option<U256>,
+    pub nonce: U256,
+    /// Relevant for legacy and EIP-2930 transactions only.
+    pub gas_price: U256,
+    /// Relevant for EIP-1559 transactions only.
+    pub max_inclusion_fee_per_gas: U256,
+    /// Relevant for EIP-1559 transactions only.
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    /// `None` if the transaction deploys a contract.
+    pub to: Option<Address>,
+    pub amount: U256,
+    pub payload: Data,
+    /// Always empty for legacy transactions.
+    pub access_list: AccessList,
+    /// A hash of the transaction data that is signed.
+    pub pre_hash: H256,
+    pub signature: Option<DecodedSignature>,
+}
+
+impl DecodedTransaction {
+    /// Decodes a legacy or an EIP-2718 enveloped transaction, either signed or unsigned.
+    /// Recovers the sender address if the transaction is signed.
+    pub fn decode(encoded: &[u8]) -> SigningResult<DecodedTransaction> {
+        match encoded {
+            [EIP2930_TX_TYPE, payload @ ..] => {
+                Self::decode_typed(TransactionType::Eip2930, payload)
+            },
+            [EIP1559_TX_TYPE, payload @ ..] => {
+                Self::decode_typed(TransactionType::Eip1559, payload)
+            },
+            // Legacy transactions are RLP lists.
+            [0xc0..=0xff, ..] => Self::decode_legacy(encoded),
+            _ => Err(SigningError(SigningErrorType::Error_input_parse)),
+        }
+    }
+
+    /// Encodes the transaction without a signature as it is signed.
+    pub fn encode_unsigned(&self) -> Data {
+        let mut list = RlpList::new();
+        if self.tx_type != TransactionType::Legacy {
+            list.append(self.chain_id.unwrap_or_default());
+        }
+
+        list.append(self.nonce);
+        match self.tx_type {
+            TransactionType::Eip1559 => list
+                .append(self.max_inclusion_fee_per_gas)
+                .append(self.max_fee_per_gas),
+            TransactionType::Legacy | TransactionType::Eip2930 => list.append(self.gas_price),
+        };
+        list.append(self.gas_limit)
+            .append(self.to)
+            .append(self.amount)
+            .append(self.payload.as_slice());
+
+        if self.tx_type == TransactionType::Legacy {
+            // Pre-EIP155 transactions are signed without the `chain_id, 0, 0` tail.
+            if let Some(chain_id) = self.chain_id {
+                list.append(chain_id)
+                    .append(U256::zero())
+                    .append(U256::zero());
+            }
+            return list.finish();
+        }
+
+        list.append(self.access_list.clone());
+        let mut encoded = vec![self.tx_type.type_number()];
+        encoded.extend_from_slice(list.finish().as_slice());
+        encoded
+    }
+
+    fn decode_legacy(encoded: &[u8]) -> SigningResult<DecodedTransaction> {
+        let mut reader = RlpReader::new(encoded);
+        let mut fields = reader.next_item()?.list()?;
+        reader.finish()?;
+
+        let nonce = fields.decode_next()?;
+        let gas_price = fields.decode_next()?;
+        let gas_limit = fields.decode_next()?;
+        let to = fields.decode_next()?;
+        let amount = fields.decode_next()?;
+        let payload = fields.decode_next()?;
+
+        let mut tx = DecodedTransaction {
+            tx_type: TransactionType::Legacy,
+            chain_id: None,
+            nonce,
+            gas_price,
+            max_inclusion_fee_per_gas: U256::zero(),
+            max_fee_per_gas: U256::zero(),
+            gas_limit,
+            to,
+            amount,
+            payload,
+            access_list: AccessList::default(),
+            pre_hash: H256::default(),
+            signature: None,
+        };
+
+        // Unsigned pre-EIP155 transactions consist of six fields only.
+        if fields.is_empty() {
+            tx.pre_hash = tx.compute_pre_hash();
+            return Ok(tx);
+        }
+
+        let v: U256 = fields.decode_next()?;
+        let r: U256 = fields.decode_next()?;
+        let s: U256 = fields.decode_next()?;
+        fields.finish()?;
+
+        // Unsigned EIP155 transactions contain `chain_id, 0, 0` in place of the signature.
+        if r.is_zero() && s.is_zero() {
+            tx.chain_id = Some(v);
+            tx.pre_hash = tx.compute_pre_hash();
+            return Ok(tx);
+        }
+
+        let recovery_id = match u8::try_from(v) {
+            Ok(legacy_v @ ETHEREUM_SIGNATURE_V_OFFSET..=28) => {
+                legacy_v - ETHEREUM_SIGNATURE_V_OFFSET
+            },
+            _ => {
+                let (chain_id, recovery_id) = split_eip155_replay_protection(v)?;
+                tx.chain_id = Some(chain_id);
+                recovery_id
+            },
+        };
+
+        tx.pre_hash = tx.compute_pre_hash();
+        tx.signature = Some(DecodedSignature::recover(
+            v,
+            r,
+            s,
+            recovery_id,
+            tx.pre_hash,
+        )?);
+        Ok(tx)
+    }
+
+    fn decode_typed(tx_type: TransactionType, encoded: &[u8]) -> SigningResult<DecodedTransaction> {
+        let mut reader = RlpReader::new(encoded);
+        let mut fields = reader.next_item()?.list()?;
+        reader.finish()?;
+
+        let chain_id = fields.decode_next()?;
+        let nonce = fields.decode_next()?;
+        let (gas_price, max_inclusion_fee_per_gas, max_fee_per_gas) = match tx_type {
+            TransactionType::Eip1559 => {
+                (U256::zero(), fields.decode_next()?, fields.decode_next()?)
+            },
+            TransactionType::Legacy | TransactionType::Eip2930 => {
+                (fields.decode_next()?, U256::zero(), U256::zero())
+            },
+        };
+        let gas_limit = fields.decode_next()?;
+        let to = fields.decode_next()?;
+        let amount = fields.decode_next()?;
+        let payload = fields.decode_next()?;
+        let access_list = fields.decode_next()?;
+
+        let mut tx = DecodedTransaction {
+            tx_type,
+            chain_id: Some(chain_id),
+            nonce,
+            gas_price,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to,
+            amount,
+            payload,
+            access_list,
+            pre_hash: H256::default(),
+            signature: None,
+        };
+        tx.pre_hash = tx.compute_pre_hash();
+
+        if fields.is_empty() {
+            return Ok(tx);
+        }
+
+        let y_parity: U256 = fields.decode_next()?;
+        let r = fields.decode_next()?;
+        let s = fields.decode_next()?;
+        fields.finish()?;
+
+        let recovery_id = match u8::try_from(y_parity) {
+            Ok(y_parity @ (0 | 1)) => y_parity,
+            _ => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+        };
+        tx.signature = Some(DecodedSignature::recover(
+            y_parity,
+            r,
+            s,
+            recovery_id,
+            tx.pre_hash,
+        )?);
+        Ok(tx)
+    }
+
+    fn compute_pre_hash(&self) -> H256 {
+        let hash = keccak256(&self.encode_unsigned());
+        H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes")
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+//! Offline lower bounds of the gas a transaction or a user operation requires,
+//! computed without any RPC call.
+
+use crate::transaction::access_list::AccessList;
+use crate::transaction::UnsignedTransactionBox;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_memory::Data;
+use tw_number::U256;
+
+const TX_GAS: u64 = 21_000;
+const TX_CREATE_GAS: u64 = 53_000;
+/// https://eips.ethereum.org/EIPS/eip-2930
+const ACCESS_LIST_ADDRESS_GAS: u64 = 2_400;
+const ACCESS_LIST_STORAGE_KEY_GAS: u64 = 1_900;
+/// https://eips.ethereum.org/EIPS/eip-3860
+const INIT_CODE_WORD_GAS: u64 = 2;
+/// https://eips.ethereum.org/EIPS/eip-7702
+const PER_EMPTY_ACCOUNT_GAS: u64 = 25_000;
+/// Calldata is priced by tokens: a zero byte counts as 1 token, a non-zero byte as 4 tokens,
+/// so that a token costs 4 gas normally (EIP-2028), and 10 gas under the EIP-7623 floor.
+/// https://eips.ethereum.org/EIPS/eip-7623
+const NON_ZERO_BYTE_TOKENS: u64 = 4;
+const STANDARD_TOKEN_GAS: u64 = 4;
+const FLOOR_TOKEN_GAS: u64 = 10;
+
+/// Bundler overheads used by the reference ERC-4337 bundler.
+/// https://github.com/eth-infinitism/bundler/blob/main/packages/sdk/src/calcPreVerificationGas.ts
+const BUNDLE_FIXED_GAS: u64 = 21_000;
+const BUNDLE_SIZE: u64 = 1;
+const PER_USER_OP_GAS: u64 = 18_300;
+const PER_USER_OP_WORD_GAS: u64 = 4;
+/// A user operation is estimated with a signature of non-zero bytes, as the actual signature is unknown yet.
+pub(crate) const DUMMY_SIGNATURE: [u8; 65] = [1; 65];
+
+const WORD_LEN: u64 = 32;
+
+/// What the gas a transaction requires depends on.
+pub enum GasParams<'a> {
+    Transaction(TransactionGasParams<'a>),
+    UserOperation(UserOperationGasParams),
+}
+
+pub struct TransactionGasParams<'a> {
+    pub gas_limit: U256,
+    /// Whether the transaction deploys a contract, i.e has no recipient.
+    pub is_create: bool,
+    pub payload: &'a [u8],
+    pub access_list: Option<&'a AccessList>,
+    /// Number of EIP-7702 authorizations.
+    pub authorizations: usize,
+}
+
+pub struct UserOperationGasParams {
+    pub pre_verification_gas: U256,
+    /// The user operation ABI-encoded as in the `EntryPoint.handleOps` calldata,
+    /// with a dummy 65-byte signature.
+    pub encoded: Data,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IntrinsicGas {
+    /// Gas charged before the execution starts.
+    pub intrinsic: u64,
+    /// The minimum gas charged by EIP-7623 regardless of the execution.
+    pub floor: u64,
+}
+
+impl IntrinsicGas {
+    /// Returns the minimum gas limit the transaction can be included with.
+    pub fn min_gas_limit(&self) -> u64 {
+        self.intrinsic.max(self.floor)
+    }
+}
+
+/// Computes the intrinsic gas of a transaction as of the Prague hardfork.
+pub fn intrinsic_gas(params: &TransactionGasParams<'_>) -> IntrinsicGas {
+    let tokens = calldata_tokens(params.payload);
+
+    let mut intrinsic = if params.is_create {
+        TX_CREATE_GAS + INIT_CODE_WORD_GAS * words_len(params.payload)
+    } else {
+        TX_GAS
+    };
+    intrinsic += tokens * STANDARD_TOKEN_GAS;
+
+    if let Some(access_list) = params.access_list {
+        let storage_keys: usize = access_list
+            .0
+            .iter()
+            .map(|item| item.storage_keys.len())
+            .sum();
+        intrinsic += access_list.0.len() as u64 * ACCESS_LIST_ADDRESS_GAS
+            + storage_keys as u64 * ACCESS_LIST_STORAGE_KEY_GAS;
+    }
+    intrinsic += params.authorizations as u64 * PER_EMPTY_ACCOUNT_GAS;
+
+    let floor = TX_GAS + tokens * FLOOR_TOKEN_GAS;
+
+    IntrinsicGas { intrinsic, floor }
+}
+
+/// Estimates `preVerificationGas` of a user operation, i.e the calldata and the bundle overhead
+/// that is not metered by the EntryPoint. The bundle is assumed to contain the only user operation.
+pub fn pre_verification_gas(params: &UserOperationGasParams) -> u64 {
+    calldata_tokens(&params.encoded) * STANDARD_TOKEN_GAS
+        + BUNDLE_FIXED_GAS / BUNDLE_SIZE
+        + PER_USER_OP_GAS
+        + PER_USER_OP_WORD_GAS * words_len(&params.encoded)
+}
+
+/// Checks whether the transaction gas limit, or the user operation `preVerificationGas`,
+/// covers the minimum computed offline.
+/// Transactions whose gas can't be computed offline are not checked.
+pub fn check_gas_limit(tx: &dyn UnsignedTransactionBox) -> SigningResult<()> {
+    let (actual, min) = match tx.gas_params() {
+        Some(GasParams::Transaction(params)) => {
+            (params.gas_limit, intrinsic_gas(&params).min_gas_limit())
+        },
+        Some(GasParams::UserOperation(params)) => {
+            (params.pre_verification_gas, pre_verification_gas(&params))
+        },
+        None => return Ok(()),
+    };
+
+    if actual < U256::from(min) {
+        return Err(SigningError(SigningErrorType::Error_wrong_fee));
+    }
+    Ok(())
+}
+
+fn calldata_tokens(data: &[u8]) -> u64 {
+    let zero_bytes = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zero_bytes = data.len() as u64 - zero_bytes;
+    zero_bytes + non_zero_bytes * NON_ZERO_BYTE_TOKENS
+}
+
+fn words_len(data: &[u8]) -> u64 {
+    (data.len() as u64).div_ceil(WORD_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::erc20::Erc20;
+    use crate::address::Address;
+    use crate::transaction::access_list::AccessListItem;
+    use crate::transaction::user_operation::UserOperation;
+    use crate::transaction::UnsignedTransaction;
+    use tw_hash::H256;
+
+    fn params(payload: &[u8]) -> TransactionGasParams<'_> {
+        TransactionGasParams {
+            gas_limit: U256::zero(),
+            is_create: false,
+            payload,
+            access_list: None,
+            authorizations: 0,
+        }
+    }
+
+    #[test]
+    fn test_intrinsic_gas_transfer() {
+        let gas = intrinsic_gas(&params(&[]));
+        assert_eq!(gas.min_gas_limit(), 21_000);
+    }
+
+    #[test]
+    fn test_intrinsic_gas_calldata_floor() {
+        let payload = Erc20::transfer(
+            Address::from("0x5322b34c88ed0691971bf52a7047448f0f4efc84"),
+            U256::from(2_000_000_000_000_000_000_u64),
+        )
+        .unwrap();
+        let gas = intrinsic_gas(&params(&payload));
+        assert_eq!(
+            gas,
+            IntrinsicGas {
+                intrinsic: 21_632,
+                floor: 22_580,
+            }
+        );
+        assert_eq!(gas.min_gas_limit(), 22_580);
+    }
+
+    #[test]
+    fn test_intrinsic_gas_create() {
+        let init_code = [0x60; 64];
+        let gas = intrinsic_gas(&TransactionGasParams {
+            is_create: true,
+            ..params(&init_code)
+        });
+        // 53000 + 2 words * 2 + 64 non-zero bytes * 16
+        assert_eq!(gas.min_gas_limit(), 54_028);
+    }
+
+    #[test]
+    fn test_intrinsic_gas_access_list_and_authorizations() {
+        let access_list = AccessList(vec![AccessListItem {
+            address: Address::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+            storage_keys: vec![H256::default(), H256::default()],
+        }]);
+        let gas = intrinsic_gas(&TransactionGasParams {
+            access_list: Some(&access_list),
+            ..params(&[])
+        });
+        assert_eq!(gas.min_gas_limit(), 27_200);
+
+        let gas = intrinsic_gas(&TransactionGasParams {
+            authorizations: 1,
+            ..params(&[])
+        });
+        assert_eq!(gas.min_gas_limit(), 46_000);
+    }
+
+    #[test]
+    fn test_pre_verification_gas() {
+        let user_op = UserOperation {
+            nonce: U256::zero(),
+            entry_point: Address::from("0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789"),
+            sender: Address::from("0xb16Db98B365B1f89191996942612B14F1Da4Bd5f"),
+            init_code: Data::default(),
+            gas_limit: U256::from(100_000u64),
+            verification_gas_limit: U256::from(100_000u64),
+            max_fee_per_gas: U256::from(1_000_000_000u64),
+            max_inclusion_fee_per_gas: U256::from(1_000_000_000u64),
+            pre_verification_gas: U256::from(46_856u64),
+            paymaster_and_data: Data::default(),
+            payload: vec![0xde, 0xad, 0xbe, 0xef],
+        };
+        let Some(GasParams::UserOperation(params)) = user_op.gas_params() else {
+            panic!("Expected user operation gas params");
+        };
+        assert_eq!(pre_verification_gas(&params), 43_164);
+    }
+}
@@ -9,8 +9,12 @@
 //!  -- simple ETH transfer
 //!  -- others with payload, function call, e.g. ERC20 transfer
 //! - Typed transactions (enveloped, EIP2718), with specific type and transaction payload
-//! - User operations (EIP4337)
+//!  -- blob-carrying (EIP4844) transactions are broadcast along with blobs, KZG commitments and proofs
+//!  -- set-code (EIP7702) transactions carry authorizations signed by the accounts that delegate their code
+//!  -- zkSync Era EIP712 (0x71) transactions are signed as typed data and may carry factory deps and paymaster params
+//! - User operations (EIP4337), EntryPoint v0.6 and v0.7 (packed) layouts
 
+use crate::transaction::intrinsic_gas::GasParams;
 use crate::transaction::signature::EthSignature;
 use tw_coin_entry::error::SigningResult;
 use tw_hash::{sha3::keccak256, H256};
@@ -18,10 +22,20 @@ use tw_keypair::ecdsa::secp256k1;
 use tw_memory::Data;
 use tw_number::U256;
 
+pub mod access_list;
+pub mod authorization;
+pub mod blob;
+pub mod decoder;
+pub mod intrinsic_gas;
 pub mod signature;
 pub mod transaction_eip1559;
+pub mod transaction_eip2930;
+pub mod transaction_eip4844;
+pub mod transaction_eip7702;
 pub mod transaction_non_typed;
+pub mod transaction_zksync;
 pub mod user_operation;
+pub mod user_operation_v0_7;
 
 pub trait TransactionCommon {
     fn payload(&self) -> Data;
@@ -30,9 +44,9 @@ pub trait TransactionCommon {
 pub trait UnsignedTransaction: TransactionCommon {
     type SignedTransaction: SignedTransaction + 'static;
 
-    fn pre_hash(&self, chain_id: U256) -> H256 {
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256> {
         let hash = keccak256(&self.encode(chain_id));
-        H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes")
+        Ok(H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes"))
     }
 
     fn encode(&self, chain_id: U256) -> Data;
@@ -42,6 +56,9 @@ pub trait UnsignedTransaction: TransactionCommon {
         signature: secp256k1::Signature,
         chain_id: U256,
     ) -> SigningResult<Self::SignedTransaction>;
+
+    /// Returns what the gas of the transaction depends on, or `None` if it can't be computed offline.
+    fn gas_params(&self) -> Option<GasParams<'_>>;
 }
 
 pub trait SignedTransaction: TransactionCommon {
@@ -49,6 +66,11 @@ pub trait SignedTransaction: TransactionCommon {
 
     fn encode(&self) -> Data;
 
+    /// Returns the network representation of the transaction if it differs from [`SignedTransaction::encode`].
+    fn encode_network(&self) -> Option<Data> {
+        None
+    }
+
     fn signature(&self) -> &Self::Signature;
 }
 
@@ -60,7 +82,7 @@ pub trait UnsignedTransactionBox: TransactionCommon {
         Box::new(self)
     }
 
-    fn pre_hash(&self, chain_id: U256) -> H256;
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256>;
 
     fn encode(&self, chain_id: U256) -> Data;
 
@@ -69,13 +91,15 @@ pub trait UnsignedTransactionBox: TransactionCommon {
         signature: secp256k1::Signature,
         chain_id: U256,
     ) -> SigningResult<Box<dyn SignedTransactionBox>>;
+
+    fn gas_params(&self) -> Option<GasParams<'_>>;
 }
 
 impl<T> UnsignedTransactionBox for T
 where
     T: UnsignedTransaction,
 {
-    fn pre_hash(&self, chain_id: U256) -> H256 {
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256> {
         <Self as UnsignedTransaction>::pre_hash(self, chain_id)
     }
 
@@ -91,11 +115,17 @@ where
         let signed = <Self as UnsignedTransaction>::try_into_signed(*self, signature, chain_id)?;
         Ok(Box::new(signed))
     }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        <Self as UnsignedTransaction>::gas_params(self)
+    }
 }
 
 pub trait SignedTransactionBox: TransactionCommon {
     fn encode(&self) -> Data;
 
+    fn encode_network(&self) -> Option<Data>;
+
     fn signature(&self) -> &dyn EthSignature;
 }
 
@@ -107,6 +137,10 @@ where
         <Self as SignedTransaction>::encode(self)
     }
 
+    fn encode_network(&self) -> Option<Data> {
+        <Self as SignedTransaction>::encode_network(self)
+    }
+
     fn signature(&self) -> &dyn EthSignature {
         <Self as SignedTransaction>::signature(self)
     }
@@ -18,6 +18,15 @@ pub trait EthSignature {
     fn s(&self) -> U256;
 }
 
+/// Creates a `secp256k1` signature from the `r`, `s` values and `v` without replay protection.
+pub fn secp256k1_signature_from_parts(
+    r: U256,
+    s: U256,
+    recovery_id: u8,
+) -> KeyPairResult<secp256k1::Signature> {
+    secp256k1::Signature::try_from_parts(r.to_big_endian(), s.to_big_endian(), recovery_id)
+}
+
 /// R-S-V Signature values.
 pub struct Signature {
     v: U256,
@@ -6,6 +6,8 @@
 
 use crate::address::Address;
 use crate::rlp::list::RlpList;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::intrinsic_gas::{GasParams, TransactionGasParams};
 use crate::transaction::signature::{EthSignature, Signature};
 use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
 use tw_coin_entry::error::SigningResult;
@@ -24,6 +26,7 @@ pub struct TransactionEip1559 {
     pub to: Option<Address>,
     pub amount: U256,
     pub payload: Data,
+    pub access_list: AccessList,
 }
 
 impl TransactionCommon for TransactionEip1559 {
@@ -53,6 +56,16 @@ impl UnsignedTransaction for TransactionEip1559 {
             chain_id,
         })
     }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        Some(GasParams::Transaction(TransactionGasParams {
+            gas_limit: self.gas_limit,
+            is_create: self.to.is_none(),
+            payload: &self.payload,
+            access_list: Some(&self.access_list),
+            authorizations: 0,
+        }))
+    }
 }
 
 pub struct SignedTransactionEip1559 {
@@ -96,8 +109,7 @@ fn encode_transaction(
         .append(tx.to)
         .append(tx.amount)
         .append(tx.payload.as_slice())
-        // empty `access_list`.
-        .append_empty_list();
+        .append(&tx.access_list);
 
     if let Some(signature) = signature {
         list.append(signature.v());
@@ -128,6 +140,7 @@ mod tests {
             to: Some(Address::from("0x6b175474e89094c44da98b954eedeac495271d0f")),
             amount: U256::zero(),
             payload: hex::decode("a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1").unwrap(),
+            access_list: AccessList::default(),
         };
         let chain_id = U256::from(10u64);
         let actual = tx.encode(chain_id);
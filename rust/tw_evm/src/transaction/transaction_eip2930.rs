@@ -0,0 +1,161 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::intrinsic_gas::{GasParams, TransactionGasParams};
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::SigningResult;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP2930_TX_TYPE: u8 = 0x01;
+
+/// EIP2930 transaction.
+pub struct TransactionEip2930 {
+    pub nonce: U256,
+    pub gas_price: U256,
+    pub gas_limit: U256,
+    pub to: Option<Address>,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+}
+
+impl TransactionCommon for TransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip2930 {
+    type SignedTransaction = SignedTransactionEip2930;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip2930 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        Some(GasParams::Transaction(TransactionGasParams {
+            gas_limit: self.gas_limit,
+            is_create: self.to.is_none(),
+            payload: &self.payload,
+            access_list: Some(&self.access_list),
+            authorizations: 0,
+        }))
+    }
+}
+
+pub struct SignedTransactionEip2930 {
+    unsigned: TransactionEip2930,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip2930 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+fn encode_transaction(
+    tx: &TransactionEip2930,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(chain_id)
+        .append(tx.nonce)
+        .append(tx.gas_price)
+        .append(tx.gas_limit)
+        .append(tx.to)
+        .append(tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list);
+
+    if let Some(signature) = signature {
+        list.append(signature.v());
+        list.append(signature.r());
+        list.append(signature.s());
+    }
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP2930_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::access_list::AccessListItem;
+    use tw_encoding::hex;
+    use tw_hash::H256;
+
+    #[test]
+    fn test_encode_transaction_eip2930() {
+        let tx = TransactionEip2930 {
+            nonce: U256::from(9u64),
+            gas_price: U256::from(20_000_000_000u64),
+            gas_limit: U256::from(50_000u64),
+            to: Some(Address::from("0x3535353535353535353535353535353535353535")),
+            amount: U256::from(1_000_000_000_000_000_000u64),
+            payload: Vec::default(),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::from("0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae"),
+                storage_keys: vec![
+                    H256::from("0000000000000000000000000000000000000000000000000000000000000003"),
+                    H256::from("0000000000000000000000000000000000000000000000000000000000000007"),
+                ],
+            }]),
+        };
+        let chain_id = U256::from(1u64);
+
+        let expected = "01f88701098504a817c80082c350943535353535353535353535353535353535353535880de0b6b3a764000080f85bf85994de0b295669a9fd93d5f28d9ec85e40f4cb697baef842a00000000000000000000000000000000000000000000000000000000000000003a00000000000000000000000000000000000000000000000000000000000000007";
+        assert_eq!(hex::encode(tx.encode(chain_id), false), expected);
+
+        let expected_pre_hash = "dca5f9338671f1ee96c484ffb6fa83930e0919054305f3b3a95ac79491c85e55";
+        assert_eq!(
+            hex::encode(tx.pre_hash(chain_id).unwrap(), false),
+            expected_pre_hash
+        );
+    }
+}
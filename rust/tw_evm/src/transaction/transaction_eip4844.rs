@@ -0,0 +1,199 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::rlp::RlpEncode;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::blob::BlobSidecar;
+use crate::transaction::intrinsic_gas::{GasParams, TransactionGasParams};
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::SigningResult;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP4844_TX_TYPE: u8 = 0x03;
+
+/// EIP4844 blob-carrying transaction.
+pub struct TransactionEip4844 {
+    pub nonce: U256,
+    pub max_inclusion_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    /// Blob transactions cannot be used to create a contract.
+    pub to: Address,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+    pub max_fee_per_blob_gas: U256,
+    pub blob_versioned_hashes: Vec<H256>,
+    /// Blobs are not signed, but they are required to broadcast the transaction.
+    pub sidecar: Option<BlobSidecar>,
+}
+
+impl TransactionCommon for TransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip4844 {
+    type SignedTransaction = SignedTransactionEip4844;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        envelope(&encode_transaction(self, chain_id, None))
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip4844 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        Some(GasParams::Transaction(TransactionGasParams {
+            gas_limit: self.gas_limit,
+            is_create: false,
+            payload: &self.payload,
+            access_list: Some(&self.access_list),
+            authorizations: 0,
+        }))
+    }
+}
+
+pub struct SignedTransactionEip4844 {
+    unsigned: TransactionEip4844,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip4844 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        envelope(&encode_transaction(
+            &self.unsigned,
+            self.chain_id,
+            Some(&self.signature),
+        ))
+    }
+
+    /// Encodes the transaction along with the blobs, KZG commitments and proofs:
+    /// `0x03 || rlp([tx_payload_body, blobs, commitments, proofs])`.
+    fn encode_network(&self) -> Option<Data> {
+        let sidecar = self.unsigned.sidecar.as_ref()?;
+        let tx_encoded = encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature));
+
+        let mut list = RlpList::new();
+        list.append_raw_encoded(&tx_encoded)
+            .append_raw_encoded(&encode_list(sidecar.blobs.iter().map(Data::as_slice)))
+            .append_raw_encoded(&encode_list(sidecar.commitments.iter().map(Data::as_slice)))
+            .append_raw_encoded(&encode_list(sidecar.proofs.iter().map(Data::as_slice)));
+        Some(envelope(&list.finish()))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+/// Returns the RLP list of the transaction fields without the type prefix.
+fn encode_transaction(
+    tx: &TransactionEip4844,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(chain_id)
+        .append(tx.nonce)
+        .append(tx.max_inclusion_fee_per_gas)
+        .append(tx.max_fee_per_gas)
+        .append(tx.gas_limit)
+        .append(tx.to)
+        .append(tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list)
+        .append(tx.max_fee_per_blob_gas)
+        .append_raw_encoded(&encode_list(tx.blob_versioned_hashes.iter().copied()));
+
+    if let Some(signature) = signature {
+        list.append(signature.v());
+        list.append(signature.r());
+        list.append(signature.s());
+    }
+
+    list.finish()
+}
+
+fn encode_list<T, I>(items: I) -> Data
+where
+    T: RlpEncode,
+    I: IntoIterator<Item = T>,
+{
+    let mut list = RlpList::new();
+    for item in items {
+        list.append(item);
+    }
+    list.finish()
+}
+
+fn envelope(tx_encoded: &[u8]) -> Data {
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP4844_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded);
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex;
+
+    #[test]
+    fn test_encode_transaction_eip4844() {
+        let tx = TransactionEip4844 {
+            nonce: U256::from(1u64),
+            max_inclusion_fee_per_gas: U256::from(1_000_000_000u64),
+            max_fee_per_gas: U256::from(30_000_000_000u64),
+            gas_limit: U256::from(21000u32),
+            to: Address::from("0x3535353535353535353535353535353535353535"),
+            amount: U256::zero(),
+            payload: Data::default(),
+            access_list: AccessList::default(),
+            max_fee_per_blob_gas: U256::from(1_000_000_000u64),
+            blob_versioned_hashes: vec![H256::from(
+                "01fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b286",
+            )],
+            sidecar: None,
+        };
+        let chain_id = U256::from(1u64);
+        let actual = tx.encode(chain_id);
+
+        let expected = "03f84f0101843b9aca008506fc23ac008252089435353535353535353535353535353535353535358080c0843b9aca00e1a001fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b286";
+        assert_eq!(hex::encode(actual, false), expected);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::authorization::AuthorizationList;
+use crate::transaction::intrinsic_gas::{GasParams, TransactionGasParams};
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::SigningResult;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP7702_TX_TYPE: u8 = 0x04;
+
+/// EIP7702 transaction that sets the code of the authorizing accounts.
+pub struct TransactionEip7702 {
+    pub nonce: U256,
+    pub max_inclusion_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    /// Set-code transactions cannot be used to create a contract.
+    pub to: Address,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+    pub authorization_list: AuthorizationList,
+}
+
+impl TransactionCommon for TransactionEip7702 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip7702 {
+    type SignedTransaction = SignedTransactionEip7702;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip7702 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        Some(GasParams::Transaction(TransactionGasParams {
+            gas_limit: self.gas_limit,
+            is_create: false,
+            payload: &self.payload,
+            access_list: Some(&self.access_list),
+            authorizations: self.authorization_list.0.len(),
+        }))
+    }
+}
+
+pub struct SignedTransactionEip7702 {
+    unsigned: TransactionEip7702,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip7702 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip7702 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+fn encode_transaction(
+    tx: &TransactionEip7702,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(chain_id)
+        .append(tx.nonce)
+        .append(tx.max_inclusion_fee_per_gas)
+        .append(tx.max_fee_per_gas)
+        .append(tx.gas_limit)
+        .append(tx.to)
+        .append(tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list)
+        .append(&tx.authorization_list);
+
+    if let Some(signature) = signature {
+        list.append(signature.v());
+        list.append(signature.r());
+        list.append(signature.s());
+    }
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP7702_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::authorization::Authorization;
+    use tw_encoding::hex;
+
+    #[test]
+    fn test_encode_transaction_eip7702() {
+        let private_key = secp256k1::PrivateKey::try_from(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        // The sender delegates its own account, so the authorization nonce is the transaction nonce + 1.
+        let authorization = Authorization {
+            chain_id: U256::from(1u64),
+            address: Address::from("0x3535353535353535353535353535353535353535"),
+            nonce: U256::from(1u64),
+        }
+        .sign(&private_key)
+        .unwrap();
+
+        let tx = TransactionEip7702 {
+            nonce: U256::zero(),
+            max_inclusion_fee_per_gas: U256::from(1_000_000_000u64),
+            max_fee_per_gas: U256::from(30_000_000_000u64),
+            gas_limit: U256::from(100_000u64),
+            to: Address::from("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            amount: U256::zero(),
+            payload: Data::default(),
+            access_list: AccessList::default(),
+            authorization_list: AuthorizationList(vec![authorization]),
+        };
+        let chain_id = U256::from(1u64);
+        let actual = tx.encode(chain_id);
+
+        let expected = "04f8870180843b9aca008506fc23ac00830186a0949d8a62f656a8d1615c1294fd71e9cfb3e4855a4f8080c0f85cf85a019435353535353535353535353535353535353535350101a0c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955aa018b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1";
+        assert_eq!(hex::encode(actual, false), expected);
+    }
+}
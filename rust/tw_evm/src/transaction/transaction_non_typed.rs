@@ -6,6 +6,7 @@
 
 use crate::address::Address;
 use crate::rlp::list::RlpList;
+use crate::transaction::intrinsic_gas::{GasParams, TransactionGasParams};
 use crate::transaction::signature::{EthSignature, SignatureEip155};
 use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
 use tw_coin_entry::error::SigningResult;
@@ -50,6 +51,16 @@ impl UnsignedTransaction for TransactionNonTyped {
             chain_id,
         })
     }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        Some(GasParams::Transaction(TransactionGasParams {
+            gas_limit: self.gas_limit,
+            is_create: self.to.is_none(),
+            payload: &self.payload,
+            access_list: None,
+            authorizations: 0,
+        }))
+    }
 }
 
 pub struct SignedTransactionNonTyped {
@@ -150,7 +161,7 @@ mod tests {
             payload: Vec::default(),
         };
         let chain_id = U256::from(1_u64);
-        let actual = tx.pre_hash(chain_id);
+        let actual = tx.pre_hash(chain_id).unwrap();
 
         let expected = "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53";
         assert_eq!(hex::encode(actual, false), expected);
@@ -0,0 +1,359 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::address::Address;
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::EthMessage;
+use crate::rlp::list::RlpList;
+use crate::transaction::intrinsic_gas::GasParams;
+use crate::transaction::signature::Signature;
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use serde_json::{json, Value as Json};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::hex;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const ZKSYNC_EIP712_TX_TYPE: u8 = 0x71;
+/// The default `gasPerPubdataByteLimit` used by the zkSync SDKs.
+pub const DEFAULT_GAS_PER_PUBDATA: u64 = 50_000;
+
+const BYTECODE_WORD_LEN: usize = 32;
+const BYTECODE_HASH_VERSION: u8 = 1;
+
+/// Contract bytecode deployed along with the transaction.
+#[derive(Clone, Debug)]
+pub struct FactoryDep {
+    bytecode: Data,
+    hash: H256,
+}
+
+impl FactoryDep {
+    /// Validates the bytecode and computes its zkSync bytecode hash:
+    /// `version (1 byte) ++ 0x00 ++ length in words (2 bytes) ++ sha256(bytecode)[4..]`.
+    /// https://docs.zksync.io/zksync-protocol/contracts/system-contracts#bytecode-hashing
+    pub fn new(bytecode: Data) -> SigningResult<FactoryDep> {
+        if bytecode.is_empty() || bytecode.len() % BYTECODE_WORD_LEN != 0 {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        let words_len = bytecode.len() / BYTECODE_WORD_LEN;
+        // The length in words must be odd and fit into 2 bytes.
+        let words_len = u16::try_from(words_len)
+            .ok()
+            .filter(|len| len % 2 == 1)
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+
+        let mut hash =
+            H256::try_from(sha256(&bytecode).as_slice()).expect("Expected 32 byte array");
+        hash[0] = BYTECODE_HASH_VERSION;
+        hash[1] = 0;
+        hash[2..4].copy_from_slice(&words_len.to_be_bytes());
+
+        Ok(FactoryDep { bytecode, hash })
+    }
+
+    pub fn bytecode(&self) -> &[u8] {
+        &self.bytecode
+    }
+
+    pub fn hash(&self) -> H256 {
+        self.hash
+    }
+}
+
+/// Paymaster that pays the transaction fee on behalf of the sender.
+#[derive(Clone, Debug)]
+pub struct PaymasterParams {
+    pub paymaster: Address,
+    pub paymaster_input: Data,
+}
+
+/// zkSync Era EIP-712 transaction (type 0x71).
+/// https://docs.zksync.io/zksync-protocol/rollup/transaction-lifecycle#eip-712-0x71
+pub struct TransactionZkSync {
+    pub nonce: U256,
+    pub max_inclusion_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    /// The sender is a part of the signed data.
+    pub from: Address,
+    pub to: Address,
+    pub amount: U256,
+    pub payload: Data,
+    pub gas_per_pubdata: U256,
+    pub factory_deps: Vec<FactoryDep>,
+    /// Signature of a smart contract account, used instead of the sender ECDSA signature if set.
+    pub custom_signature: Option<Data>,
+    pub paymaster_params: Option<PaymasterParams>,
+}
+
+impl TransactionZkSync {
+    /// Returns the EIP-712 typed data whose hash is signed by the sender.
+    /// Addresses are encoded as `uint256` according to the zkSync `Transaction` struct.
+    pub fn typed_data(&self, chain_id: U256) -> Json {
+        let (paymaster, paymaster_input) = match self.paymaster_params {
+            Some(ref params) => (
+                address_to_u256(params.paymaster),
+                params.paymaster_input.as_slice(),
+            ),
+            None => (U256::zero(), [].as_slice()),
+        };
+        let factory_deps: Vec<_> = self
+            .factory_deps
+            .iter()
+            .map(|dep| hex::encode(dep.hash(), true))
+            .collect();
+
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                ],
+                "Transaction": [
+                    { "name": "txType", "type": "uint256" },
+                    { "name": "from", "type": "uint256" },
+                    { "name": "to", "type": "uint256" },
+                    { "name": "gasLimit", "type": "uint256" },
+                    { "name": "gasPerPubdataByteLimit", "type": "uint256" },
+                    { "name": "maxFeePerGas", "type": "uint256" },
+                    { "name": "maxPriorityFeePerGas", "type": "uint256" },
+                    { "name": "paymaster", "type": "uint256" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "value", "type": "uint256" },
+                    { "name": "data", "type": "bytes" },
+                    { "name": "factoryDeps", "type": "bytes32[]" },
+                    { "name": "paymasterInput", "type": "bytes" },
+                ],
+            },
+            "primaryType": "Transaction",
+            "domain": {
+                "name": "zkSync",
+                "version": "2",
+                "chainId": chain_id.to_string(),
+            },
+            "message": {
+                "txType": ZKSYNC_EIP712_TX_TYPE,
+                "from": address_to_u256(self.from).to_string(),
+                "to": address_to_u256(self.to).to_string(),
+                "gasLimit": self.gas_limit.to_string(),
+                "gasPerPubdataByteLimit": self.gas_per_pubdata.to_string(),
+                "maxFeePerGas": self.max_fee_per_gas.to_string(),
+                "maxPriorityFeePerGas": self.max_inclusion_fee_per_gas.to_string(),
+                "paymaster": paymaster.to_string(),
+                "nonce": self.nonce.to_string(),
+                "value": self.amount.to_string(),
+                "data": hex::encode(&self.payload, true),
+                "factoryDeps": factory_deps,
+                "paymasterInput": hex::encode(paymaster_input, true),
+            },
+        })
+    }
+}
+
+impl TransactionCommon for TransactionZkSync {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionZkSync {
+    type SignedTransaction = SignedTransactionZkSync;
+
+    /// Unlike other transactions, the signing hash is the EIP-712 hash of the transaction.
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256> {
+        let message = Eip712Message::from_json(self.typed_data(chain_id))?;
+        Ok(message.hash()?)
+    }
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionZkSync {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+
+    /// zkSync Era charges L2 gas and pubdata in its own way that can't be computed offline.
+    #[inline]
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        None
+    }
+}
+
+pub struct SignedTransactionZkSync {
+    unsigned: TransactionZkSync,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionZkSync {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionZkSync {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+/// The sender signature is passed as `customSignature` in the R-S-V format, `v` is either 27 or 28.
+/// The `v, r, s` fields are set to `chainId, 0x, 0x` as the zkSync SDKs do.
+fn encode_transaction(
+    tx: &TransactionZkSync,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let empty: &[u8] = &[];
+
+    let mut list = RlpList::new();
+    list.append(tx.nonce)
+        .append(tx.max_inclusion_fee_per_gas)
+        .append(tx.max_fee_per_gas)
+        .append(tx.gas_limit)
+        .append(tx.to)
+        .append(tx.amount)
+        .append(tx.payload.as_slice())
+        .append(chain_id)
+        .append(empty)
+        .append(empty)
+        .append(chain_id)
+        .append(tx.from)
+        .append(tx.gas_per_pubdata);
+
+    let mut factory_deps = RlpList::new();
+    for dep in tx.factory_deps.iter() {
+        factory_deps.append(dep.bytecode());
+    }
+    list.append_list(factory_deps);
+
+    match (&tx.custom_signature, signature) {
+        (Some(custom_signature), _) => list.append(custom_signature.as_slice()),
+        (None, Some(signature)) => {
+            let mut rsv = signature.to_rsv_bytes();
+            rsv[64] += 27;
+            list.append(rsv.as_slice())
+        },
+        (None, None) => list.append(empty),
+    };
+
+    match tx.paymaster_params {
+        Some(ref params) => {
+            let mut paymaster = RlpList::new();
+            paymaster
+                .append(params.paymaster)
+                .append(params.paymaster_input.as_slice());
+            list.append_list(paymaster)
+        },
+        None => list.append_empty_list(),
+    };
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(ZKSYNC_EIP712_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+fn address_to_u256(addr: Address) -> U256 {
+    U256::from_big_endian_slice(addr.as_slice()).expect("Address is shorter than 32 bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+    use tw_keypair::traits::SigningKeyTrait;
+
+    #[test]
+    fn test_factory_dep_hash() {
+        let dep = FactoryDep::new(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            dep.hash().to_hex(),
+            "0100000128fc4c10d78e287ca5d9cc51ee1ae73cbfde08c6b37324cbfaac8bc5"
+        );
+
+        // Must consist of whole 32-byte words.
+        FactoryDep::new(vec![0; 33]).unwrap_err();
+        // Must consist of an odd number of words.
+        FactoryDep::new(vec![0; 64]).unwrap_err();
+        FactoryDep::new(Data::default()).unwrap_err();
+    }
+
+    #[test]
+    fn test_encode_transaction_zksync_with_paymaster() {
+        let private_key = secp256k1::PrivateKey::try_from(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let bytecode = "0000000000000000000000000000000000000000000000000000000000000001"
+            .decode_hex()
+            .unwrap();
+
+        let tx = TransactionZkSync {
+            nonce: U256::from(7u64),
+            max_inclusion_fee_per_gas: U256::from(100_000_000u64),
+            max_fee_per_gas: U256::from(250_000_000u64),
+            gas_limit: U256::from(2_000_000u64),
+            from: Address::from("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            to: Address::from("0x5322b34c88ed0691971bf52a7047448f0f4efc84"),
+            amount: U256::zero(),
+            payload: "deadbeef".decode_hex().unwrap(),
+            gas_per_pubdata: U256::from(800u64),
+            factory_deps: vec![FactoryDep::new(bytecode).unwrap()],
+            custom_signature: None,
+            paymaster_params: Some(PaymasterParams {
+                paymaster: Address::from("0x4B5DF730c2e6b28E17013A1485E5d9BC41Efe021"),
+                // `general(bytes)` paymaster flow with empty input.
+                paymaster_input: "8c5a344500000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000".decode_hex().unwrap(),
+            }),
+        };
+        let chain_id = U256::from(324u64);
+
+        let pre_hash = tx.pre_hash(chain_id).unwrap();
+        assert_eq!(
+            pre_hash.to_hex(),
+            "daaf3e14a279e3138cbbcdcceae244954cd2c9475e6e56b4cdec62406c725ccf"
+        );
+
+        let signature = private_key.sign(pre_hash).unwrap();
+        let signed = tx.try_into_signed(signature, chain_id).unwrap();
+        let expected = "71f9010c078405f5e100840ee6b280831e8480945322b34c88ed0691971bf52a7047448f0f4efc848084deadbeef8201448080820144949d8a62f656a8d1615c1294fd71e9cfb3e4855a4f820320e1a00000000000000000000000000000000000000000000000000000000000000001b8416e495b5ae6637ae237dfd13b712ee1e6bc4619063c960ccd0dc38a68da1978255a83826b6e7ce04afc87a0fc6813ceac1385b6dc3e14bc29a366095b6d9bd98e1bf85b944b5df730c2e6b28e17013a1485e5d9bc41efe021b8448c5a344500000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(signed.encode().to_hex(), expected);
+    }
+}
@@ -8,6 +8,7 @@ use crate::abi::encode::encode_tokens;
 use crate::abi::non_empty_array::NonEmptyBytes;
 use crate::abi::token::Token;
 use crate::address::Address;
+use crate::transaction::intrinsic_gas::{GasParams, UserOperationGasParams, DUMMY_SIGNATURE};
 use crate::transaction::signature::Signature;
 use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
 use serde::Serialize;
@@ -44,7 +45,7 @@ impl TransactionCommon for UserOperation {
 impl UnsignedTransaction for UserOperation {
     type SignedTransaction = SignedUserOperation;
 
-    fn pre_hash(&self, chain_id: U256) -> H256 {
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256> {
         let encode_hash = keccak256(&self.encode(chain_id));
         let encode_hash =
             NonEmptyBytes::new(encode_hash).expect("keccak256 must not return an empty hash");
@@ -56,7 +57,7 @@ impl UnsignedTransaction for UserOperation {
         ];
         let encoded = encode_tokens(&tokens);
         let pre_hash = keccak256(&encoded);
-        H256::try_from(pre_hash.as_slice()).expect("keccak256 returns 32 bytes")
+        Ok(H256::try_from(pre_hash.as_slice()).expect("keccak256 returns 32 bytes"))
     }
 
     fn encode(&self, _chain_id: U256) -> Data {
@@ -99,6 +100,26 @@ impl UnsignedTransaction for UserOperation {
             signature: Signature::new(signature),
         })
     }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        let tokens = [
+            Token::Address(self.sender),
+            Token::u256(self.nonce),
+            Token::Bytes(self.init_code.clone()),
+            Token::Bytes(self.payload.clone()),
+            Token::u256(self.gas_limit),
+            Token::u256(self.verification_gas_limit),
+            Token::u256(self.pre_verification_gas),
+            Token::u256(self.max_fee_per_gas),
+            Token::u256(self.max_inclusion_fee_per_gas),
+            Token::Bytes(self.paymaster_and_data.clone()),
+            Token::Bytes(DUMMY_SIGNATURE.to_vec()),
+        ];
+        Some(GasParams::UserOperation(UserOperationGasParams {
+            pre_verification_gas: self.pre_verification_gas,
+            encoded: encode_tokens(&tokens),
+        }))
+    }
 }
 
 pub struct SignedUserOperation {
@@ -195,7 +216,7 @@ mod tests {
         let expected = "000000000000000000000000b16db98b365b1f89191996942612b14f1da4bd5f0000000000000000000000000000000000000000000000000000000000000002c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470fbec3c1db0378685d954edd265aa6eb11e8474d828e6bda151810263838e457000000000000000000000000000000000000000000000000000000000000186a000000000000000000000000000000000000000000000000000000000000186a0000000000000000000000000000000000000000000000000000000000000b70800000000000000000000000000000000000000000000000000000001a339c9e900000000000000000000000000000000000000000000000000000001a339c9e9c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
         assert_eq!(encoded, expected);
 
-        let pre_hash = user_op.pre_hash(chain_id);
+        let pre_hash = user_op.pre_hash(chain_id).unwrap();
         let expected_pre_hash =
             H256::from("2d37191a8688f69090451ed90a0a9ba69d652c2062ee9d023b3ebe964a3ed2ae");
         assert_eq!(pre_hash, expected_pre_hash);
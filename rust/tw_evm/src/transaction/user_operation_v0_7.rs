@@ -0,0 +1,325 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use crate::abi::encode::encode_tokens;
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::token::Token;
+use crate::address::Address;
+use crate::transaction::intrinsic_gas::{GasParams, UserOperationGasParams, DUMMY_SIGNATURE};
+use crate::transaction::signature::Signature;
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_encoding::hex;
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// EIP4337 PackedUserOperation as expected by EntryPoint v0.7.
+/// Gas limits and fees are packed in pairs of `uint128` values.
+/// https://github.com/eth-infinitism/account-abstraction/blob/v0.7.0/contracts/interfaces/PackedUserOperation.sol
+pub struct UserOperationV0_7 {
+    pub nonce: U256,
+    pub entry_point: Address,
+    pub sender: Address,
+    /// Account factory contract, or `None` if the account is already deployed.
+    pub factory: Option<Address>,
+    pub factory_data: Data,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: U256,
+    pub max_fee_per_gas: u128,
+    pub max_inclusion_fee_per_gas: u128,
+    /// Paymaster sponsoring the transaction, or `None` for a self-sponsored transaction.
+    pub paymaster: Option<Address>,
+    pub paymaster_verification_gas_limit: u128,
+    pub paymaster_post_op_gas_limit: u128,
+    pub paymaster_data: Data,
+    pub payload: Data,
+}
+
+impl UserOperationV0_7 {
+    /// Returns `factory || factory_data`, or an empty value if there is no factory.
+    pub fn init_code(&self) -> Data {
+        match self.factory {
+            Some(factory) => {
+                let mut init_code = factory.as_slice().to_vec();
+                init_code.extend_from_slice(&self.factory_data);
+                init_code
+            },
+            None => Data::default(),
+        }
+    }
+
+    /// Returns `verification_gas_limit || call_gas_limit`.
+    pub fn account_gas_limits(&self) -> H256 {
+        concat_u128(self.verification_gas_limit, self.call_gas_limit)
+    }
+
+    /// Returns `max_priority_fee_per_gas || max_fee_per_gas`.
+    pub fn gas_fees(&self) -> H256 {
+        concat_u128(self.max_inclusion_fee_per_gas, self.max_fee_per_gas)
+    }
+
+    /// Returns `paymaster || paymaster_verification_gas_limit || paymaster_post_op_gas_limit || paymaster_data`,
+    /// or an empty value if there is no paymaster.
+    pub fn paymaster_and_data(&self) -> Data {
+        match self.paymaster {
+            Some(paymaster) => {
+                let mut paymaster_and_data = paymaster.as_slice().to_vec();
+                paymaster_and_data
+                    .extend_from_slice(&self.paymaster_verification_gas_limit.to_be_bytes());
+                paymaster_and_data
+                    .extend_from_slice(&self.paymaster_post_op_gas_limit.to_be_bytes());
+                paymaster_and_data.extend_from_slice(&self.paymaster_data);
+                paymaster_and_data
+            },
+            None => Data::default(),
+        }
+    }
+}
+
+impl TransactionCommon for UserOperationV0_7 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for UserOperationV0_7 {
+    type SignedTransaction = SignedUserOperationV0_7;
+
+    fn pre_hash(&self, chain_id: U256) -> SigningResult<H256> {
+        let encode_hash = keccak256(&self.encode(chain_id));
+        let encode_hash =
+            NonEmptyBytes::new(encode_hash).expect("keccak256 must not return an empty hash");
+
+        let tokens = [
+            Token::FixedBytes(encode_hash),
+            Token::Address(self.entry_point),
+            Token::u256(chain_id),
+        ];
+        let encoded = encode_tokens(&tokens);
+        let pre_hash = keccak256(&encoded);
+        Ok(H256::try_from(pre_hash.as_slice()).expect("keccak256 returns 32 bytes"))
+    }
+
+    fn encode(&self, _chain_id: U256) -> Data {
+        let init_code_hash = keccak256(&self.init_code());
+        let init_code_hash =
+            NonEmptyBytes::new(init_code_hash).expect("keccak256 must not return an empty hash");
+
+        let payload_hash = keccak256(&self.payload);
+        let payload_hash =
+            NonEmptyBytes::new(payload_hash).expect("keccak256 must not return an empty hash");
+
+        let paymaster_and_data_hash = keccak256(&self.paymaster_and_data());
+        let paymaster_and_data_hash = NonEmptyBytes::new(paymaster_and_data_hash)
+            .expect("keccak256 must not return an empty hash");
+
+        let account_gas_limits =
+            NonEmptyBytes::new(self.account_gas_limits().to_vec()).expect("H256 must not be empty");
+        let gas_fees =
+            NonEmptyBytes::new(self.gas_fees().to_vec()).expect("H256 must not be empty");
+
+        let tokens = [
+            Token::Address(self.sender),
+            Token::u256(self.nonce),
+            Token::FixedBytes(init_code_hash),
+            Token::FixedBytes(payload_hash),
+            Token::FixedBytes(account_gas_limits),
+            Token::u256(self.pre_verification_gas),
+            Token::FixedBytes(gas_fees),
+            Token::FixedBytes(paymaster_and_data_hash),
+        ];
+
+        encode_tokens(&tokens)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: tw_keypair::ecdsa::secp256k1::Signature,
+        _chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedUserOperationV0_7 {
+            unsigned: self,
+            signature: Signature::new(signature),
+        })
+    }
+
+    fn gas_params(&self) -> Option<GasParams<'_>> {
+        let account_gas_limits =
+            NonEmptyBytes::new(self.account_gas_limits().to_vec()).expect("H256 must not be empty");
+        let gas_fees =
+            NonEmptyBytes::new(self.gas_fees().to_vec()).expect("H256 must not be empty");
+
+        let tokens = [
+            Token::Address(self.sender),
+            Token::u256(self.nonce),
+            Token::Bytes(self.init_code()),
+            Token::Bytes(self.payload.clone()),
+            Token::FixedBytes(account_gas_limits),
+            Token::u256(self.pre_verification_gas),
+            Token::FixedBytes(gas_fees),
+            Token::Bytes(self.paymaster_and_data()),
+            Token::Bytes(DUMMY_SIGNATURE.to_vec()),
+        ];
+        Some(GasParams::UserOperation(UserOperationGasParams {
+            pre_verification_gas: self.pre_verification_gas,
+            encoded: encode_tokens(&tokens),
+        }))
+    }
+}
+
+pub struct SignedUserOperationV0_7 {
+    unsigned: UserOperationV0_7,
+    signature: Signature,
+}
+
+impl TransactionCommon for SignedUserOperationV0_7 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedUserOperationV0_7 {
+    type Signature = Signature;
+
+    /// Encodes the user operation in the unpacked form accepted by bundlers (`eth_sendUserOperation`).
+    fn encode(&self) -> Data {
+        let mut signature = self.signature.to_rsv_bytes();
+        signature[64] += 27;
+
+        let prefix = true;
+        let unsigned = &self.unsigned;
+
+        let (factory, factory_data) = match unsigned.factory {
+            Some(factory) => (
+                Some(factory.to_string()),
+                Some(hex::encode(&unsigned.factory_data, prefix)),
+            ),
+            None => (None, None),
+        };
+        let paymaster = unsigned.paymaster.map(|paymaster| PaymasterSerde {
+            paymaster: paymaster.to_string(),
+            paymaster_verification_gas_limit: unsigned.paymaster_verification_gas_limit.to_string(),
+            paymaster_post_op_gas_limit: unsigned.paymaster_post_op_gas_limit.to_string(),
+            paymaster_data: hex::encode(&unsigned.paymaster_data, prefix),
+        });
+
+        let tx = SignedUserOperationV0_7Serde {
+            sender: unsigned.sender.to_string(),
+            nonce: unsigned.nonce.to_string(),
+            factory,
+            factory_data,
+            call_data: hex::encode(&unsigned.payload, prefix),
+            call_gas_limit: unsigned.call_gas_limit.to_string(),
+            verification_gas_limit: unsigned.verification_gas_limit.to_string(),
+            pre_verification_gas: unsigned.pre_verification_gas.to_string(),
+            max_fee_per_gas: unsigned.max_fee_per_gas.to_string(),
+            max_priority_fee_per_gas: unsigned.max_inclusion_fee_per_gas.to_string(),
+            paymaster,
+            signature: hex::encode(signature.as_slice(), prefix),
+        };
+        serde_json::to_string(&tx)
+            .expect("Simple structure should never fail on serialization")
+            .into_bytes()
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedUserOperationV0_7Serde {
+    sender: String,
+    nonce: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory_data: Option<String>,
+    call_data: String,
+    call_gas_limit: String,
+    verification_gas_limit: String,
+    pre_verification_gas: String,
+    max_fee_per_gas: String,
+    max_priority_fee_per_gas: String,
+    #[serde(flatten)]
+    paymaster: Option<PaymasterSerde>,
+    signature: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PaymasterSerde {
+    paymaster: String,
+    paymaster_verification_gas_limit: String,
+    paymaster_post_op_gas_limit: String,
+    paymaster_data: String,
+}
+
+fn concat_u128(high: u128, low: u128) -> H256 {
+    let mut res = H256::default();
+    res[0..16].copy_from_slice(&high.to_be_bytes());
+    res[16..32].copy_from_slice(&low.to_be_bytes());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::prebuild::erc4337::{Erc4337SimpleAccount, ExecuteArgs};
+
+    #[test]
+    fn test_encode_user_operation_v0_7() {
+        let chain_id = U256::from(11_155_111u64);
+
+        let execute_args = ExecuteArgs {
+            to: Address::from("0x61061fCAE11fD5461535e134EfF67A98CFFF44E9"),
+            value: U256::from(0x2_386f_26fc_10000u64),
+            data: Vec::default(),
+        };
+        let payload = Erc4337SimpleAccount::encode_execute(execute_args).unwrap();
+
+        let user_op = UserOperationV0_7 {
+            nonce: U256::zero(),
+            entry_point: Address::from("0x0000000071727De22E5E9d8BAf0edAc6f37da032"),
+            sender: Address::from("0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029"),
+            factory: Some(Address::from("0xf471789937856d80e589f5996cf8b0511ddd9de4")),
+            factory_data: hex::decode("f14ddffc000000000000000000000000b0086171ac7b6bd4d046580bca6d6a4b0835c2320000000000000000000000000000000000000000000000000000000000000000").unwrap(),
+            call_gas_limit: 100_000,
+            verification_gas_limit: 100_000,
+            pre_verification_gas: U256::from(1_000_000u64),
+            max_fee_per_gas: 100_000_000_000,
+            max_inclusion_fee_per_gas: 1_000_000_000,
+            paymaster: None,
+            paymaster_verification_gas_limit: 0,
+            paymaster_post_op_gas_limit: 0,
+            paymaster_data: Data::default(),
+            payload,
+        };
+
+        assert_eq!(
+            hex::encode(user_op.account_gas_limits(), false),
+            "000000000000000000000000000186a0000000000000000000000000000186a0"
+        );
+        assert_eq!(
+            hex::encode(user_op.gas_fees(), false),
+            "0000000000000000000000003b9aca000000000000000000000000174876e800"
+        );
+
+        let pre_hash = user_op.pre_hash(chain_id).unwrap();
+        let expected_pre_hash =
+            H256::from("9a0549a2384be268965000a15886867b9226a5c92a915a8d3925785f0d0c8266");
+        assert_eq!(pre_hash, expected_pre_hash);
+    }
+}
@@ -687,3 +687,257 @@ fn test_decode_contract_call_error() {
     assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
     assert!(!output.error_message.is_empty());
 }
+
+#[test]
+fn test_decode_log_erc20_transfer() {
+    let abi_json = json!([
+        {
+            "anonymous": false,
+            "inputs": [
+                { "indexed": true, "internalType": "address", "name": "from", "type": "address" },
+                { "indexed": true, "internalType": "address", "name": "to", "type": "address" },
+                { "indexed": false, "internalType": "uint256", "name": "value", "type": "uint256" }
+            ],
+            "name": "Transfer",
+            "type": "event"
+        }
+    ]);
+    let topics = [
+        "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+        "0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3",
+        "00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9",
+    ];
+
+    let input = Proto::LogDecodingInput {
+        topics: topics
+            .iter()
+            .map(|topic| topic.decode_hex().unwrap().into())
+            .collect(),
+        data: "00000000000000000000000000000000000000000000000000000000000003e8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        smart_contract_abi_json: serde_json::to_string(&abi_json).unwrap().into(),
+        ..Proto::LogDecodingInput::default()
+    };
+
+    let output = AbiEncoder::<StandardEvmContext>::decode_log(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.event_signature, "Transfer(address,address,uint256)");
+    let expected_tokens = vec![
+        named_token(
+            "from",
+            TokenEnum::address("0x5FbDB2315678afecb367f032d93F642f64180aa3".into()),
+        ),
+        named_token(
+            "to",
+            TokenEnum::address("0x61061FCaE11fD5461535E134EFf67A98cFff44E9".into()),
+        ),
+        named_token("value", u_number_n::<256>(1000)),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+}
+
+#[test]
+fn test_decode_log_indexed_string() {
+    let abi_json = json!([
+        {
+            "inputs": [
+                { "indexed": true, "name": "name", "type": "string" },
+                { "indexed": false, "name": "owner", "type": "address" }
+            ],
+            "name": "Registered",
+            "type": "event"
+        }
+    ]);
+    let topics = [
+        "50f74ca45caac8020b8d891bd13ea5a2d79564986ee6a839f0d914896388322d",
+        // keccak256("alice")
+        "9c0257114eb9399a2985f8e75dad7600c5d89fe3824ffa99ec1c3eb8bf3b0501",
+    ];
+
+    let input = Proto::LogDecodingInput {
+        topics: topics
+            .iter()
+            .map(|topic| topic.decode_hex().unwrap().into())
+            .collect(),
+        data: "00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        smart_contract_abi_json: serde_json::to_string(&abi_json).unwrap().into(),
+        ..Proto::LogDecodingInput::default()
+    };
+
+    let output = AbiEncoder::<StandardEvmContext>::decode_log(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+
+    // Only the hash of an indexed string is stored in the topics.
+    let name_hash = topics[1].decode_hex().unwrap();
+    let expected_tokens = vec![
+        named_token("name", TokenEnum::byte_array_fix(name_hash.into())),
+        named_token(
+            "owner",
+            TokenEnum::address("0x61061FCaE11fD5461535E134EFf67A98cFff44E9".into()),
+        ),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+}
+
+#[test]
+fn test_decode_log_anonymous() {
+    let abi_json = json!([
+        {
+            "anonymous": true,
+            "inputs": [
+                { "indexed": true, "name": "owner", "type": "address" },
+                { "indexed": false, "name": "amount", "type": "uint128" }
+            ],
+            "name": "Deposit",
+            "type": "event"
+        }
+    ]);
+    let topics = ["00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9"];
+    let data = "00000000000000000000000000000000000000000000000000000000000003e8";
+
+    // Anonymous events cannot be found by the first topic.
+    let input = Proto::LogDecodingInput {
+        topics: topics
+            .iter()
+            .map(|topic| topic.decode_hex().unwrap().into())
+            .collect(),
+        data: data.decode_hex().unwrap().into(),
+        smart_contract_abi_json: serde_json::to_string(&abi_json).unwrap().into(),
+        ..Proto::LogDecodingInput::default()
+    };
+    let output = AbiEncoder::<StandardEvmContext>::decode_log(input.clone());
+    assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
+
+    let input = Proto::LogDecodingInput {
+        event_name: "Deposit".into(),
+        ..input
+    };
+    let output = AbiEncoder::<StandardEvmContext>::decode_log(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+
+    assert_eq!(output.event_signature, "Deposit(address,uint128)");
+    let expected_tokens = vec![
+        named_token(
+            "owner",
+            TokenEnum::address("0x61061FCaE11fD5461535E134EFf67A98cFff44E9".into()),
+        ),
+        named_token("amount", u_number_n::<128>(1000)),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+}
+
+#[test]
+fn test_decode_revert_custom_error() {
+    let abi_json = json!([
+        {
+            "inputs": [
+                { "internalType": "uint256", "name": "available", "type": "uint256" },
+                { "internalType": "uint256", "name": "required", "type": "uint256" }
+            ],
+            "name": "InsufficientBalance",
+            "type": "error"
+        }
+    ]);
+    let encoded = "cf47918100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002";
+
+    let input = Proto::RevertDecodingInput {
+        encoded: encoded.decode_hex().unwrap().into(),
+        smart_contract_abi_json: serde_json::to_string(&abi_json).unwrap().into(),
+    };
+    let output = AbiEncoder::<StandardEvmContext>::decode_revert(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(
+        output.error_signature,
+        "InsufficientBalance(uint256,uint256)"
+    );
+    assert!(output.reason.is_empty());
+    let expected_tokens = vec![
+        named_token("available", u_number_n::<256>(1)),
+        named_token("required", u_number_n::<256>(2)),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+}
+
+#[test]
+fn test_decode_revert_error_string() {
+    let encoded = "08c379a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001a4e6f7420656e6f7567682045746865722070726f76696465642e000000000000";
+
+    let input = Proto::RevertDecodingInput {
+        encoded: encoded.decode_hex().unwrap().into(),
+        ..Proto::RevertDecodingInput::default()
+    };
+    let output = AbiEncoder::<StandardEvmContext>::decode_revert(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+
+    assert_eq!(output.error_signature, "Error(string)");
+    assert_eq!(output.reason, "Not enough Ether provided.");
+    let expected_tokens = vec![named_token(
+        "",
+        TokenEnum::string_value("Not enough Ether provided.".into()),
+    )];
+    assert_eq!(output.tokens, expected_tokens);
+}
+
+#[test]
+fn test_interpret_contract_call_custom_abi() {
+    let abi_json = json!([
+        {
+            "inputs": [],
+            "name": "deposit",
+            "outputs": [],
+            "stateMutability": "payable",
+            "type": "function"
+        }
+    ]);
+
+    let input = Proto::ContractCallInterpretingInput {
+        encoded: "d0e30db0".decode_hex().unwrap().into(),
+        interface_hint: Proto::ContractInterface::Custom,
+        abis: vec![Proto::ContractAbi {
+            name: "WETH".into(),
+            smart_contract_abi_json: serde_json::to_string(&abi_json).unwrap().into(),
+        }],
+    };
+    let output = AbiEncoder::<StandardEvmContext>::interpret_contract_call(input);
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+
+    let intent = output.intent.expect("Expected an interpreted call");
+    assert_eq!(intent.contract_name, "WETH");
+    assert_eq!(intent.interface, Proto::ContractInterface::Custom);
+    assert_eq!(intent.function_signature, "deposit()");
+    assert_eq!(intent.description, "Call `deposit` on WETH");
+    assert!(intent.risks.is_empty());
+}
+
+#[test]
+fn test_interpret_contract_call_error() {
+    let input = Proto::ContractCallInterpretingInput {
+        encoded: "d0e30db0".decode_hex().unwrap().into(),
+        interface_hint: Proto::ContractInterface::Custom,
+        abis: Vec::default(),
+    };
+    let output = AbiEncoder::<StandardEvmContext>::interpret_contract_call(input);
+    assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
+    assert!(output.intent.is_none());
+
+    let input = Proto::ContractCallInterpretingInput {
+        encoded: "d0e30db0".decode_hex().unwrap().into(),
+        interface_hint: Proto::ContractInterface::Custom,
+        abis: vec![Proto::ContractAbi {
+            name: "WETH".into(),
+            smart_contract_abi_json: "{".into(),
+        }],
+    };
+    let output = AbiEncoder::<StandardEvmContext>::interpret_contract_call(input);
+    assert_eq!(output.error, AbiErrorKind::Error_invalid_abi);
+}
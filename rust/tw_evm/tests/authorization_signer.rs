@@ -0,0 +1,77 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_evm::evm_context::StandardEvmContext;
+use tw_evm::modules::authorization_signer::AuthorizationSigner;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+
+#[test]
+fn test_sign_authorization() {
+    let input = Proto::AuthorizationSigningInput {
+        authorization: Some(Proto::Authorization {
+            chain_id: U256::encode_be_compact(1),
+            address: "0x3535353535353535353535353535353535353535".into(),
+            nonce: U256::encode_be_compact(1),
+        }),
+        private_key: "4646464646464646464646464646464646464646464646464646464646464646"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::AuthorizationSigningInput::default()
+    };
+
+    let output = AuthorizationSigner::<StandardEvmContext>::sign_authorization_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "17a8451e0b7239d0cacd656debaf1842e5e3457b48183a364e8aa8fa0378606d"
+    );
+
+    let signed = output
+        .authorization
+        .expect("Expected a signed authorization");
+    let authorization = signed.authorization.expect("Expected an authorization");
+    assert_eq!(authorization.chain_id, U256::encode_be_compact(1));
+    assert_eq!(
+        authorization.address,
+        "0x3535353535353535353535353535353535353535"
+    );
+    assert_eq!(authorization.nonce, U256::encode_be_compact(1));
+
+    assert_eq!(signed.y_parity, 1);
+    assert_eq!(
+        signed.r.to_hex(),
+        "c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955a"
+    );
+    assert_eq!(
+        signed.s.to_hex(),
+        "18b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1"
+    );
+}
+
+#[test]
+fn test_sign_authorization_invalid_address() {
+    let input = Proto::AuthorizationSigningInput {
+        authorization: Some(Proto::Authorization {
+            chain_id: U256::encode_be_compact(1),
+            address: "0x35353535".into(),
+            nonce: U256::encode_be_compact(1),
+        }),
+        private_key: "4646464646464646464646464646464646464646464646464646464646464646"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::AuthorizationSigningInput::default()
+    };
+
+    let output = AuthorizationSigner::<StandardEvmContext>::sign_authorization_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
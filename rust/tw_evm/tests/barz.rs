@@ -31,6 +31,15 @@ fn test_barz_transfer_account_deployed() {
         pre_verification_gas: U256::encode_be_compact(0xb708),
         verification_gas_limit: U256::encode_be_compact(0x186a0),
         paymaster_and_data: Cow::default(),
+        version: Proto::UserOperationVersion::V0_6,
+        factory: Cow::default(),
+        factory_data: Cow::default(),
+        paymaster: Cow::default(),
+        paymaster_verification_gas_limit: Cow::default(),
+        paymaster_post_op_gas_limit: Cow::default(),
+        paymaster_data: Cow::default(),
+        account_type: Proto::SCAccountType::SimpleAccount,
+        delegate_call: false,
     };
 
     let input = Proto::SigningInput {
@@ -47,6 +56,7 @@ fn test_barz_transfer_account_deployed() {
             transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
         }),
         user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
     };
 
     let output = Signer::<StandardEvmContext>::sign_proto(input);
@@ -84,6 +94,15 @@ fn test_barz_transfer_account_not_deployed() {
         pre_verification_gas: U256::encode_be_compact(0xb708),
         verification_gas_limit: U256::encode_be_compact(0x2D_C6C0),
         paymaster_and_data: Cow::default(),
+        version: Proto::UserOperationVersion::V0_6,
+        factory: Cow::default(),
+        factory_data: Cow::default(),
+        paymaster: Cow::default(),
+        paymaster_verification_gas_limit: Cow::default(),
+        paymaster_post_op_gas_limit: Cow::default(),
+        paymaster_data: Cow::default(),
+        account_type: Proto::SCAccountType::SimpleAccount,
+        delegate_call: false,
     };
 
     let input = Proto::SigningInput {
@@ -100,6 +119,7 @@ fn test_barz_transfer_account_not_deployed() {
             transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
         }),
         user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
     };
 
     let output = Signer::<StandardEvmContext>::sign_proto(input);
@@ -159,6 +179,15 @@ fn test_barz_batched_account_deployed() {
         pre_verification_gas: U256::encode_be_compact(0xDAFC),
         verification_gas_limit: U256::encode_be_compact(0x07_F7C4),
         paymaster_and_data: Cow::default(),
+        version: Proto::UserOperationVersion::V0_6,
+        factory: Cow::default(),
+        factory_data: Cow::default(),
+        paymaster: Cow::default(),
+        paymaster_verification_gas_limit: Cow::default(),
+        paymaster_post_op_gas_limit: Cow::default(),
+        paymaster_data: Cow::default(),
+        account_type: Proto::SCAccountType::SimpleAccount,
+        delegate_call: false,
     };
 
     let input = Proto::SigningInput {
@@ -173,10 +202,14 @@ fn test_barz_batched_account_deployed() {
         private_key: private_key.into(),
         transaction: Some(Proto::Transaction {
             transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::batch(
-                Proto::mod_Transaction::Batch { calls },
+                Proto::mod_Transaction::Batch {
+                    calls,
+                    multicall3_address: Cow::default(),
+                },
             ),
         }),
         user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
     };
 
     let output = Signer::<StandardEvmContext>::sign_proto(input);
@@ -0,0 +1,150 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_evm::evm_context::StandardEvmContext;
+use tw_evm::modules::deployment_address::DeploymentAddress;
+use tw_number::U256;
+use tw_proto::Barz::Proto as BarzProto;
+use tw_proto::Ethereum::Proto;
+use Proto::mod_DeploymentAddressInput::OneOfdeployment as DeploymentType;
+
+const BARZ_BYTECODE: &str = "0x60806040526040516104c83803806104c883398101604081905261002291610163565b6000858585858560405160240161003d959493929190610264565b60408051601f198184030181529181526020820180516001600160e01b0316634a93641760e01b1790525190915060009081906001600160a01b038a16906100869085906102c3565b600060405180830381855af49150503d80600081146100c1576040519150601f19603f3d011682016040523d82523d6000602084013e6100c6565b606091505b50915091508115806100e157506100dc816102df565b600114155b156100ff57604051636ff35f8960e01b815260040160405180910390fd5b505050505050505050610306565b80516001600160a01b038116811461012457600080fd5b919050565b634e487b7160e01b600052604160045260246000fd5b60005b8381101561015a578181015183820152602001610142565b50506000910152565b60008060008060008060c0878903121561017c57600080fd5b6101858761010d565b95506101936020880161010d565b94506101a16040880161010d565b93506101af6060880161010d565b92506101bd6080880161010d565b60a08801519092506001600160401b03808211156101da57600080fd5b818901915089601f8301126101ee57600080fd5b81518181111561020057610200610129565b604051601f8201601f19908116603f0116810190838211818310171561022857610228610129565b816040528281528c602084870101111561024157600080fd5b61025283602083016020880161013f565b80955050505050509295509295509295565b600060018060a01b0380881683528087166020840152808616604084015280851660608401525060a0608083015282518060a08401526102ab8160c085016020870161013f565b601f01601f19169190910160c0019695505050505050565b600082516102d581846020870161013f565b9190910192915050565b80516020808301519190811015610300576000198160200360031b1b821691505b50919050565b6101b3806103156000396000f3fe60806040523661000b57005b600080356001600160e01b03191681527f183cde5d4f6bb7b445b8fc2f7f15d0fd1d162275aded24183babbffee7cd491f6020819052604090912054819060601c806100cf576004838101546040516366ffd66360e11b81526000356001600160e01b031916928101929092526001600160a01b03169063cdffacc690602401602060405180830381865afa1580156100a8573d6000803e3d6000fd5b505050506040513d601f19601f820116820180604052508101906100cc919061014d565b90505b6001600160a01b0381166101295760405162461bcd60e51b815260206004820152601d60248201527f4261727a3a2046756e6374696f6e20646f6573206e6f74206578697374000000604482015260640160405180910390fd5b3660008037600080366000845af43d6000803e808015610148573d6000f35b3d6000fd5b60006020828403121561015f57600080fd5b81516001600160a01b038116811461017657600080fd5b939250505056fea2646970667358221220d35db061bb6ecdb7688c3674af669ce44d527cae4ded59214d06722d73da62be64736f6c63430008120033";
+/// `GnosisSafeProxyFactory.proxyCreationCode()` of Safe v1.3.0.
+const SAFE_PROXY_BYTECODE: &str = "608060405234801561001057600080fd5b506040516101e63803806101e68339818101604052602081101561003357600080fd5b8101908080519060200190929190505050600073ffffffffffffffffffffffffffffffffffffffff168173ffffffffffffffffffffffffffffffffffffffff1614156100ca576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260228152602001806101c46022913960400191505060405180910390fd5b806000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055505060ab806101196000396000f3fe608060405273ffffffffffffffffffffffffffffffffffffffff600054167fa619486e0000000000000000000000000000000000000000000000000000000060003514156050578060005260206000f35b3660008037600080366000845af43d6000803e60008114156070573d6000fd5b3d6000f3fea2646970667358221220d1429297349653a4918076d650332de1a1068c5f3e07c5c82360c277770b955264736f6c63430007060033496e76616c69642073696e676c65746f6e20616464726573732070726f7669646564";
+/// `setup([0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F], 1, 0x0, 0x, CompatibilityFallbackHandler, 0x0, 0, 0x0)`.
+const SAFE_INITIALIZER: &str = "b63e800d0000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000f48f2b2d2a534e402487b3ee7c18c33aec0fe5e400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f0000000000000000000000000000000000000000000000000000000000000000";
+
+fn compute_address(deployment: DeploymentType<'_>) -> Proto::DeploymentAddressOutput<'static> {
+    let input = Proto::DeploymentAddressInput { deployment };
+    DeploymentAddress::<StandardEvmContext>::compute_address_proto(input)
+}
+
+#[test]
+fn test_deployment_address_create() {
+    let output = compute_address(DeploymentType::create(Proto::CreateDeployment {
+        sender: "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0".into(),
+        nonce: U256::encode_be_compact(1),
+    }));
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.address, "0x343c43A37D37dfF08AE8C4A11544c718AbB4fCF8");
+    assert!(output.init_code_hash.is_empty());
+}
+
+// https://eips.ethereum.org/EIPS/eip-1014#examples
+#[test]
+fn test_deployment_address_create2() {
+    let output = compute_address(DeploymentType::create2(Proto::Create2Deployment {
+        deployer: "0x00000000000000000000000000000000deadbeef".into(),
+        salt: U256::encode_be_compact(0xcafebabe),
+        init_code: "deadbeef".decode_hex().unwrap().into(),
+        init_code_hash: Default::default(),
+    }));
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7");
+    assert_eq!(
+        output.init_code_hash.to_hex(),
+        "d4fd4e189132273036449fc9e11198c739161b4c0116a9a2dccdfa1c492006f1"
+    );
+
+    // The same deployment given by the init code hash.
+    let output = compute_address(DeploymentType::create2(Proto::Create2Deployment {
+        deployer: "0x00000000000000000000000000000000deadbeef".into(),
+        salt: U256::encode_be_compact(0xcafebabe),
+        init_code: Default::default(),
+        init_code_hash: "d4fd4e189132273036449fc9e11198c739161b4c0116a9a2dccdfa1c492006f1"
+            .decode_hex()
+            .unwrap()
+            .into(),
+    }));
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7");
+}
+
+#[test]
+fn test_deployment_address_create2_invalid_init_code_hash() {
+    let output = compute_address(DeploymentType::create2(Proto::Create2Deployment {
+        deployer: "0x00000000000000000000000000000000deadbeef".into(),
+        salt: U256::encode_be_compact(0xcafebabe),
+        init_code: Default::default(),
+        init_code_hash: "deadbeef".decode_hex().unwrap().into(),
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.address.is_empty());
+}
+
+#[test]
+fn test_deployment_address_simple_account() {
+    let output = compute_address(DeploymentType::simple_account(
+        Proto::SimpleAccountDeployment {
+            factory: "0x9406Cc6185a346906296840746125a0E44976454".into(),
+            account_implementation: "0x8ABB13360b87Be5EEb1B98647A016adD927a136c".into(),
+            proxy_bytecode: "60806040526040516104".decode_hex().unwrap().into(),
+            owner: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".into(),
+            salt: U256::encode_be_compact(7),
+        },
+    ));
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0x9f2BDA335d375d6ce775DA5488f5f42c6c89482E");
+}
+
+// Matches the `Barz.GetCounterfactualAddressNonZeroSalt` C++ test.
+#[test]
+fn test_deployment_address_barz() {
+    let output = compute_address(DeploymentType::barz(BarzProto::ContractAddressInput {
+        entry_point: "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789".into(),
+        factory: "0x96C489979E39F877BDb8637b75A25C1a5B2DE14C".into(),
+        account_facet: "0xF6F5e5fC74905e65e3FF53c6BacEba8535dd14d1".into(),
+        verification_facet: "0xaB84813cbf26Fd951CB3d7E33Dccb8995027e490".into(),
+        facet_registry: "0x9a95d201BB8F559771784D12c01F8084278c65E5".into(),
+        default_fallback: "0x522cDc7558b5f798dF5D61AB09B6D95Ebd342EF9".into(),
+        bytecode: BARZ_BYTECODE.into(),
+        public_key: "0xB5547FBdC56DCE45e1B8ef75569916D438e09c46".into(),
+        salt: 123456,
+    }));
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0xB91aaa96B138A1B1D94c9df4628187132c5F2bf1");
+}
+
+// Safe v1.3.0 deployed with the canonical `GnosisSafeProxyFactory` and `GnosisSafe` singleton.
+#[test]
+fn test_deployment_address_safe() {
+    let output = compute_address(DeploymentType::safe(Proto::SafeProxyDeployment {
+        factory: "0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2".into(),
+        proxy_bytecode: SAFE_PROXY_BYTECODE.decode_hex().unwrap().into(),
+        singleton: "0xd9Db270c1B5E3Bd161E8c8503c55cEABeE709552".into(),
+        initializer: SAFE_INITIALIZER.decode_hex().unwrap().into(),
+        salt_nonce: U256::encode_be_compact(5),
+    }));
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0x7cA83125A1eaA1E95B0d83D65d563f4306558d50");
+    assert_eq!(
+        output.init_code_hash.to_hex(),
+        "56e3081a3d1bb38ed4eed1a39f7729c3cc77c7825794c15bbf326f3047fd779c"
+    );
+}
+
+#[test]
+fn test_deployment_address_invalid_address() {
+    let output = compute_address(DeploymentType::create(Proto::CreateDeployment {
+        sender: "6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0".into(),
+        nonce: U256::encode_be_compact(1),
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_deployment_address_no_deployment() {
+    let output = compute_address(DeploymentType::None);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
@@ -19,6 +19,17 @@ const EIP712_WITH_CUSTOM_ARRAY: &str = include_str!("data/eip712_with_custom_arr
 const EIP712_UNEQUAL_ARRAY_LEN: &str = include_str!("data/eip712_unequal_array_lengths.json");
 const EIP712_WITH_CHAIN_ID_STR: &str = include_str!("data/eip712_with_chain_id_string.json");
 const EIP712_GREENFIELD: &str = include_str!("data/eip712_greenfield.json");
+const EIP712_WALLETCONNECT: &str = include_str!("data/eip712_walletconnect.json");
+const SIWE_MESSAGE: &str = "example.com wants you to sign in with your Ethereum account:
+0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F
+
+Sign in to Example.
+
+URI: https://example.com
+Version: 1
+Chain ID: 1
+Nonce: 9oXtSwrkGx4
+Issued At: 2024-05-01T12:00:00Z";
 
 struct SignVerifyTestInput {
     private_key: &'static str,
@@ -54,6 +65,7 @@ fn test_message_signer_sign_verify(test_input: SignVerifyTestInput) {
         message: test_input.msg.into(),
         public_key: public_key.compressed().to_vec().into(),
         signature: test_input.signature.into(),
+        ..Proto::MessageVerifyingInput::default()
     };
     assert!(
         EthMessageSigner.verify_message(&EmptyCoinContext, verifying_input),
@@ -172,6 +184,17 @@ fn test_message_signer_hash_with_custom_array() {
     });
 }
 
+/// `RelayRequest.encodedFunction` is a dynamic `bytes` field that must be encoded as a single `keccak256` word.
+#[test]
+fn test_message_signer_hash_eip712_bytes_field() {
+    test_message_signer_preimage_hashes(PreimageTestInput {
+        msg: EIP712_WALLETCONNECT,
+        msg_type: Proto::MessageType::MessageType_typed,
+        chain_id: 42,
+        data_hash: "abc79f527273b9e7bca1b3f1ac6ad1a8431fa6dc34ece900deabcd6969856b5e",
+    });
+}
+
 #[test]
 fn test_message_signer_hash_unequal_array_len() {
     let signing_input = Proto::MessageSigningInput {
@@ -256,3 +279,224 @@ fn test_message_signer_sign_verify_eip712_greenfield() {
         signature: "cb3a4684a991014a387a04a85b59227ebb79567c2025addcb296b4ca856e9f810d3b526f2a0d0fad6ad1b126b3b9516f8b3be020a7cca9c03ce3cf47f4199b6d1b",
     });
 }
+
+#[test]
+fn test_message_signer_sign_verify_siwe() {
+    test_message_signer_sign_verify(SignVerifyTestInput {
+        private_key: "4646464646464646464646464646464646464646464646464646464646464646",
+        msg: SIWE_MESSAGE,
+        msg_type: Proto::MessageType::MessageType_siwe,
+        chain_id: Some(1),
+        signature: "b1d6e181c3a5e28288dc92f111d242c8665db1fa5b6742df739ac45da2e5cbc20909d61f3dac08b34b87be69ceb64a75e11404e2c51110470d68f2620b8fa9891b",
+    });
+}
+
+#[test]
+fn test_message_signer_hash_siwe() {
+    test_message_signer_preimage_hashes(PreimageTestInput {
+        msg: SIWE_MESSAGE,
+        msg_type: Proto::MessageType::MessageType_siwe,
+        chain_id: 1,
+        data_hash: "4637c29e7a1f2ef17acc5beed3925a54d48d19f23ea7fd7a22269cfe63263ae3",
+    });
+}
+
+#[test]
+fn test_message_signer_sign_siwe_invalid_chain_id() {
+    test_message_signer_sign_err(SignErrorTestInput {
+        private_key: "4646464646464646464646464646464646464646464646464646464646464646",
+        msg: SIWE_MESSAGE,
+        msg_type: Proto::MessageType::MessageType_siwe,
+        chain_id: 56,
+        error: SigningErrorType::Error_invalid_params,
+    });
+}
+
+#[test]
+fn test_message_signer_sign_siwe_another_account() {
+    test_message_signer_sign_err(SignErrorTestInput {
+        private_key: "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d",
+        msg: SIWE_MESSAGE,
+        msg_type: Proto::MessageType::MessageType_siwe,
+        chain_id: 1,
+        error: SigningErrorType::Error_invalid_address,
+    });
+}
+
+#[test]
+fn test_message_signer_verify_siwe_another_account() {
+    // The signature is valid, but the message states another account.
+    let message = SIWE_MESSAGE.replace(
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+        "0x5FbDB2315678afecb367f032d93F642f64180aa3",
+    );
+    let private_key = "4646464646464646464646464646464646464646464646464646464646464646";
+    let signing_input = Proto::MessageSigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        message: message.clone().into(),
+        message_type: Proto::MessageType::MessageType_legacy,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    let verifying_input = Proto::MessageVerifyingInput {
+        message: message.into(),
+        public_key: secp256k1::PrivateKey::try_from(private_key)
+            .unwrap()
+            .public()
+            .compressed()
+            .to_vec()
+            .into(),
+        signature: output.signature,
+        ..Proto::MessageVerifyingInput::default()
+    };
+    assert!(!EthMessageSigner.verify_message(&EmptyCoinContext, verifying_input));
+}
+
+#[test]
+fn test_message_signer_sign_verify_legacy_binary() {
+    const PRIVATE_KEY: &str = "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d";
+    const SIGNATURE: &str = "7c3ac0da17d9c85e42d01c17e85f57714061fe26d6afc5dcb15067dabee6fb525c049b8777167473b76e468fd7766dba1e59bdf1a4da98bfc465eb172024d1931b";
+
+    // `0x`-prefixed messages are detected as hex, others are expected to be hex explicitly.
+    for (msg, encoding) in [
+        ("0xdeadbeef", Proto::MessageEncoding::MessageEncoding_auto),
+        ("deadbeef", Proto::MessageEncoding::MessageEncoding_hex),
+    ] {
+        let signing_input = Proto::MessageSigningInput {
+            private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+            message: msg.into(),
+            message_type: Proto::MessageType::MessageType_legacy,
+            encoding,
+            ..Proto::MessageSigningInput::default()
+        };
+        let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+        assert_eq!(output.error, SigningErrorType::OK);
+        assert_eq!(output.signature, SIGNATURE, "message: {msg}");
+    }
+
+    let public_key = secp256k1::PrivateKey::try_from(PRIVATE_KEY)
+        .unwrap()
+        .public();
+    let verifying_input = Proto::MessageVerifyingInput {
+        message: "0xdeadbeef".into(),
+        public_key: public_key.compressed().to_vec().into(),
+        signature: SIGNATURE.into(),
+        encoding: Proto::MessageEncoding::MessageEncoding_auto,
+        ..Proto::MessageVerifyingInput::default()
+    };
+    assert!(EthMessageSigner.verify_message(&EmptyCoinContext, verifying_input));
+
+    // The same message is treated as a text by default.
+    let verifying_input = Proto::MessageVerifyingInput {
+        message: "0xdeadbeef".into(),
+        public_key: public_key.compressed().to_vec().into(),
+        signature: SIGNATURE.into(),
+        ..Proto::MessageVerifyingInput::default()
+    };
+    assert!(!EthMessageSigner.verify_message(&EmptyCoinContext, verifying_input));
+}
+
+#[test]
+fn test_message_signer_sign_legacy_hex_like_text() {
+    // `0x`-prefixed messages are signed as a text unless `encoding` is set explicitly.
+    let signing_input = Proto::MessageSigningInput {
+        private_key: "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "0xdeadbeef".into(),
+        message_type: Proto::MessageType::MessageType_legacy,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.signature, "bd3458392dcaa8e412400687959b79e7d15c7339af5ba0a58851d9d951bb73cd69a4ee6936431b2b2c61eb936dcee15529a77213ff9660fb5cbf9e1f5c8321531c");
+}
+
+#[test]
+fn test_message_signer_sign_legacy_invalid_hex() {
+    let signing_input = Proto::MessageSigningInput {
+        private_key: "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Foo".into(),
+        message_type: Proto::MessageType::MessageType_legacy,
+        encoding: Proto::MessageEncoding::MessageEncoding_hex,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_message_signer_sign_verify_intended_validator() {
+    const PRIVATE_KEY: &str = "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d";
+    const VALIDATOR: &str = "0x3535353535353535353535353535353535353535";
+    const SIGNATURE: &str = "13e33d9a42449517b8bcd719f9e2653f3587f16bcb8c31d47cf279b6fa1d28641f93e0ec8c664cef5573be52d24d1222b6c7648ee89e7bc16c8e42ac90fd176b1c";
+
+    let signing_input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "0xdeadbeef".into(),
+        message_type: Proto::MessageType::MessageType_intended_validator,
+        encoding: Proto::MessageEncoding::MessageEncoding_hex,
+        validator: VALIDATOR.into(),
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.signature, SIGNATURE);
+
+    let public_key = secp256k1::PrivateKey::try_from(PRIVATE_KEY)
+        .unwrap()
+        .public();
+    let verifying_input = Proto::MessageVerifyingInput {
+        message: "0xdeadbeef".into(),
+        public_key: public_key.compressed().to_vec().into(),
+        signature: SIGNATURE.into(),
+        encoding: Proto::MessageEncoding::MessageEncoding_hex,
+        validator: VALIDATOR.into(),
+        ..Proto::MessageVerifyingInput::default()
+    };
+    assert!(EthMessageSigner.verify_message(&EmptyCoinContext, verifying_input));
+
+    // The validator is required.
+    let signing_input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "0xdeadbeef".into(),
+        message_type: Proto::MessageType::MessageType_intended_validator,
+        encoding: Proto::MessageEncoding::MessageEncoding_hex,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.sign_message(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_message_signer_hash_eip712_v3() {
+    // V3 and V4 hashes are equal if the message has neither arrays nor missing fields.
+    let signing_input = Proto::MessageSigningInput {
+        message: EIP712_CASE_1.into(),
+        message_type: Proto::MessageType::MessageType_typed,
+        typed_data_version: Proto::TypedDataVersion::TypedDataVersion_v3,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.message_preimage_hashes(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+    );
+
+    // Arrays are not supported by V3.
+    let signing_input = Proto::MessageSigningInput {
+        message: EIP712_WITH_CUSTOM_ARRAY.into(),
+        message_type: Proto::MessageType::MessageType_typed,
+        typed_data_version: Proto::TypedDataVersion::TypedDataVersion_v3,
+        ..Proto::MessageSigningInput::default()
+    };
+    let output = EthMessageSigner.message_preimage_hashes(&EmptyCoinContext, signing_input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
@@ -10,6 +10,7 @@ use tw_coin_entry::error::SigningErrorType;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_evm::evm_context::StandardEvmContext;
 use tw_evm::modules::rlp_encoder::{RlpEncoder, RECURSION_LIMIT};
+use tw_evm::rlp::list::RlpList;
 use tw_number::U256;
 use tw_proto::EthereumRlp::Proto as RlpProto;
 use RlpProto::mod_RlpItem::OneOfitem as Item;
@@ -245,3 +246,98 @@ fn test_ethereum_rlp_list_eip1559() {
         "f86c0a06847735940084b2d05e0082526c946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1c0"
     );
 }
+
+#[track_caller]
+fn test_decode(encoded: &str, expected: Item) {
+    let input = RlpProto::DecodingInput {
+        encoded: Cow::from(encoded.decode_hex().unwrap()),
+    };
+    let output = RlpEncoder::<StandardEvmContext>::decode_with_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.item, Some(make_item(expected)));
+}
+
+#[track_caller]
+fn test_decode_error(encoded: &str, expected: SigningErrorType) {
+    let input = RlpProto::DecodingInput {
+        encoded: Cow::from(encoded.decode_hex().unwrap()),
+    };
+    let output = RlpEncoder::<StandardEvmContext>::decode_with_proto(input);
+    assert_eq!(output.error, expected);
+    assert!(output.item.is_none());
+}
+
+fn data_item(hex: &str) -> RlpProto::RlpItem<'static> {
+    make_item(Item::data(Cow::from(hex.decode_hex().unwrap())))
+}
+
+#[test]
+fn test_decode_data() {
+    test_decode("80", Item::data(Cow::default()));
+    test_decode("7f", Item::data(Cow::from(vec![0x7f])));
+    test_decode("8180", Item::data(Cow::from(vec![0x80])));
+    test_decode("83646f67", Item::data(Cow::from(b"dog".to_vec())));
+}
+
+#[test]
+fn test_decode_nested_list() {
+    // [[], [[]], [[], [[]]]]
+    let empty = || make_item(Item::list(RlpProto::RlpList::default()));
+    let list_of = |items| make_item(Item::list(RlpProto::RlpList { items }));
+
+    let expected = RlpProto::RlpList {
+        items: vec![
+            empty(),
+            list_of(vec![empty()]),
+            list_of(vec![empty(), list_of(vec![empty()])]),
+        ],
+    };
+    test_decode("c7c0c1c0c3c0c1c0", Item::list(expected));
+}
+
+#[test]
+fn test_decode_list_eip1559() {
+    let expected = RlpProto::RlpList {
+        items: vec![
+            data_item("0a"),
+            data_item("06"),
+            data_item("77359400"),
+            data_item("b2d05e00"),
+            data_item("526c"),
+            data_item("6b175474e89094c44da98b954eedeac495271d0f"),
+            data_item(""),
+            data_item("a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1"),
+            make_item(Item::list(RlpProto::RlpList::default())),
+        ],
+    };
+    test_decode(
+        "f86c0a06847735940084b2d05e0082526c946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1c0",
+        Item::list(expected),
+    );
+}
+
+#[test]
+fn test_decode_invalid() {
+    // Empty input.
+    test_decode_error("", SigningErrorType::Error_input_parse);
+    // Trailing bytes.
+    test_decode_error("c0c0", SigningErrorType::Error_input_parse);
+    // Payload is shorter than declared.
+    test_decode_error("c30102", SigningErrorType::Error_input_parse);
+    // Non-canonical single byte.
+    test_decode_error("8105", SigningErrorType::Error_input_parse);
+    // Non-canonical long list header.
+    test_decode_error("f80101", SigningErrorType::Error_input_parse);
+}
+
+#[test]
+fn test_decode_nested_list_recursion_limit() {
+    let mut encoded = RlpList::new().finish();
+    for _ in 0..RECURSION_LIMIT + 10 {
+        let mut list = RlpList::new();
+        list.append_raw_encoded(&encoded);
+        encoded = list.finish();
+    }
+    test_decode_error(&encoded.to_hex(), SigningErrorType::Error_invalid_params);
+}
@@ -9,6 +9,8 @@ use tw_coin_entry::error::SigningErrorType;
 use tw_encoding::hex::{self, ToHex};
 use tw_evm::evm_context::StandardEvmContext;
 use tw_evm::modules::signer::Signer;
+use tw_evm::transaction::blob::BYTES_PER_BLOB;
+use tw_hash::sha3::keccak256;
 use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 use tw_proto::Ethereum::Proto::TransactionMode;
@@ -419,6 +421,243 @@ fn test_sign_transaction_eip1559_erc1155_transfer() {
     );
 }
 
+#[test]
+fn test_sign_transaction_eip1559_erc721_safe_transfer() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    let transaction = Proto::mod_Transaction::ERC721SafeTransfer {
+        from: "0x718046867b5b1782379a14eA4fc0c9b724DA94Fc".into(),
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        token_id: hex::decode("23c47ee5").unwrap().into(),
+        data: hex::decode("01020304").unwrap().into(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc721_safe_transfer(
+                transaction,
+            ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f901300180847735940084b2d05e00830130b9944e45e92ed38f885d39a733c14f1817217a89d42580b8c4b88d4fde000000000000000000000000718046867b5b1782379a14ea4fc0c9b724da94fc0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000023c47ee5000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000040102030400000000000000000000000000000000000000000000000000000000c080a07ccddf2f6b5e676e80eee408b9abed6023f98dd5f81a0cfa9b2023520d261b81a07c4c9feb5d31e11c25bd4f96240671652fec41a4dcef23dd3e0417c99edd7bbb";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "10eed7a8768798454799c44558b7a45868b1feb742f1b0883176c9607dc624cb"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip1559_erc1155_batch_transfer() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    let transaction = Proto::mod_Transaction::ERC1155BatchTransfer {
+        from: "0x718046867b5b1782379a14eA4fc0c9b724DA94Fc".into(),
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        token_ids: vec![U256::encode_be_compact(1), U256::encode_be_compact(2)],
+        values: vec![U256::encode_be_compact(10), U256::encode_be_compact(20)],
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof:
+                Proto::mod_Transaction::OneOftransaction_oneof::erc1155_batch_transfer(transaction),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f901f10180847735940084b2d05e00830130b9944e45e92ed38f885d39a733c14f1817217a89d42580b901842eb2c2d6000000000000000000000000718046867b5b1782379a14ea4fc0c9b724da94fc0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc8400000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001600000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000000c080a0c5d4f973330633145ce131ef8b00c78d5f50ff058861c34ffeb023be80df0bb6a05737a2d36291c4521a75f28cd27930d2f6386e28ec1f9d057dd2ba68fae72c00";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "87e9627eae52a2bcb3d3aa6bd9cd89438d95a8a74f3be4b5e6224270398da662"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip1559_erc1155_set_approval_for_all() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    let transaction = Proto::mod_Transaction::SetApprovalForAll {
+        operator: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        approved: true,
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof:
+                Proto::mod_Transaction::OneOftransaction_oneof::erc1155_set_approval_for_all(
+                    transaction,
+                ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f8b00180847735940084b2d05e00830130b9944e45e92ed38f885d39a733c14f1817217a89d42580b844a22cb4650000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000000001c080a0bc259492e9efe94cee667cef7324b191e93ff076487e05e0eac9030464e832e4a046d1d3af928e7bf2c588f4b83460bc26e7714e1acf15d1ad022d011188d8759c";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "2b19dc7928d5c2034ed1acaeabd44b07d2ff399c6b8a1296a153f3dc3b5d6c3d"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip1559_erc721_approve() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    let transaction = Proto::mod_Transaction::ERC721Approve {
+        spender: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        token_id: U256::encode_be_compact(0x23c47ee5),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc721_approve(
+                transaction,
+            ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f8b00180847735940084b2d05e00830130b9944e45e92ed38f885d39a733c14f1817217a89d42580b844095ea7b30000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000023c47ee5c001a03fcc5b1fe693135bea110e10835bd85a9a81b589cc374b031a734a14e852b42ea05a209de05a9d880f750d590cee02297d2d7a18729f7115b7bc15263a5c855e2c";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "35087bd17e8f2c07c6034744131980b237df433d96ba68ab06792c1785aaa921"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip1559_erc721_set_approval_for_all() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    // Revoke the approval.
+    let transaction = Proto::mod_Transaction::SetApprovalForAll {
+        operator: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        approved: false,
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof:
+                Proto::mod_Transaction::OneOftransaction_oneof::erc721_set_approval_for_all(
+                    transaction,
+                ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f8b00180847735940084b2d05e00830130b9944e45e92ed38f885d39a733c14f1817217a89d42580b844a22cb4650000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000000000c080a0488b8e1540b817511d1cfcf19045afbad7ba609185bbba05e0bafeaa9a313ec9a056afb9f3a85153007a7b4410437ccca3ca9f2c7d116b17363733a8a6b4b8459d";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "15a18a294d4775987da21ad04d7c30c46b884846e1efe36696fab131e09ff528"
+    );
+}
+
+#[test]
+fn test_sign_transaction_erc1155_batch_transfer_values_mismatch() {
+    let private =
+        hex::decode("0x608dcb1742bb3fb7aec002074e3420e4fab7d00cced79ccdac53ed5b27138151").unwrap();
+
+    let transaction = Proto::mod_Transaction::ERC1155BatchTransfer {
+        from: "0x718046867b5b1782379a14eA4fc0c9b724DA94Fc".into(),
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        token_ids: vec![U256::encode_be_compact(1), U256::encode_be_compact(2)],
+        values: vec![U256::encode_be_compact(10)],
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(78_009),
+        to_address: "0x4e45e92ed38f885d39a733c14f1817217a89d425".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof:
+                Proto::mod_Transaction::OneOftransaction_oneof::erc1155_batch_transfer(transaction),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
 #[test]
 fn test_sign_transaction_non_typed_erc20_transfer_as_contract_generic() {
     let private =
@@ -584,3 +823,809 @@ fn test_sign_transaction_non_typed_erc1155_transfer() {
     let expected_data = "f242432a000000000000000000000000718046867b5b1782379a14ea4fc0c9b724da94fc0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000023c47ee50000000000000000000000000000000000000000000000001bc16d674ec8000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000040102030400000000000000000000000000000000000000000000000000000000";
     assert_eq!(hex::encode(output.data, false), expected_data);
 }
+
+#[test]
+fn test_sign_transaction_eip2930_native_transfer() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+    let access = Proto::Access {
+        address: "0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe".into(),
+        stored_keys: vec![
+            hex::decode("0000000000000000000000000000000000000000000000000000000000000003")
+                .unwrap()
+                .into(),
+            hex::decode("0000000000000000000000000000000000000000000000000000000000000007")
+                .unwrap()
+                .into(),
+        ],
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        tx_mode: TransactionMode::AccessList,
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(50_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        access_list: vec![access],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "01f8ca01098504a817c80082c350943535353535353535353535353535353535353535880de0b6b3a764000080f85bf85994de0b295669a9fd93d5f28d9ec85e40f4cb697baef842a00000000000000000000000000000000000000000000000000000000000000003a0000000000000000000000000000000000000000000000000000000000000000780a05b2c251724b07d5e74952575d47e1ed1902de59e91eb53c717976ce8451a370ea01cf5e08ff99314e00fafda9939b672e3dcb4cf3625a5d7d90dab51d4b5fe5863";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "00");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "dca5f9338671f1ee96c484ffb6fa83930e0919054305f3b3a95ac79491c85e55"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip1559_erc20_transfer_with_access_list() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let erc20_transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_000_000_000_000_000_000),
+    };
+    let access_list = vec![
+        Proto::Access {
+            address: "0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe".into(),
+            stored_keys: vec![
+                hex::decode("0000000000000000000000000000000000000000000000000000000000000003")
+                    .unwrap()
+                    .into(),
+                hex::decode("0000000000000000000000000000000000000000000000000000000000000007")
+                    .unwrap()
+                    .into(),
+            ],
+        },
+        Proto::Access {
+            address: "0xbb9bc244d798123fde783fcc1c72d3bb8c189413".into(),
+            stored_keys: Vec::default(),
+        },
+    ];
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(100_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: "0x6b175474e89094c44da98b954eedeac495271d0f".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_transfer(
+                erc20_transfer,
+            ),
+        }),
+        private_key: private.into(),
+        access_list,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "02f901230180847735940084b2d05e00830186a0946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000f872f85994de0b295669a9fd93d5f28d9ec85e40f4cb697baef842a00000000000000000000000000000000000000000000000000000000000000003a00000000000000000000000000000000000000000000000000000000000000007d694bb9bc244d798123fde783fcc1c72d3bb8c189413c080a003c5a8442d9fb0cd57f0157543a42d57f33d0b6ff8d201147f37591debc50d1da0643234a719b327774737655bf5f2c0fbbe148a4c9d17a28b57c8b5f29f647250";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "25a280542a68a88948311d0cb27e43aed3ca775fbab00a17bf0436f505b484aa"
+    );
+}
+
+#[test]
+fn test_sign_transaction_invalid_storage_key() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let access = Proto::Access {
+        address: "0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe".into(),
+        // Storage key must be 32 bytes long.
+        stored_keys: vec![hex::decode("03").unwrap().into()],
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::AccessList,
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        access_list: vec![access],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_eip4844_with_versioned_hashes() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Blob,
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(30_000_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        max_fee_per_blob_gas: U256::encode_be_compact(1_000_000_000),
+        blob_versioned_hashes: vec![hex::decode(
+            "01fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b286",
+        )
+        .unwrap()
+        .into()],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "03f8990180843b9aca008506fc23ac0082520894353535353535353535353535353535353535353587038d7ea4c6800080c0843b9aca00e1a001fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b28680a0b56b0cf81825e640f056db88a357d96ccc70bcdaec5ae90c150f778b84c5a0cca045a9a58eff8029989efdb8acdfe10572b6bc6b26600742c25cb4ed2be612616d";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "00");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "69ff8b393618a9c1496010130b8d296ca48f4bb9eb497d61a5baff184a7742d6"
+    );
+    // Blobs are not provided, so the network wrapper cannot be built.
+    assert!(output.network_encoded.is_empty());
+}
+
+#[test]
+fn test_sign_transaction_eip4844_with_blobs() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    let mut blob = vec![0; BYTES_PER_BLOB];
+    blob[31] = 1;
+    blob[63] = 2;
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Blob,
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(30_000_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        max_fee_per_blob_gas: U256::encode_be_compact(1_000_000_000),
+        // Versioned hashes are computed from the blobs.
+        blobs: vec![blob.into()],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "03f8990180843b9aca008506fc23ac0082520894353535353535353535353535353535353535353587038d7ea4c6800080c0843b9aca00e1a001fda29d7d209d37e25fb02c202264d5deec82c8a44db01c38d7277c18a9b28680a0b56b0cf81825e640f056db88a357d96ccc70bcdaec5ae90c150f778b84c5a0cca045a9a58eff8029989efdb8acdfe10572b6bc6b26600742c25cb4ed2be612616d";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    // `0x03 || rlp([tx_payload_body, blobs, commitments, proofs])`.
+    assert_eq!(output.network_encoded.len(), 131_340);
+    assert_eq!(
+        keccak256(&output.network_encoded).to_hex(),
+        "e51f47559a4e8674b36040670c7a215f786aafa80a5a32a21dab214f74152e7d"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_blob_hash_mismatch() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Blob,
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        blob_versioned_hashes: vec![hex::decode(
+            "0100000000000000000000000000000000000000000000000000000000000000",
+        )
+        .unwrap()
+        .into()],
+        blobs: vec![vec![0; BYTES_PER_BLOB].into()],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_eip4844_without_blobs() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Blob,
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_eip7702_delegate_own_account() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    // The sender delegates its own account, so the authorization nonce is the transaction nonce + 1.
+    let authorization = Proto::SignedAuthorization {
+        authorization: Some(Proto::Authorization {
+            chain_id: U256::encode_be_compact(1),
+            address: "0x3535353535353535353535353535353535353535".into(),
+            nonce: U256::encode_be_compact(1),
+        }),
+        y_parity: 1,
+        r: hex::decode("c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955a")
+            .unwrap()
+            .into(),
+        s: hex::decode("18b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1")
+            .unwrap()
+            .into(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::SetCode,
+        gas_limit: U256::encode_be_compact(100_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(30_000_000_000),
+        to_address: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        authorization_list: vec![authorization],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "04f8ca0180843b9aca008506fc23ac00830186a0949d8a62f656a8d1615c1294fd71e9cfb3e4855a4f8080c0f85cf85a019435353535353535353535353535353535353535350101a0c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955aa018b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e101a033fc28884c8c6922e5388dcd15485c86aa39b1d52f35910147180fc6807e9e96a02ef9b08c2e1e1ec11435028a02aa52d6f9e32dcdcf7cfc5dc9d2e53ec1d83998";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "01");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "e21e77d7222bbba689910620f70e792ca5ffe443291cc7f26d9beb3341cfe821"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip7702_invalid_y_parity() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let authorization = Proto::SignedAuthorization {
+        authorization: Some(Proto::Authorization {
+            chain_id: U256::encode_be_compact(1),
+            address: "0x3535353535353535353535353535353535353535".into(),
+            nonce: U256::encode_be_compact(1),
+        }),
+        // Must be either 0 or 1.
+        y_parity: 27,
+        r: hex::decode("c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955a")
+            .unwrap()
+            .into(),
+        s: hex::decode("18b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1")
+            .unwrap()
+            .into(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::SetCode,
+        to_address: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        authorization_list: vec![authorization],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_user_operation_v0_7_with_paymaster() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0x23_86f2_6fc1_0000),
+        data: Cow::default(),
+    };
+    let user_op = Proto::UserOperation {
+        entry_point: "0x0000000071727De22E5E9d8BAf0edAc6f37da032".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        pre_verification_gas: U256::encode_be_compact(1_000_000),
+        verification_gas_limit: U256::encode_be_compact(100_000),
+        version: Proto::UserOperationVersion::V0_7,
+        factory: "0xf471789937856d80e589f5996cf8b0511ddd9de4".into(),
+        factory_data: hex::decode("0xf14ddffc000000000000000000000000b0086171ac7b6bd4d046580bca6d6a4b0835c2320000000000000000000000000000000000000000000000000000000000000000").unwrap().into(),
+        paymaster: "0xa5D7C6e5e6D2A5B9D8a0C3E1F2b4C6d8E0f1a2b3".into(),
+        paymaster_verification_gas_limit: U256::encode_be_compact(50_000),
+        paymaster_post_op_gas_limit: U256::encode_be_compact(20_000),
+        paymaster_data: hex::decode("0x00").unwrap().into(),
+        ..Proto::UserOperation::default()
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11_155_111),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::UserOp,
+        gas_limit: U256::encode_be_compact(100_000),
+        max_fee_per_gas: U256::encode_be_compact(100_000_000_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "a5352414c6a4ff8cee98ed7b8d0ff753172c787e2f2e63448abbf9c55568e2a9"
+    );
+
+    let expected = r#"{"sender":"0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029","nonce":"0","factory":"0xf471789937856D80e589F5996cf8b0511DDD9de4","factoryData":"0xf14ddffc000000000000000000000000b0086171ac7b6bd4d046580bca6d6a4b0835c2320000000000000000000000000000000000000000000000000000000000000000","callData":"0xb61d27f600000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc1000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000","callGasLimit":"100000","verificationGasLimit":"100000","preVerificationGas":"1000000","maxFeePerGas":"100000000000","maxPriorityFeePerGas":"1000000000","paymaster":"0xa5D7C6e5e6D2A5B9D8a0C3E1F2b4C6d8E0f1a2b3","paymasterVerificationGasLimit":"50000","paymasterPostOpGasLimit":"20000","paymasterData":"0x00","signature":"0x111bb8f7bb849f9fe0467a5aed6f79a8ffde2fd91e2927e74381fae41966e70347e3fe50d20424a1f184e2b7e455920ba15dd92ca63b265324196568f90360231c"}"#;
+    let actual = String::from_utf8(output.encoded.to_vec()).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_sign_user_operation_v0_7_gas_limit_overflow() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let user_op = Proto::UserOperation {
+        entry_point: "0x0000000071727De22E5E9d8BAf0edAc6f37da032".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        // Gas limits are packed as `uint128` in EntryPoint v0.7.
+        verification_gas_limit: U256::from(u128::MAX)
+            .checked_add(1u64)
+            .unwrap()
+            .to_big_endian_compact()
+            .into(),
+        version: Proto::UserOperationVersion::V0_7,
+        ..Proto::UserOperation::default()
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11_155_111),
+        tx_mode: TransactionMode::UserOp,
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_user_operation_safe_4337_module() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0x23_86f2_6fc1_0000),
+        data: Cow::default(),
+    };
+    let user_op = Proto::UserOperation {
+        entry_point: "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        pre_verification_gas: U256::encode_be_compact(1_000_000),
+        verification_gas_limit: U256::encode_be_compact(100_000),
+        account_type: Proto::SCAccountType::Safe4337,
+        ..Proto::UserOperation::default()
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11_155_111),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::UserOp,
+        gas_limit: U256::encode_be_compact(100_000),
+        max_fee_per_gas: U256::encode_be_compact(100_000_000_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "4b79f5307e3f994dcfbdfaf8c25588cf6c45c93f590335a4148f8ccae8f18609"
+    );
+
+    let expected = r#"{"callData":"0x7bb3742800000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","callGasLimit":"100000","initCode":"0x","maxFeePerGas":"100000000000","maxPriorityFeePerGas":"1000000000","nonce":"0","paymasterAndData":"0x","preVerificationGas":"1000000","sender":"0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029","signature":"0x51a01105e129ac9d02dabcc20bea67fc7b7bbee6635bf00b9749507895db5151794a44342c6ea4599b35b34034212bf81b3445612d5cf5f3aef67cec08f85d5d1b","verificationGasLimit":"100000"}"#;
+    let actual = String::from_utf8(output.encoded.to_vec()).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_sign_user_operation_delegate_call_unsupported() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let user_op = Proto::UserOperation {
+        entry_point: "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        // Biconomy accounts can only `call` the target.
+        account_type: Proto::SCAccountType::Biconomy,
+        delegate_call: true,
+        ..Proto::UserOperation::default()
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11_155_111),
+        tx_mode: TransactionMode::UserOp,
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        user_operation: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_eip1559_multicall3_batch() {
+    let private =
+        hex::decode("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904").unwrap();
+
+    let batch = Proto::mod_Transaction::Batch {
+        calls: vec![
+            Proto::mod_Transaction::mod_Batch::BatchedCall {
+                address: "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD".into(),
+                amount: U256::encode_be_compact(5_000),
+                payload: Cow::default(),
+            },
+            Proto::mod_Transaction::mod_Batch::BatchedCall {
+                address: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".into(),
+                amount: U256::encode_be_compact(7_000),
+                payload: Cow::default(),
+            },
+        ],
+        // Use the default Multicall3 address.
+        multicall3_address: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(6),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(150_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::batch(batch),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    // `aggregate3Value` is sent to 0xcA11bde05977b3631167028862bE2a173976CA11 with the total value of 12000 wei.
+    let expected = "02f902330106847735940084b2d05e00830249f094ca11bde05977b3631167028862be2a173976ca11822ee0b901c4174dea7100000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000e00000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001388000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000009d8a62f656a8d1615c1294fd71e9cfb3e4855a4f00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001b5800000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000000c001a0dede1d7be6b941066381a295f7b4dffa37d2ffeb4858aeb4a46cb2f9d500d88ca072ca165b942d24d575c3e198c882521e214abcdb4a24f0aec4568c8cc86909fd";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "439ef55221deff58c8d65ac421f7959f5c10b6d9e324aea873385fd326e2c31b"
+    );
+}
+
+// Multicall3 is the `msg.sender` of the batched calls, so contract calls can't be batched for regular accounts.
+#[test]
+fn test_sign_transaction_multicall3_batch_with_payload() {
+    let private =
+        hex::decode("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904").unwrap();
+
+    // approve(0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD, 1000)
+    let approve = hex::decode("095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e8").unwrap();
+    let batch = Proto::mod_Transaction::Batch {
+        calls: vec![
+            Proto::mod_Transaction::mod_Batch::BatchedCall {
+                address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".into(),
+                amount: Cow::default(),
+                payload: approve.into(),
+            },
+            Proto::mod_Transaction::mod_Batch::BatchedCall {
+                address: "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD".into(),
+                amount: U256::encode_be_compact(5_000),
+                payload: Cow::default(),
+            },
+        ],
+        multicall3_address: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(6),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(150_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::batch(batch),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_sign_transaction_batch_unsupported_mode() {
+    let private =
+        hex::decode("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904").unwrap();
+
+    let batch = Proto::mod_Transaction::Batch {
+        calls: vec![Proto::mod_Transaction::mod_Batch::BatchedCall {
+            address: "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD".into(),
+            amount: U256::encode_be_compact(5_000),
+            payload: Cow::default(),
+        }],
+        multicall3_address: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(6),
+        tx_mode: TransactionMode::AccessList,
+        gas_limit: U256::encode_be_compact(150_000),
+        gas_price: U256::encode_be_compact(3_000_000_000),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::batch(batch),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_zksync_native_transfer() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(324),
+        nonce: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::ZkSync,
+        gas_limit: U256::encode_be_compact(1_000_000),
+        max_fee_per_gas: U256::encode_be_compact(250_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer {
+                    // 0.01 ETH
+                    amount: U256::encode_be_compact(10_000_000_000_000_000),
+                    data: Cow::default(),
+                },
+            ),
+        }),
+        private_key: private.into(),
+        zksync_params: Some(Proto::ZkSyncParams {
+            from: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".into(),
+            ..Proto::ZkSyncParams::default()
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "71f88e0180840ee6b280830f4240943535353535353535353535353535353535353535872386f26fc10000808201448080820144949d8a62f656a8d1615c1294fd71e9cfb3e4855a4f82c350c0b841b072b5cd909eac2c3328f776c74923897ad44c0d524358c0b1e303302866ccee116da1173793973570d983a4f423f010aa4cfadd75172bbf810cc265ed2e00601cc0";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "01");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "e2d8653327c37cfa091f3f9c4b32772b6a092f1a2b98dca84a97141b8dfb74fa"
+    );
+}
+
+#[test]
+fn test_sign_transaction_zksync_sender() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let input = |from: &'static str| Proto::SigningInput {
+        chain_id: U256::encode_be_compact(324),
+        nonce: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::ZkSync,
+        gas_limit: U256::encode_be_compact(1_000_000),
+        max_fee_per_gas: U256::encode_be_compact(250_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer {
+                    amount: U256::encode_be_compact(10_000_000_000_000_000),
+                    data: Cow::default(),
+                },
+            ),
+        }),
+        private_key: private.clone().into(),
+        zksync_params: Some(Proto::ZkSyncParams {
+            from: from.into(),
+            ..Proto::ZkSyncParams::default()
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    // The sender is derived from the private key if omitted.
+    // Same as `test_sign_transaction_zksync_native_transfer`.
+    let output = Signer::<StandardEvmContext>::sign_proto(input(""));
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "e2d8653327c37cfa091f3f9c4b32772b6a092f1a2b98dca84a97141b8dfb74fa"
+    );
+
+    // The sender doesn't match the private key.
+    let output = Signer::<StandardEvmContext>::sign_proto(input(
+        "0x3535353535353535353535353535353535353535",
+    ));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_sign_transaction_zksync_missing_params() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(324),
+        tx_mode: TransactionMode::ZkSync,
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_transaction_check_intrinsic_gas() {
+    let private =
+        hex::decode("4646464646464646464646464646464646464646464646464646464646464646").unwrap();
+
+    let erc20_transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_000_000_000_000_000_000),
+    };
+    let input = |gas_limit: u64| Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Enveloped,
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(30_000_000_000),
+        gas_limit: U256::encode_be_compact(gas_limit),
+        // DAI
+        to_address: "0x6b175474e89094c44da98b954eedeac495271d0f".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_transfer(
+                erc20_transfer.clone(),
+            ),
+        }),
+        private_key: private.clone().into(),
+        check_intrinsic_gas: true,
+        ..Proto::SigningInput::default()
+    };
+
+    // The EIP-7623 calldata floor is above the intrinsic gas of 21632.
+    let output = Signer::<StandardEvmContext>::sign_proto(input(22_000));
+    assert_eq!(output.error, SigningErrorType::Error_wrong_fee);
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input(22_580));
+    assert_eq!(output.error, SigningErrorType::OK);
+}
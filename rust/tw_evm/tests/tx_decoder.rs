@@ -0,0 +1,184 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use std::borrow::Cow;
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_evm::evm_context::StandardEvmContext;
+use tw_evm::modules::tx_decoder::TxDecoder;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+
+fn decode(encoded: &str) -> Proto::TransactionDecodingOutput<'static> {
+    let input = Proto::TransactionDecodingInput {
+        encoded: Cow::from(encoded.decode_hex().unwrap()),
+    };
+    TxDecoder::<StandardEvmContext>::decode_transaction_proto(input)
+}
+
+#[test]
+fn test_decode_legacy_eip155_signed() {
+    let output = decode("f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10");
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.tx_type, 0);
+    assert_eq!(output.chain_id, U256::encode_be_compact(1));
+    assert_eq!(output.nonce, U256::encode_be_compact(0));
+    assert_eq!(output.gas_price, U256::encode_be_compact(3_600_000_000));
+    assert_eq!(output.gas_limit, U256::encode_be_compact(21_000));
+    assert_eq!(
+        output.to_address,
+        "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1"
+    );
+    assert_eq!(output.amount, U256::encode_be_compact(924_400_000_000_000));
+    assert!(output.data.is_empty());
+    assert!(output.access_list.is_empty());
+
+    assert_eq!(output.v.to_hex(), "25");
+    assert_eq!(
+        output.r.to_hex(),
+        "fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21"
+    );
+    assert_eq!(
+        output.s.to_hex(),
+        "5bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10"
+    );
+    assert_eq!(
+        output.from_address,
+        "0x989031B3607faD4D98F881B699822BF2E966d7AD"
+    );
+}
+
+#[test]
+fn test_decode_legacy_pre_eip155_signed() {
+    let output = decode("f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000801ba08383adc8b8ae116f918fb44ca7ff9dfd8012596a5c130c6246a2cc717ba41cdaa053ddfacf5bd4aa7e46d1575acf52636ea659b91f29e2fb91c75567a279738f38");
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    assert_eq!(output.tx_type, 0);
+    assert!(output.chain_id.is_empty());
+    assert_eq!(output.v.to_hex(), "1b");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "f9e36c28c8cb35adba138005c02ab7aa7fbcd891f3139cb2eeed052a51cd2713"
+    );
+    assert_eq!(
+        output.from_address,
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+    );
+}
+
+#[test]
+fn test_decode_legacy_eip155_unsigned() {
+    let output = decode("f86a808509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec800000a8080");
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    assert_eq!(output.chain_id, U256::encode_be_compact(10));
+    assert_eq!(output.gas_price, U256::encode_be_compact(42_000_000_000));
+    assert_eq!(output.gas_limit, U256::encode_be_compact(78_009));
+    assert_eq!(output.data.to_hex(), "a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "600809d8e49def36c202858f5d7a45f86465a01db630dc6168d0c8764d2b1560"
+    );
+    assert!(output.v.is_empty());
+    assert!(output.from_address.is_empty());
+}
+
+#[test]
+fn test_decode_eip2930_signed() {
+    let output = decode("01f8ca01098504a817c80082c350943535353535353535353535353535353535353535880de0b6b3a764000080f85bf85994de0b295669a9fd93d5f28d9ec85e40f4cb697baef842a00000000000000000000000000000000000000000000000000000000000000003a0000000000000000000000000000000000000000000000000000000000000000780a05b2c251724b07d5e74952575d47e1ed1902de59e91eb53c717976ce8451a370ea01cf5e08ff99314e00fafda9939b672e3dcb4cf3625a5d7d90dab51d4b5fe5863");
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    assert_eq!(output.tx_type, 1);
+    assert_eq!(output.chain_id, U256::encode_be_compact(1));
+    assert_eq!(output.nonce, U256::encode_be_compact(9));
+    assert_eq!(output.gas_price, U256::encode_be_compact(20_000_000_000));
+    assert_eq!(output.gas_limit, U256::encode_be_compact(50_000));
+
+    assert_eq!(output.access_list.len(), 1);
+    let access = &output.access_list[0];
+    assert_eq!(access.address, "0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe");
+    let stored_keys: Vec<_> = access.stored_keys.iter().map(|key| key.to_hex()).collect();
+    assert_eq!(
+        stored_keys,
+        [
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "0000000000000000000000000000000000000000000000000000000000000007"
+        ]
+    );
+
+    assert_eq!(output.v.to_hex(), "00");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "dca5f9338671f1ee96c484ffb6fa83930e0919054305f3b3a95ac79491c85e55"
+    );
+    assert_eq!(
+        output.from_address,
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+    );
+}
+
+#[test]
+fn test_decode_eip1559_signed() {
+    let output = decode("02f8710306847735940084b2d05e0082526c94b9f5771c27664bf2282d98e09d7f50cec7cb01a78701ee0c29f50cb180c080a092c336138f7d0231fe9422bb30ee9ef10bf222761fe9e04442e3a11e88880c64a06487026011dae03dc281bc21c7d7ede5c2226d197befb813a4ecad686b559e58");
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    assert_eq!(output.tx_type, 2);
+    assert_eq!(output.chain_id, U256::encode_be_compact(3));
+    assert_eq!(output.nonce, U256::encode_be_compact(6));
+    assert_eq!(
+        output.max_inclusion_fee_per_gas,
+        U256::encode_be_compact(2_000_000_000)
+    );
+    assert_eq!(
+        output.max_fee_per_gas,
+        U256::encode_be_compact(3_000_000_000)
+    );
+    assert_eq!(output.gas_limit, U256::encode_be_compact(21_100));
+    assert_eq!(output.amount, U256::encode_be_compact(543_210_987_654_321));
+
+    // Compare with `test_sign_transaction_eip1559_native_transfer`.
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "6468eb103d51c9a683b51818fdb73390151c9973831d2cfb4e9587ad54273155"
+    );
+    assert_eq!(output.v.to_hex(), "00");
+    assert_eq!(
+        output.from_address,
+        "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7"
+    );
+}
+
+#[test]
+fn test_decode_eip1559_contract_creation() {
+    let output = decode("02ce01800102830186a08080826000c0");
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    assert_eq!(output.tx_type, 2);
+    assert!(output.to_address.is_empty());
+    assert_eq!(output.data.to_hex(), "6000");
+    assert!(output.from_address.is_empty());
+}
+
+#[test]
+fn test_decode_invalid() {
+    // Unsupported transaction type.
+    let output = decode("05c0");
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+
+    // Trailing bytes after the transaction.
+    let output = decode("02ce01800102830186a08080826000c000");
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+
+    // Missing access list.
+    let output = decode("02cd01800102830186a08080826000");
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+
+    // Invalid `y_parity`.
+    let output = decode("02f8710306847735940084b2d05e0082526c94b9f5771c27664bf2282d98e09d7f50cec7cb01a78701ee0c29f50cb180c002a092c336138f7d0231fe9422bb30ee9ef10bf222761fe9e04442e3a11e88880c64a06487026011dae03dc281bc21c7d7ede5c2226d197befb813a4ecad686b559e58");
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
@@ -142,6 +142,32 @@ impl U256 {
             .ok_or(NumberError::IntegerOverflow)
     }
 
+    /// Checked subtraction. Returns `NumberError::IntegerOverflow` if overflow occurred.
+    #[inline]
+    pub fn checked_sub<T>(&self, rhs: T) -> NumberResult<U256>
+    where
+        T: Into<primitive_types::U256>,
+    {
+        let rhs = rhs.into();
+        self.0
+            .checked_sub(rhs)
+            .map(U256)
+            .ok_or(NumberError::IntegerOverflow)
+    }
+
+    /// Checked division. Returns `NumberError::IntegerOverflow` if `rhs == 0`.
+    #[inline]
+    pub fn checked_div<T>(&self, rhs: T) -> NumberResult<U256>
+    where
+        T: Into<primitive_types::U256>,
+    {
+        let rhs = rhs.into();
+        self.0
+            .checked_div(rhs)
+            .map(U256)
+            .ok_or(NumberError::IntegerOverflow)
+    }
+
     #[inline]
     fn leading_zero_bytes(&self) -> usize {
         U256::BYTES - (self.0.bits() + 7) / 8
@@ -246,6 +272,7 @@ impl_map_from!(U256, u8);
 impl_map_from!(U256, u16);
 impl_map_from!(U256, u32);
 impl_map_from!(U256, u64);
+impl_map_from!(U256, u128);
 impl_map_from!(U256, usize);
 
 #[cfg(test)]
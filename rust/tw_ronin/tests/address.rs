@@ -5,7 +5,28 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry_ext::CoinEntryExt;
+use tw_keypair::tw::PublicKeyType;
 use tw_ronin::address::Address;
+use tw_ronin::entry::RoninEntry;
+
+/// Ronin as specified in `registry.json`.
+struct RoninCoinContext;
+
+impl CoinContext for RoninCoinContext {
+    fn public_key_type(&self) -> PublicKeyType {
+        PublicKeyType::Secp256k1Extended
+    }
+
+    fn chain_id(&self) -> Option<&str> {
+        Some("2020")
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("ronin:")
+    }
+}
 
 #[test]
 fn test_ronin_address_valid() {
@@ -18,8 +39,12 @@ fn test_ronin_address_valid() {
     ];
 
     for test in valid {
-        let addr = Address::from_str(test).unwrap();
-        assert_eq!(addr.to_string(), normalized);
+        let addr = RoninEntry
+            .normalize_address(&RoninCoinContext, test, None)
+            .unwrap();
+        assert_eq!(addr, normalized);
+        // Signing inputs accept the same addresses.
+        Address::from_str(test).unwrap();
     }
 }
 
@@ -38,6 +63,9 @@ fn test_ronin_address_invalid() {
     ];
 
     for test in invalid {
+        RoninEntry
+            .validate_address(&RoninCoinContext, test, None)
+            .unwrap_err();
         Address::from_str(test).unwrap_err();
     }
 }
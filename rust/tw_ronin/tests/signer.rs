@@ -60,9 +60,8 @@ fn test_sign_json() {
         .decode_hex()
         .unwrap();
 
-    RoninEntry
+    let encoded = RoninEntry
         .sign_json(&EmptyCoinContext, input_json, private_key)
-        .expect_err("'EthEntry::sign_json' is not supported yet");
-
-    // Expected result - "f86880843b9aca0082520894c36edf48e21cf395b206352a1819de658fd7f988830437df80820feca0442aa06b0d0465bfecf84b28e2ce614a32a1ccc12735dc03a5799517d6659d7aa004e1bf2efa30743f1b6d49dbec2671e9fb5ead1e7da15e352ca1df6fb86a8ba7"
+        .unwrap();
+    assert_eq!(encoded, "f86880843b9aca0082520894c36edf48e21cf395b206352a1819de658fd7f988830437df80820feca0442aa06b0d0465bfecf84b28e2ce614a32a1ccc12735dc03a5799517d6659d7aa004e1bf2efa30743f1b6d49dbec2671e9fb5ead1e7da15e352ca1df6fb86a8ba7");
 }
@@ -108,3 +108,81 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_value(
         .map(|data| TWData::from(data).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
+
+/// Decodes an event log according to a given ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.LogDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.LogDecodingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_decode_log(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .decode_abi_log(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decodes revert data of a failed call: `Error(string)`, `Panic(uint256)` or a custom error declared in a given ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.RevertDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.RevertDecodingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_decode_revert(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .decode_abi_revert(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Encodes parameters in the non-standard packed mode, i.e `abi.encodePacked(...)`,
+/// and returns the encoded data along with its Keccak-256 hash.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.PackedEncodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.PackedEncodingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_encode_packed(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .encode_abi_packed(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Interprets a smart contract call against the built-in ERC-20, ERC-721, ERC-1155, ERC-4337 account ABIs
+/// and the given additional ABIs, and returns a human readable intent with risk flags.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.ContractCallInterpretingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.ContractCallInterpretingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_interpret_contract_call(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .interpret_abi_contract_call(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
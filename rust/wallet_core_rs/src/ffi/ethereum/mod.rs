@@ -8,3 +8,5 @@
 pub mod abi;
 #[cfg(feature = "ethereum-rlp")]
 pub mod rlp;
+#[cfg(feature = "ethereum-transaction")]
+pub mod transaction;
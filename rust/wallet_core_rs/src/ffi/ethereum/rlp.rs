@@ -29,3 +29,21 @@ pub unsafe extern "C" fn tw_ethereum_rlp_encode(
         .map(|data| TWData::from(data).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
+
+/// Decodes an RLP encoded item or a list of items.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `EthereumRlp::Proto::DecodingInput`.
+/// \return serialized `EthereumRlp::Proto::DecodingOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_rlp_decode(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .decode_rlp(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
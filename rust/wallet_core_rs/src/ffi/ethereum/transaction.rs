@@ -0,0 +1,86 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::dispatcher::{evm_coin_dispatcher, evm_dispatcher};
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Decodes a signed or unsigned transaction. Recovers the sender if the transaction is signed.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::TransactionDecodingInput`.
+/// \return serialized `Ethereum::Proto::TransactionDecodingOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_decode_transaction(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .decode_transaction(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Signs an EIP-7702 authorization to set the code of the signer's account.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::AuthorizationSigningInput`.
+/// \return serialized `Ethereum::Proto::AuthorizationSigningOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_sign_authorization(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let (coin_context, evm_dispatcher) =
+        try_or_else!(evm_coin_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .sign_authorization(&coin_context, input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Computes an address of a contract or an ERC-4337 smart account before it's deployed.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::DeploymentAddressInput`.
+/// \return serialized `Ethereum::Proto::DeploymentAddressOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_compute_deployment_address(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .compute_deployment_address(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Returns a display-ready tree of an EIP-712 typed data.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `Ethereum::Proto::TypedDataDescribingInput`.
+/// \return serialized `Ethereum::Proto::TypedDataDescribingOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_describe_typed_data(
+    coin: CoinType,
+    input: *const TWData,
+) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .describe_typed_data(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
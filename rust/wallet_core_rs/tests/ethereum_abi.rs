@@ -12,9 +12,10 @@ use tw_number::U256;
 use tw_proto::EthereumAbi::{Proto as AbiProto, Proto};
 use tw_proto::{deserialize, serialize};
 use wallet_core_rs::ffi::ethereum::abi::{
-    tw_ethereum_abi_decode_contract_call, tw_ethereum_abi_decode_params,
-    tw_ethereum_abi_decode_value, tw_ethereum_abi_encode_function,
-    tw_ethereum_abi_function_get_signature,
+    tw_ethereum_abi_decode_contract_call, tw_ethereum_abi_decode_log,
+    tw_ethereum_abi_decode_params, tw_ethereum_abi_decode_revert, tw_ethereum_abi_decode_value,
+    tw_ethereum_abi_encode_function, tw_ethereum_abi_encode_packed,
+    tw_ethereum_abi_function_get_signature, tw_ethereum_abi_interpret_contract_call,
 };
 
 use Proto::mod_ParamType::OneOfparam as ParamTypeEnum;
@@ -194,3 +195,140 @@ fn test_ethereum_abi_decode_value() {
     assert!(output.error_message.is_empty());
     assert_eq!(output.param_str, "42");
 }
+
+#[test]
+fn test_ethereum_abi_decode_log() {
+    let abi_json = json!([
+        {
+            "anonymous": false,
+            "inputs": [
+                { "indexed": true, "name": "from", "type": "address" },
+                { "indexed": true, "name": "to", "type": "address" },
+                { "indexed": false, "name": "value", "type": "uint256" }
+            ],
+            "name": "Transfer",
+            "type": "event"
+        }
+    ]);
+    let topics = [
+        "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+        "0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3",
+        "00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9",
+    ];
+    let input = AbiProto::LogDecodingInput {
+        topics: topics
+            .iter()
+            .map(|topic| topic.decode_hex().unwrap().into())
+            .collect(),
+        data: "00000000000000000000000000000000000000000000000000000000000003e8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        smart_contract_abi_json: abi_json.to_string().into(),
+        ..AbiProto::LogDecodingInput::default()
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_decode_log(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_decode_log returned nullptr");
+    let output: AbiProto::LogDecodingOutput =
+        deserialize(&output_data).expect("!tw_ethereum_abi_decode_log returned an invalid output");
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.event_signature, "Transfer(address,address,uint256)");
+    assert_eq!(output.tokens.len(), 3);
+    assert_eq!(
+        output.tokens[2].token,
+        TokenEnum::number_uint(number_n::<256>(1000))
+    );
+}
+
+#[test]
+fn test_ethereum_abi_decode_revert() {
+    let input = AbiProto::RevertDecodingInput {
+        encoded: "4e487b710000000000000000000000000000000000000000000000000000000000000011"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..AbiProto::RevertDecodingInput::default()
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_decode_revert(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_decode_revert returned nullptr");
+    let output: AbiProto::RevertDecodingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_abi_decode_revert returned an invalid output");
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.error_signature, "Panic(uint256)");
+    assert_eq!(output.reason, "arithmetic overflow or underflow");
+}
+
+#[test]
+fn test_ethereum_abi_encode_packed() {
+    let input = AbiProto::PackedEncodingInput {
+        tokens: vec![
+            named_token("", TokenEnum::number_uint(number_n::<48>(12))),
+            named_token("", TokenEnum::string_value("Hello".into())),
+            named_token("", TokenEnum::boolean(true)),
+        ],
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_encode_packed(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_encode_packed returned nullptr");
+    let output: AbiProto::PackedEncodingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_abi_encode_packed returned an invalid output");
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.encoded.to_hex(), "00000000000c48656c6c6f01");
+    assert_eq!(
+        output.hash.to_hex(),
+        "1adf5f6727120126515309f65dd534bba78c51e31f92e683b5001cbe3e28d63c"
+    );
+}
+
+#[test]
+fn test_ethereum_abi_interpret_contract_call() {
+    // approve(0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD, type(uint256).max)
+    let encoded = "095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fadffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff".decode_hex().unwrap();
+    let input = AbiProto::ContractCallInterpretingInput {
+        encoded: encoded.into(),
+        interface_hint: AbiProto::ContractInterface::ERC20,
+        abis: Vec::default(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_interpret_contract_call(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_interpret_contract_call returned nullptr");
+    let output: AbiProto::ContractCallInterpretingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_abi_interpret_contract_call returned an invalid output");
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+
+    let intent = output.intent.expect("Expected an interpreted call");
+    assert_eq!(intent.interface, AbiProto::ContractInterface::ERC20);
+    assert_eq!(intent.function_signature, "approve(address,uint256)");
+    assert_eq!(
+        intent.description,
+        "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to spend unlimited tokens"
+    );
+    assert_eq!(intent.risks, vec![AbiProto::RiskFlag::UnlimitedApproval]);
+    assert_eq!(intent.params.len(), 2);
+}
@@ -5,11 +5,11 @@
 // file LICENSE at the root of the source code distribution tree.
 
 use tw_coin_entry::error::SigningErrorType;
-use tw_encoding::hex::ToHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_proto::EthereumRlp::Proto as RlpProto;
 use tw_proto::{deserialize, serialize};
-use wallet_core_rs::ffi::ethereum::rlp::tw_ethereum_rlp_encode;
+use wallet_core_rs::ffi::ethereum::rlp::{tw_ethereum_rlp_decode, tw_ethereum_rlp_encode};
 use RlpProto::mod_RlpItem::OneOfitem as Item;
 
 const ETHEREUM_COIN_TYPE: u32 = 60;
@@ -35,3 +35,33 @@ fn test_ethereum_rlp() {
     let expected_encoded = "8180";
     assert_eq!(output.encoded.to_hex(), expected_encoded);
 }
+
+#[test]
+fn test_ethereum_rlp_decode() {
+    let input = RlpProto::DecodingInput {
+        encoded: "c3018180".decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data =
+        TWDataHelper::wrap(unsafe { tw_ethereum_rlp_decode(ETHEREUM_COIN_TYPE, input_data.ptr()) })
+            .to_vec()
+            .expect("!tw_ethereum_rlp_decode returned nullptr");
+    let output: RlpProto::DecodingOutput =
+        deserialize(&output_data).expect("!tw_ethereum_rlp_decode returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let Some(RlpProto::RlpItem {
+        item: Item::list(list),
+    }) = output.item
+    else {
+        panic!("Expected a list");
+    };
+    let items: Vec<_> = list.items.into_iter().map(|item| item.item).collect();
+    assert_eq!(
+        items,
+        vec![Item::data(vec![0x01].into()), Item::data(vec![0x80].into())]
+    );
+}
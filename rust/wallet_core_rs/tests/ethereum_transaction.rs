@@ -0,0 +1,204 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::ethereum::transaction::{
+    tw_ethereum_compute_deployment_address, tw_ethereum_decode_transaction,
+    tw_ethereum_describe_typed_data, tw_ethereum_sign_authorization,
+};
+
+const ETHEREUM_COIN_TYPE: u32 = 60;
+
+#[test]
+fn test_ethereum_decode_transaction() {
+    let encoded = "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10";
+    let input = Proto::TransactionDecodingInput {
+        encoded: encoded.decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_decode_transaction(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_decode_transaction returned nullptr");
+    let output: Proto::TransactionDecodingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_decode_transaction returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.to_address,
+        "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1"
+    );
+    assert_eq!(
+        output.from_address,
+        "0x989031B3607faD4D98F881B699822BF2E966d7AD"
+    );
+}
+
+#[test]
+fn test_ethereum_sign_authorization() {
+    let input = Proto::AuthorizationSigningInput {
+        authorization: Some(Proto::Authorization {
+            chain_id: vec![1].into(),
+            address: "0x3535353535353535353535353535353535353535".into(),
+            nonce: vec![1].into(),
+        }),
+        private_key: "4646464646464646464646464646464646464646464646464646464646464646"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::AuthorizationSigningInput::default()
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_sign_authorization(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_sign_authorization returned nullptr");
+    let output: Proto::AuthorizationSigningOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_sign_authorization returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let signed = output
+        .authorization
+        .expect("Expected a signed authorization");
+    assert_eq!(signed.y_parity, 1);
+}
+
+#[test]
+fn test_ethereum_sign_authorization_check_chain_id() {
+    // Ethereum chain ID is 1, and 0 makes the authorization valid on any chain.
+    for (chain_id, expected_error) in [
+        (1, SigningErrorType::OK),
+        (56, SigningErrorType::Error_invalid_params),
+        (0, SigningErrorType::Error_invalid_params),
+    ] {
+        let input = Proto::AuthorizationSigningInput {
+            authorization: Some(Proto::Authorization {
+                chain_id: U256::encode_be_compact(chain_id),
+                address: "0x3535353535353535353535353535353535353535".into(),
+                nonce: vec![1].into(),
+            }),
+            private_key: "4646464646464646464646464646464646464646464646464646464646464646"
+                .decode_hex()
+                .unwrap()
+                .into(),
+            check_chain_id: true,
+        };
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+        let output_data = TWDataHelper::wrap(unsafe {
+            tw_ethereum_sign_authorization(ETHEREUM_COIN_TYPE, input_data.ptr())
+        })
+        .to_vec()
+        .expect("!tw_ethereum_sign_authorization returned nullptr");
+        let output: Proto::AuthorizationSigningOutput = deserialize(&output_data)
+            .expect("!tw_ethereum_sign_authorization returned an invalid output");
+
+        assert_eq!(output.error, expected_error, "chain ID: {chain_id}");
+        assert_eq!(
+            output.authorization.is_some(),
+            expected_error == SigningErrorType::OK
+        );
+    }
+}
+
+#[test]
+fn test_ethereum_compute_deployment_address() {
+    use Proto::mod_DeploymentAddressInput::OneOfdeployment as DeploymentType;
+
+    let input = Proto::DeploymentAddressInput {
+        deployment: DeploymentType::create(Proto::CreateDeployment {
+            sender: "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0".into(),
+            nonce: Default::default(),
+        }),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_compute_deployment_address(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_compute_deployment_address returned nullptr");
+    let output: Proto::DeploymentAddressOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_compute_deployment_address returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0xcd234A471b72ba2F1Ccf0A70FCABA648a5eeCD8d");
+}
+
+#[test]
+fn test_ethereum_describe_typed_data() {
+    let message = serde_json::json!({
+        "types": {
+            "EIP712Domain": [{ "name": "name", "type": "string" }],
+            "Message": [{ "name": "hashes", "type": "bytes4[]" }]
+        },
+        "primaryType": "Message",
+        "domain": { "name": "Test" },
+        "message": { "hashes": ["0xdeadbeef"] }
+    });
+    let input = Proto::TypedDataDescribingInput {
+        message: message.to_string().into(),
+        ..Proto::TypedDataDescribingInput::default()
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_describe_typed_data(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_describe_typed_data returned nullptr");
+    let output: Proto::TypedDataDescribingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_describe_typed_data returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let actual: serde_json::Value = serde_json::from_str(&output.typed_data_json).unwrap();
+    let expected = serde_json::json!({
+        "domain": [
+            { "name": "name", "type": "string", "value": { "kind": "primitive", "value": "Test" } }
+        ],
+        "primaryType": "Message",
+        "message": [
+            {
+                "name": "hashes",
+                "type": "bytes4[]",
+                "value": { "kind": "array", "items": [{ "kind": "primitive", "value": "0xdeadbeef" }] }
+            }
+        ]
+    });
+    assert_eq!(actual, expected);
+
+    // Arrays are not supported by `eth_signTypedData_v3`.
+    let input = Proto::TypedDataDescribingInput {
+        message: message.to_string().into(),
+        typed_data_version: Proto::TypedDataVersion::TypedDataVersion_v3,
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_describe_typed_data(ETHEREUM_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_describe_typed_data returned nullptr");
+    let output: Proto::TypedDataDescribingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_describe_typed_data returned an invalid output");
+
+    assert_ne!(output.error, SigningErrorType::OK);
+    assert!(output.typed_data_json.is_empty());
+}
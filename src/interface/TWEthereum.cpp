@@ -7,14 +7,44 @@
 #include "Data.h"
 #include "Ethereum/EIP1014.h"
 #include "Ethereum/EIP2645.h"
+#include "rust/Wrapper.h"
 #include <TrustWalletCore/TWEthereum.h>
 
 #include <string>
 
+using namespace TW;
+
+template <typename F>
+static TWData* _Nonnull ethereumForwardToRust(F rustFunction, enum TWCoinType coin, TWData* _Nonnull input) {
+    const Data& inputData = *(reinterpret_cast<const Data*>(input));
+
+    const Rust::TWDataWrapper dataInPtr(inputData);
+    Rust::TWDataWrapper dataOutPtr = rustFunction(static_cast<uint32_t>(coin), dataInPtr.get());
+
+    auto dataOut = dataOutPtr.toDataOrDefault();
+    return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
+}
+
 TWString* TWEthereumEip2645GetPath(TWString* ethAddress, TWString* layer, TWString* application, TWString* index) {
     const auto& ethAddressStr = *reinterpret_cast<const std::string*>(ethAddress);
     const auto& layerStr = *reinterpret_cast<const std::string*>(layer);
     const auto& applicationStr = *reinterpret_cast<const std::string*>(application);
     const auto& indexStr = *reinterpret_cast<const std::string*>(index);
     return new std::string(TW::Ethereum::accountPathFromAddress(ethAddressStr, layerStr, applicationStr, indexStr));
-}
\ No newline at end of file
+}
+
+TWData* _Nonnull TWEthereumDecodeTransaction(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumForwardToRust(Rust::tw_ethereum_decode_transaction, coin, input);
+}
+
+TWData* _Nonnull TWEthereumSignAuthorization(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumForwardToRust(Rust::tw_ethereum_sign_authorization, coin, input);
+}
+
+TWData* _Nonnull TWEthereumComputeDeploymentAddress(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumForwardToRust(Rust::tw_ethereum_compute_deployment_address, coin, input);
+}
+
+TWData* _Nonnull TWEthereumDescribeTypedData(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumForwardToRust(Rust::tw_ethereum_describe_typed_data, coin, input);
+}
@@ -20,3 +20,13 @@ TWData* _Nonnull TWEthereumRlpEncode(enum TWCoinType coin, TWData* _Nonnull inpu
     auto dataOut = dataOutPtr.toDataOrDefault();
     return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
 }
+
+TWData* _Nonnull TWEthereumRlpDecode(enum TWCoinType coin, TWData* _Nonnull input) {
+    const Data& dataIn = *(reinterpret_cast<const Data*>(input));
+
+    const Rust::TWDataWrapper dataInPtr(dataIn);
+    Rust::TWDataWrapper dataOutPtr = Rust::tw_ethereum_rlp_decode(static_cast<uint32_t>(coin), dataInPtr.get());
+
+    auto dataOut = dataOutPtr.toDataOrDefault();
+    return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
+}
@@ -280,6 +280,110 @@ TEST(TWEthereumAbi, DecodeCall) {
     EXPECT_EQ(output.decoded_json(), expected);
 }
 
+TEST(TWEthereumAbi, DecodeLog) {
+    auto abiJson = R"|([{"anonymous":false,"inputs":[{"indexed":true,"name":"from","type":"address"},{"indexed":true,"name":"to","type":"address"},{"indexed":false,"name":"value","type":"uint256"}],"name":"Transfer","type":"event"}])|";
+    auto logData = parse_hex("00000000000000000000000000000000000000000000000000000000000003e8");
+
+    EthereumAbi::Proto::LogDecodingInput input;
+    for (const auto& topicHex : {
+             "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+             "0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3",
+             "00000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9",
+         }) {
+        auto topic = parse_hex(topicHex);
+        input.add_topics(topic.data(), topic.size());
+    }
+    input.set_data(logData.data(), logData.size());
+    input.set_smart_contract_abi_json(abiJson);
+
+    const auto inputData = data(input.SerializeAsString());
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumAbiDecodeLog(TWCoinTypeEthereum, inputTWData.get()));
+
+    EthereumAbi::Proto::LogDecodingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), EthereumAbi::Proto::AbiError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(output.event_signature(), "Transfer(address,address,uint256)");
+    ASSERT_EQ(output.tokens_size(), 3);
+    EXPECT_EQ(output.tokens(0).name(), "from");
+    EXPECT_EQ(output.tokens(0).address(), "0x5FbDB2315678afecb367f032d93F642f64180aa3");
+    EXPECT_EQ(output.tokens(1).name(), "to");
+    EXPECT_EQ(output.tokens(1).address(), "0x61061FCaE11fD5461535E134EFf67A98cFff44E9");
+    EXPECT_EQ(output.tokens(2).name(), "value");
+    EXPECT_EQ(hex(output.tokens(2).number_uint().value()), "03e8");
+}
+
+TEST(TWEthereumAbi, DecodeRevert) {
+    // Panic(0x11)
+    auto encoded = parse_hex("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+
+    EthereumAbi::Proto::RevertDecodingInput input;
+    input.set_encoded(encoded.data(), encoded.size());
+
+    const auto inputData = data(input.SerializeAsString());
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumAbiDecodeRevert(TWCoinTypeEthereum, inputTWData.get()));
+
+    EthereumAbi::Proto::RevertDecodingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), EthereumAbi::Proto::AbiError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(output.error_signature(), "Panic(uint256)");
+    EXPECT_EQ(output.reason(), "arithmetic overflow or underflow");
+}
+
+TEST(TWEthereumAbi, EncodePacked) {
+    auto number = store(uint256_t(12));
+
+    EthereumAbi::Proto::PackedEncodingInput input;
+    auto& numberToken = *input.add_tokens()->mutable_number_uint();
+    numberToken.set_bits(48);
+    numberToken.set_value(number.data(), number.size());
+    input.add_tokens()->set_string_value("Hello");
+    input.add_tokens()->set_boolean(true);
+
+    const auto inputData = data(input.SerializeAsString());
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumAbiEncodePacked(TWCoinTypeEthereum, inputTWData.get()));
+
+    EthereumAbi::Proto::PackedEncodingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), EthereumAbi::Proto::AbiError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(hex(output.encoded()), "00000000000c48656c6c6f01");
+    EXPECT_EQ(hex(output.hash()), "1adf5f6727120126515309f65dd534bba78c51e31f92e683b5001cbe3e28d63c");
+}
+
+TEST(TWEthereumAbi, InterpretContractCall) {
+    // approve(0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD, type(uint256).max)
+    auto encoded = parse_hex("095ea7b30000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fadffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+
+    EthereumAbi::Proto::ContractCallInterpretingInput input;
+    input.set_encoded(encoded.data(), encoded.size());
+    input.set_interface_hint(EthereumAbi::Proto::ContractInterface::ERC20);
+
+    const auto inputData = data(input.SerializeAsString());
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumAbiInterpretContractCall(TWCoinTypeEthereum, inputTWData.get()));
+
+    EthereumAbi::Proto::ContractCallInterpretingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), EthereumAbi::Proto::AbiError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+
+    const auto& intent = output.intent();
+    EXPECT_EQ(intent.interface(), EthereumAbi::Proto::ContractInterface::ERC20);
+    EXPECT_EQ(intent.function_signature(), "approve(address,uint256)");
+    EXPECT_EQ(intent.description(), "Approve 0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD to spend unlimited tokens");
+    ASSERT_EQ(intent.risks_size(), 1);
+    EXPECT_EQ(intent.risks(0), EthereumAbi::Proto::RiskFlag::UnlimitedApproval);
+}
+
 TEST(TWEthereumAbi, DecodeInvalidCall) {
     auto callHex = STRING("c47f002700");
     auto call = WRAPD(TWDataCreateWithHexString(callHex.get()));
@@ -0,0 +1,106 @@
+// Copyright © 2017-2023 Trust Wallet.
+//
+// This file is part of Trust. The full Trust copyright notice, including
+// terms governing use, modification, and redistribution, is contained in the
+// file LICENSE at the root of the source code distribution tree.
+
+#include "TrustWalletCore/TWEthereum.h"
+#include "proto/Ethereum.pb.h"
+#include "HexCoding.h"
+#include "TestUtilities.h"
+#include "uint256.h"
+
+#include <gtest/gtest.h>
+
+namespace TW::Ethereum::tests {
+
+TEST(TWEthereum, DecodeTransaction) {
+    auto encoded = parse_hex("f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10");
+
+    Proto::TransactionDecodingInput input;
+    input.set_encoded(encoded.data(), encoded.size());
+
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t *)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumDecodeTransaction(TWCoinTypeEthereum, inputTWData.get()));
+
+    Proto::TransactionDecodingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), Common::Proto::SigningError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(output.to_address(), "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1");
+    EXPECT_EQ(output.from_address(), "0x989031B3607faD4D98F881B699822BF2E966d7AD");
+}
+
+TEST(TWEthereum, SignAuthorization) {
+    auto privateKey = parse_hex("4646464646464646464646464646464646464646464646464646464646464646");
+    auto chainId = store(uint256_t(1));
+    auto nonce = store(uint256_t(1));
+
+    Proto::AuthorizationSigningInput input;
+    auto& authorization = *input.mutable_authorization();
+    authorization.set_chain_id(chainId.data(), chainId.size());
+    authorization.set_address("0x3535353535353535353535353535353535353535");
+    authorization.set_nonce(nonce.data(), nonce.size());
+    input.set_private_key(privateKey.data(), privateKey.size());
+
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t *)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumSignAuthorization(TWCoinTypeEthereum, inputTWData.get()));
+
+    Proto::AuthorizationSigningOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), Common::Proto::SigningError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(hex(output.pre_hash()), "17a8451e0b7239d0cacd656debaf1842e5e3457b48183a364e8aa8fa0378606d");
+
+    const auto& signedAuthorization = output.authorization();
+    EXPECT_EQ(signedAuthorization.authorization().address(), "0x3535353535353535353535353535353535353535");
+    EXPECT_EQ(signedAuthorization.y_parity(), 1u);
+    EXPECT_EQ(hex(signedAuthorization.r()), "c5231cb868a8e2ae9f568f2cb1aabb1749872b8a5f0393494aa828b1e436955a");
+    EXPECT_EQ(hex(signedAuthorization.s()), "18b5cac977d836e96ae96b66ab3bde8c8902b9d362ff0be5141d1358016013e1");
+}
+
+TEST(TWEthereum, ComputeDeploymentAddress) {
+    Proto::DeploymentAddressInput input;
+    input.mutable_create()->set_sender("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t *)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumComputeDeploymentAddress(TWCoinTypeEthereum, inputTWData.get()));
+
+    Proto::DeploymentAddressOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), Common::Proto::SigningError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(output.address(), "0xcd234A471b72ba2F1Ccf0A70FCABA648a5eeCD8d");
+}
+
+TEST(TWEthereum, DescribeTypedData) {
+    Proto::TypedDataDescribingInput input;
+    input.set_message(R"({
+        "types": {
+            "EIP712Domain": [{ "name": "name", "type": "string" }],
+            "Message": [{ "name": "contents", "type": "string" }]
+        },
+        "primaryType": "Message",
+        "domain": { "name": "Test" },
+        "message": { "contents": "Hello" }
+    })");
+
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t *)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumDescribeTypedData(TWCoinTypeEthereum, inputTWData.get()));
+
+    Proto::TypedDataDescribingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), Common::Proto::SigningError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+    EXPECT_EQ(output.typed_data_json(), R"({"domain":[{"name":"name","type":"string","value":{"kind":"primitive","value":"Test"}}],"primaryType":"Message","message":[{"name":"contents","type":"string","value":{"kind":"primitive","value":"Hello"}}]})");
+}
+
+} // namespace TW::Ethereum::tests
@@ -49,3 +49,25 @@ TEST(TWEthereumRlp, Eip1559) {
     EXPECT_TRUE(output.error_message().empty());
     EXPECT_EQ(hex(output.encoded()), "f86c0a06847735940084b2d05e0082526c946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1c0");
 }
+
+TEST(TWEthereumRlp, DecodeList) {
+    auto encoded = parse_hex("c88363617483646f67");
+
+    EthereumRlp::Proto::DecodingInput input;
+    input.set_encoded(encoded.data(), encoded.size());
+
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t *)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWEthereumRlpDecode(TWCoinTypeEthereum, inputTWData.get()));
+
+    EthereumRlp::Proto::DecodingOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+
+    EXPECT_EQ(output.error(), Common::Proto::SigningError::OK);
+    EXPECT_TRUE(output.error_message().empty());
+
+    const auto& items = output.item().list().items();
+    ASSERT_EQ(items.size(), 2);
+    EXPECT_EQ(items[0].data(), "cat");
+    EXPECT_EQ(items[1].data(), "dog");
+}
@@ -373,7 +373,7 @@ TEST(Coin, DeriveAddress) {
             EXPECT_EQ(address, "noble1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z03c2t50");
             break;
         case TWCoinTypeRootstock:
-            EXPECT_EQ(address, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+            EXPECT_EQ(address, "0x9D8A62f656a8d1615C1294Fd71E9Cfb3E4855A4F");
             break;
         case TWCoinTypeSei:
             EXPECT_EQ(address, "sei1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z05hw42q");